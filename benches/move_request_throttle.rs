@@ -0,0 +1,113 @@
+//! Times the sickle drag and motion pipeline with 50 [`Territory`]s, once sending a [`MoveRequest`] every frame
+//! and once with the default [`MoveRequestThrottle`].  
+//! \
+//! Frames are stepped at 240Hz, like a high refresh mouse, with a [`Draggable`] difference on every one of them.
+//! Run with `cargo bench --bench move_request_throttle`.
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use sickle_ui::drag_interaction::{DragState, Draggable};
+
+use megalith::components_territory::*;
+use megalith::display_territory_sickle::territory_drag_move_request_sickle;
+use megalith::systems_territory::*;
+
+const TERRITORY_COLUMNS: usize = 10;
+const TERRITORY_ROWS: usize = 5;
+const FRAMES: usize = 2400;
+const FRAME_TIME: Duration = Duration::from_micros(4167);
+
+fn main() {
+    let unthrottled = time_drag(MoveRequestThrottle { interval: Duration::ZERO });
+    let throttled = time_drag(MoveRequestThrottle::default());
+
+    println!("{} Territories, {FRAMES} frames of dragging:", TERRITORY_COLUMNS * TERRITORY_ROWS);
+    println!("    every frame:      {unthrottled:?}");
+    println!("    default throttle: {throttled:?}");
+}
+
+/// Drags one [`Territory`] back and forth among the others, and returns the time spent in [`App::update`].
+fn time_drag(move_request_throttle: MoveRequestThrottle) -> Duration {
+    let (mut app, drag_node) = drag_app(move_request_throttle);
+
+    let mut elapsed = Duration::ZERO;
+    for frame in 0..FRAMES {
+        // Wiggles inside the gap between Territories, so every MoveRequest goes through the whole pipeline.
+        let diff = if frame % 8 < 4 { Vec2::new(2.0, 1.0) } else { Vec2::new(-2.0, -1.0) };
+        *app.world_mut().get_mut::<Draggable>(drag_node).unwrap() = Draggable {
+            state: DragState::Dragging,
+            diff: Some(diff),
+            ..default()
+        };
+        app.world_mut().resource_mut::<Time>().advance_by(FRAME_TIME);
+
+        let start = Instant::now();
+        app.update();
+        elapsed += start.elapsed();
+    }
+    elapsed
+}
+
+/// Builds an [`App`] with the sickle drag system and the motion pipeline, in a `Window` full of [`Territory`]s.
+/// Returns it along with the drag node of the [`Territory`] in the middle.
+fn drag_app(move_request_throttle: MoveRequestThrottle) -> (App, Entity) {
+    let mut app = App::new();
+    app
+        .init_resource::<Time>()
+        .init_resource::<GlobalTerritorySettings>()
+        .init_resource::<GridSnap>()
+        .init_resource::<TileMode>()
+        .insert_resource(move_request_throttle)
+        .add_event::<TerritoryMoved>()
+        .add_systems(Update, (
+            territory_drag_move_request_sickle
+                .in_set(TerritoryInput),
+            (
+                territory_move_eval_type,
+                territory_move_snap_resize,
+                territory_move_snap_drag_to_window,
+                territory_move_process_fringe,
+                territory_move_group_drags,
+                territory_move_resolve_mutual_resize,
+                territory_move_tile_neighbors,
+                territory_move_check_others,
+                territory_move_apply_proposed
+            )
+                .chain()
+                .in_set(TerritoryUpdateMotion)
+                .run_if(any_with_component::<MoveRequest>)
+        ))
+        .configure_sets(Update, (
+            TerritoryInput.before(TerritoryApply),
+            TerritoryUpdateMotion.in_set(TerritoryApply)
+        ));
+
+    let (width, height) = (1000.0, 1000.0);
+    let mut window = Window::default();
+    window.resolution.set(width, height);
+    let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+
+    // A grid of Territories, each with a 20 pixel gap around it.
+    let cell_size = Vec2::new(width / TERRITORY_COLUMNS as f32, height / TERRITORY_ROWS as f32);
+    let mut drag_nodes = Vec::new();
+    for row in 0..TERRITORY_ROWS {
+        for column in 0..TERRITORY_COLUMNS {
+            let cell_center = cell_size * Vec2::new(column as f32 + 0.5, row as f32 + 0.5);
+            let drag_node = app.world_mut().spawn((Draggable::default(), TerritoryDragNode)).id();
+            let mut territory = Territory::empty();
+            territory.expanse = RectKit::from_screenspace(
+                Rect::from_center_size(cell_center, cell_size - Vec2::splat(20.0)),
+                width,
+                height
+            );
+            territory.drag_node = Some(drag_node);
+            let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevySickle)).id();
+            app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+            drag_nodes.push(drag_node);
+        }
+    }
+
+    let middle_drag_node = drag_nodes[drag_nodes.len() / 2];
+    (app, middle_drag_node)
+}
@@ -1,6 +1,13 @@
 //! Contains all States, Resources, and Components pertaining to a [`Territory`].
 
+use bevy::ecs::system::SystemParam;
+use bevy::input::touch::{TouchInput, TouchPhase};
 use bevy::prelude::*;
+use bevy::render::view::{Layer, RenderLayers};
+use bevy::utils::HashMap;
+use std::time::Duration;
+
+use crate::components_ui::TabType;
 
 /// Smallest size of a signet.
 pub const SIGNET_SIZE: Vec2 = Vec2 { x: 20.0, y: 20.0 };
@@ -10,27 +17,136 @@ pub const SIGNET_SIZE: Vec2 = Vec2 { x: 20.0, y: 20.0 };
 pub struct GlobalTerritorySettings {
     /// Smallest possible size of a [`Territory`]. Defaults to the size of a single icon.
     pub min_size: Vec2,
+    /// Largest size a [`Territory`] can be resized to. Unbounded by default.
+    pub max_size: Vec2,
     /// Starting size when spawning a new [`Territory`].
     pub default_size: Vec2,
     /// Distance of the tabs from the frame of the [`Territory`].
     pub inner_margins: Vec2,
     /// Distance of everything outside from the frame of the [`Territory`]. This will govern the space between them.
-    pub outer_margins: Vec2
+    pub outer_margins: Vec2,
+    /// How close a resizing side has to get to a neighbor's side before it snaps to it. `0.0` turns snapping off.
+    pub snap_distance: f32,
+    /// How close a dragged [`Territory`]'s side has to get to the `Window` edge before it snaps flush with it. `0.0` turns snapping off.
+    pub edge_snap_distance: f32,
+    /// Whether clicking empty space in a `Window` starts placing a new [`Territory`], committed on release.
+    pub click_to_spawn: bool,
+    /// Width of the resizing bar buttons, and both the height and width of the corner ones. 
+    /// Defaults to [`ResizeDirection::SIZE`], which can be too small a grab target on high-DPI displays.
+    pub resize_handle_size: f32,
+    /// Whether drags and resizes move the [`Territory`] every frame. On by default.  
+    /// When off, the [`Territory`] stays put until the drag ends, and a [`DeferredExpanse`] preview shows where it will land.
+    pub live_resize: bool,
+    /// Which part of a [`Territory`] starts a drag. The whole area by default.
+    pub drag_zone: DragZone
 }
 impl Default for GlobalTerritorySettings{
     fn default() -> Self {
         GlobalTerritorySettings {
             min_size: SIGNET_SIZE,
+            max_size: Vec2::splat(f32::INFINITY),
             default_size: Vec2 { x: 600.0, y: 200.0 },
             inner_margins: Vec2 { x: 3.0, y: 3.0 },
-            outer_margins: Vec2 { x: 2.5, y: 2.5 }
+            outer_margins: Vec2 { x: 2.5, y: 2.5 },
+            snap_distance: 6.0,
+            edge_snap_distance: 6.0,
+            click_to_spawn: false,
+            resize_handle_size: ResizeDirection::SIZE,
+            live_resize: true,
+            drag_zone: DragZone::default()
+        }
+    }
+}
+impl GlobalTerritorySettings {
+    /// Gets the minimum size of a [`Territory`], which is its [`MinSizeOverride`] if it has one, 
+    /// held to the size of its `Window`. Otherwise it's [`GlobalTerritorySettings::min_size`].
+    pub fn min_size_for(&self, min_size_override: Option<&MinSizeOverride>, window_size: Vec2) -> Vec2 {
+        match min_size_override {
+            Some(MinSizeOverride(min_size)) => min_size.min(window_size),
+            None => self.min_size
+        }
+    }
+
+    /// Gets the **worldspace** [`Rect`] every [`Territory`] is kept inside, which is the `Window` inset by 
+    /// [`GlobalTerritorySettings::outer_margins`] on every side.
+    pub fn margin_window_rect(&self, window_width: f32, window_height: f32) -> Rect {
+        Rect::from_center_size(
+            Vec2::ZERO, 
+            (Vec2::new(window_width, window_height) - self.outer_margins * 2.0).max(Vec2::ZERO)
+        )
+    }
+
+    /// Grows a **worldspace** [`Rect`] by [`GlobalTerritorySettings::outer_margins`] on every side. 
+    /// Other [`Territory`]s are kept out of the grown [`Rect`], so there's always a margin between two of them.
+    pub fn with_outer_margins(&self, worldspace: Rect) -> Rect {
+        Rect::from_corners(worldspace.min - self.outer_margins, worldspace.max + self.outer_margins)
+    }
+}
+
+/// Snaps the edges of moved and resized [`Territory`]s to a grid in **worldspace**, centered on the `Window`. Off by default.  
+/// \
+/// Applied last, once a move has already been cleared against its neighbors. 
+/// A snap that would leave the `Window` or overlap a neighbor is skipped for that move.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct GridSnap {
+    /// Whether edges snap to the grid at all.
+    pub enabled: bool,
+    /// Width and height of a grid cell. A side that isn't positive doesn't snap.
+    pub cell: Vec2
+}
+impl Default for GridSnap {
+    fn default() -> Self {
+        GridSnap { enabled: false, cell: SIGNET_SIZE }
+    }
+}
+impl GridSnap {
+    /// Rounds every edge of a **worldspace** [`Rect`] to the nearest grid line. 
+    /// If that would make either side smaller than `min_size`, the far edge is pushed out to the next grid line that fits.
+    pub fn snap_rect(&self, worldspace: Rect, min_size: Vec2) -> Rect {
+        let mut snapped_rect = worldspace;
+        for axis in 0..2 {
+            let cell = self.cell[axis];
+            if cell <= 0.0 {
+                continue;
+            }
+            snapped_rect.min[axis] = (worldspace.min[axis] / cell).round() * cell;
+            snapped_rect.max[axis] = (worldspace.max[axis] / cell).round() * cell;
+            if snapped_rect.max[axis] - snapped_rect.min[axis] < min_size[axis] {
+                snapped_rect.max[axis] = snapped_rect.min[axis] + (min_size[axis] / cell).ceil() * cell;
+            }
         }
+        snapped_rect
+    }
+}
+
+/// Keeps the tiled layout's space conserved while resizing. Off by default.  
+/// \
+/// A resizing side moves the whole seam it's on, found through [`CardinalConnections`]. 
+/// Linked neighbors across from it shrink by the same amount instead of being pushed, or grow to follow it back, so no gaps open up.
+/// The resize stops once any of them hits its minimum size, or if one of them is [`Locked`].
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TileMode {
+    /// Whether resizes carry their linked neighbors along.
+    pub enabled: bool
+}
+
+/// Caps how often a sickle drag or resize turns into a [`MoveRequest`].  
+/// \
+/// Differences are added up in between, so the [`MoveRequest`] carries every one of them. 
+/// Whatever is left over when the drag or resize ends is sent right away. `Duration::ZERO` sends a [`MoveRequest`] every frame.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct MoveRequestThrottle {
+    /// Shortest time between two [`MoveRequest`]s from the same drag or resize. Defaults to a 60Hz frame.
+    pub interval: Duration
+}
+impl Default for MoveRequestThrottle {
+    fn default() -> Self {
+        MoveRequestThrottle { interval: Duration::from_secs_f64(1.0 / 60.0) }
     }
 }
 
 /// A collection of `Bevy` [`Rect`]s that are useful to a variety of UI libraries.  
 /// \
-/// 
 /// So long as you pass in the correct `Window` dimensions, this component will automatically translate between all [`Rect`]s.
 /// Contains helper methods to deal with all of the different coordinate systems.
 #[derive(Component, Clone, Copy)]
@@ -55,6 +171,9 @@ impl Default for RectKit {
     }
 }
 impl RectKit {
+    /// How far apart, in pixels, [`RectKit::validate`] lets the [`Rect`]s drift from each other before calling them inconsistent.
+    pub const CONSISTENCY_EPSILON: f32 = 0.01;
+
     pub fn new(
         screenspace: Rect, 
         worldspace: Rect, 
@@ -119,6 +238,36 @@ impl RectKit {
         self.relative_worldspace
     }
 
+    /// Whether all four [`Rect`]s describe the same location in a `Window` of the given dimensions, 
+    /// within [`RectKit::CONSISTENCY_EPSILON`] pixels.  
+    /// \
+    /// A [`RectKit`] converted with stale `Window` dimensions, such as last frame's after a resize, will fail this check.
+    pub fn validate(&self, window_width: f32, window_height: f32) -> bool {
+        if window_width <= 0.0 || window_height <= 0.0 {
+            return false;
+        }
+        let matches = |a: Rect, b: Rect| {
+            a.min.abs_diff_eq(b.min, RectKit::CONSISTENCY_EPSILON) && a.max.abs_diff_eq(b.max, RectKit::CONSISTENCY_EPSILON)
+        };
+        matches(RectKit::world_rect_to_screen(self.worldspace, window_width, window_height), self.screenspace)
+            && matches(RectKit::relative_rect_to_screen(self.relative_screenspace, window_width, window_height), self.screenspace)
+            && matches(RectKit::relative_rect_to_world(self.relative_worldspace, window_width, window_height), self.worldspace)
+    }
+
+    /// In debug builds, panics if [`RectKit::validate`] fails. The `set_*` methods check once, after converting. 
+    /// The `move_*` methods and [`RectKit::fit_within_worldspace`] check once, before moving, since they build on the current [`Rect`]s 
+    /// and a [`Rect`] converted from a stale one would agree with it afterwards.  
+    /// Zero-sized `Window`s, like minimized ones, are skipped since nothing can be consistent with them.
+    #[track_caller]
+    fn debug_assert_consistent(&self, window_width: f32, window_height: f32) {
+        debug_assert!(
+            window_width <= 0.0 || window_height <= 0.0 || self.validate(window_width, window_height),
+            "RectKit is inconsistent with a {window_width} x {window_height} Window, was it converted with stale dimensions? \
+            Screenspace: {:?}, worldspace: {:?}, relative screenspace: {:?}, relative worldspace: {:?}",
+            self.screenspace, self.worldspace, self.relative_screenspace, self.relative_worldspace
+        );
+    }
+
     /// Set a new **screenspace** [`Rect`]. Requires the appropriate `Window` dimensions for translation.  
     /// \
     /// **Screenspace** coordinates have the origin `(0.0, 0.0)` in the `Window`'s upper left corner, 
@@ -132,7 +281,9 @@ impl RectKit {
         self
             .screen_to_world(window_width, window_height)
             .screen_to_relative(window_width, window_height)
-            .world_to_relative(window_width, window_height)
+            .world_to_relative(window_width, window_height);
+        self.debug_assert_consistent(window_width, window_height);
+        self
     }
 
     /// Set a new **worldspace** [`Rect`]. Requires the appropriate `Window` dimensions for translation.  
@@ -148,7 +299,9 @@ impl RectKit {
         self
             .world_to_screen(window_width, window_height)
             .world_to_relative(window_width, window_height)
-            .screen_to_relative(window_width, window_height)
+            .screen_to_relative(window_width, window_height);
+        self.debug_assert_consistent(window_width, window_height);
+        self
     }
 
     /// Set a new **screenspace** [`Rect`] in relative coordinates, from `0.0` to `1.0`.
@@ -163,7 +316,9 @@ impl RectKit {
         self
             .relative_to_screen(window_width, window_height)
             .screen_to_world(window_width, window_height)
-            .world_to_relative(window_width, window_height)
+            .world_to_relative(window_width, window_height);
+        self.debug_assert_consistent(window_width, window_height);
+        self
     }
 
     /// Set a new **worldspace** [`Rect`] in relative coordinates, from `-0.5` to `0.5`.
@@ -178,7 +333,9 @@ impl RectKit {
         self
             .relative_to_world(window_width, window_height)
             .world_to_screen(window_width, window_height)
-            .screen_to_relative(window_width, window_height)
+            .screen_to_relative(window_width, window_height);
+        self.debug_assert_consistent(window_width, window_height);
+        self
     }
 
     /// Moves [`RectKit::worldspace`]'s [`Rect::center`] some `delta_x` and `delta_y` in **worldspace** coordinates.
@@ -189,6 +346,7 @@ impl RectKit {
     ///   - [`RectKit::world_to_relative`]
     ///   - [`RectKit::screen_to_relative`]
     pub fn move_worldspace_pos(&mut self, delta_x: f32, delta_y: f32, window_width: f32, window_height: f32) -> &mut Self {
+        self.debug_assert_consistent(window_width, window_height);
         self.worldspace = Rect::from_center_size(
             Vec2::new(
                 self.worldspace.center().x + delta_x, 
@@ -199,7 +357,8 @@ impl RectKit {
         self
             .world_to_screen(window_width, window_height)
             .world_to_relative(window_width, window_height)
-            .screen_to_relative(window_width, window_height)
+            .screen_to_relative(window_width, window_height);
+        self
     }
 
     /// Moves [`RectKit::worldspace`]'s minimum and maximum corners
@@ -211,6 +370,7 @@ impl RectKit {
     ///   - [`RectKit::world_to_relative`]
     ///   - [`RectKit::screen_to_relative`]
     pub fn move_worldspace_corners(&mut self, delta_min: Vec2, delta_max: Vec2, window_width: f32, window_height: f32) -> &mut Self {
+        self.debug_assert_consistent(window_width, window_height);
         self.worldspace = Rect::from_corners(
             self.worldspace.min + delta_min,
             self.worldspace.max + delta_max
@@ -218,7 +378,8 @@ impl RectKit {
         self
             .world_to_screen(window_width, window_height)
             .world_to_relative(window_width, window_height)
-            .screen_to_relative(window_width, window_height)
+            .screen_to_relative(window_width, window_height);
+        self
     }
 
     /// Moves [`RectKit::screenspace`]'s [`Rect::min`] some `delta_x` and `delta_y` in **screenspace** coordinates.
@@ -229,6 +390,7 @@ impl RectKit {
     ///   - [`RectKit::screen_to_relative`]
     ///   - [`RectKit::world_to_relative`]
     pub fn move_screenspace_pos(&mut self, delta_x: f32, delta_y: f32, window_width: f32, window_height: f32) -> &mut Self {
+        self.debug_assert_consistent(window_width, window_height);
         self.screenspace = Rect::from_corners(
             Vec2::new(
                 self.screenspace.min.x + delta_x, 
@@ -242,7 +404,8 @@ impl RectKit {
         self
             .screen_to_world(window_width, window_height)
             .screen_to_relative(window_width, window_height)
-            .world_to_relative(window_width, window_height)
+            .world_to_relative(window_width, window_height);
+        self
     }
 
     /// Moves [`RectKit::screenspace`]'a minimum and maximum corners
@@ -254,6 +417,7 @@ impl RectKit {
     ///   - [`RectKit::screen_to_relative`]
     ///   - [`RectKit::world_to_relative`]
     pub fn move_screenspace_corners(&mut self, delta_min: Vec2, delta_max: Vec2, window_width: f32, window_height: f32) -> &mut Self {
+        self.debug_assert_consistent(window_width, window_height);
         self.screenspace = Rect::from_corners(
             self.screenspace.min + delta_min, 
             self.screenspace.max + delta_max
@@ -261,7 +425,67 @@ impl RectKit {
         self
             .screen_to_world(window_width, window_height)
             .screen_to_relative(window_width, window_height)
+            .world_to_relative(window_width, window_height);
+        self
+    }
+
+    /// Fits [`RectKit::worldspace`] inside some `bounds` in **worldspace** coordinates. 
+    /// Requires the appropriate `Window` dimensions for translation.  
+    /// \
+    /// Along each axis it fits on, the [`Rect`] is moved back inside without changing size, like a drag held to the `Window`. 
+    /// Along an axis it's bigger than `bounds` on, it's clipped down to `bounds` instead.  
+    /// \
+    /// - If anything changed, the new **worldspace** [`Rect`] will be automatically translated to the other coordinate system [`Rect`]s using:
+    ///   - [`RectKit::world_to_screen`]
+    ///   - [`RectKit::world_to_relative`]
+    ///   - [`RectKit::screen_to_relative`]
+    pub fn fit_within_worldspace(&mut self, bounds: Rect, window_width: f32, window_height: f32) -> &mut Self {
+        self.debug_assert_consistent(window_width, window_height);
+        let mut fitted_rect = self.worldspace;
+        for axis in 0..2 {
+            if fitted_rect.max[axis] - fitted_rect.min[axis] > bounds.max[axis] - bounds.min[axis] {
+                fitted_rect.min[axis] = bounds.min[axis];
+                fitted_rect.max[axis] = bounds.max[axis];
+            }
+            else if fitted_rect.min[axis] < bounds.min[axis] {
+                fitted_rect.max[axis] += bounds.min[axis] - fitted_rect.min[axis];
+                fitted_rect.min[axis] = bounds.min[axis];
+            }
+            else if fitted_rect.max[axis] > bounds.max[axis] {
+                fitted_rect.min[axis] -= fitted_rect.max[axis] - bounds.max[axis];
+                fitted_rect.max[axis] = bounds.max[axis];
+            }
+        }
+        if fitted_rect == self.worldspace {
+            return self;
+        }
+
+        self.worldspace = fitted_rect;
+        self
+            .world_to_screen(window_width, window_height)
             .world_to_relative(window_width, window_height)
+            .screen_to_relative(window_width, window_height);
+        self
+    }
+
+    /// Interpolates between this [`RectKit`] and `other` in **worldspace** coordinates, `t` of the way from this one, 
+    /// and converts the result to every other coordinate system. `t` is clamped to `0.0..=1.0`.  
+    /// \
+    /// Either [`RectKit`] can have been converted under some other `Window` size, like one from before a resize. 
+    /// Those are rebuilt from their relative **worldspace** [`Rect`] for these dimensions first, so both ends are in the same `Window`.
+    pub fn lerp(&self, other: &RectKit, t: f32, window_width: f32, window_height: f32) -> RectKit {
+        let t = t.clamp(0.0, 1.0);
+        let current_worldspace = |rect_kit: &RectKit| if rect_kit.validate(window_width, window_height) {
+            rect_kit.worldspace
+        } else {
+            RectKit::relative_rect_to_world(rect_kit.relative_worldspace, window_width, window_height)
+        };
+        let (from, to) = (current_worldspace(self), current_worldspace(other));
+        RectKit::from_worldspace(
+            Rect::from_corners(from.min.lerp(to.min, t), from.max.lerp(to.max, t)), 
+            window_width, 
+            window_height
+        )
     }
 
     /// Updates [`RectKit::screenspace`] in **screenspace** coordinates to match 
@@ -269,13 +493,14 @@ impl RectKit {
     /// \
     /// Requires the `Window`'s dimensions.
     pub fn world_to_screen(&mut self, window_width: f32, window_height: f32) -> &mut Self {
-        self.screenspace = Rect::from_center_size(
-            Vec2::new(
-            (window_width / 2.0) + self.worldspace.center().x,
-            (window_height / 2.0) - self.worldspace.center().y
-            ),
-            self.worldspace.size()
-        );
+        self.world_to_screen_with_camera(window_width, window_height, CameraView::default())
+    }
+
+    /// [`RectKit::world_to_screen`], but seen through a camera that may be offset or zoomed.  
+    /// \
+    /// Requires the `Window`'s dimensions.
+    pub fn world_to_screen_with_camera(&mut self, window_width: f32, window_height: f32, camera: CameraView) -> &mut Self {
+        self.screenspace = RectKit::world_rect_to_screen_with_camera(self.worldspace, window_width, window_height, camera);
         self
     }
 
@@ -285,12 +510,7 @@ impl RectKit {
     /// \
     /// Requires the `Window`'s dimensions.
     pub fn world_to_relative(&mut self, window_width: f32, window_height: f32) -> &mut Self {
-        self.relative_worldspace = Rect::new(
-            self.worldspace.min.x / window_width, 
-            self.worldspace.min.y / window_height, 
-            self.worldspace.max.x / window_width, 
-            self.worldspace.max.y / window_height
-        );
+        self.relative_worldspace = RectKit::world_rect_to_relative(self.worldspace, window_width, window_height);
         self
     }
 
@@ -299,13 +519,14 @@ impl RectKit {
     /// \
     /// Requires the `Window`'s dimensions.
     pub fn screen_to_world(&mut self, window_width: f32, window_height: f32) -> &mut Self {
-        self.worldspace = Rect::from_center_size(
-            Vec2::new(
-            self.screenspace.center().x - (window_width / 2.0),
-            (window_height / 2.0) - self.screenspace.center().y
-            ),
-            self.screenspace.size()
-        );
+        self.screen_to_world_with_camera(window_width, window_height, CameraView::default())
+    }
+
+    /// [`RectKit::screen_to_world`], but seen through a camera that may be offset or zoomed.  
+    /// \
+    /// Requires the `Window`'s dimensions.
+    pub fn screen_to_world_with_camera(&mut self, window_width: f32, window_height: f32, camera: CameraView) -> &mut Self {
+        self.worldspace = RectKit::screen_rect_to_world_with_camera(self.screenspace, window_width, window_height, camera);
         self
     }
 
@@ -315,12 +536,7 @@ impl RectKit {
     /// \
     /// Requires the `Window`'s dimensions.
     pub fn screen_to_relative(&mut self, window_width: f32, window_height: f32) -> &mut Self {
-        self.relative_screenspace = Rect::new(
-            self.screenspace.min.x / window_width, 
-            self.screenspace.min.y / window_height, 
-            self.screenspace.max.x / window_width, 
-            self.screenspace.max.y / window_height
-        );
+        self.relative_screenspace = RectKit::screen_rect_to_relative(self.screenspace, window_width, window_height);
         self
     }
 
@@ -330,12 +546,7 @@ impl RectKit {
     /// \
     /// Requires the `Window`'s dimensions.
     pub fn relative_to_world(&mut self, window_width: f32, window_height: f32) -> &mut Self {
-        self.worldspace = Rect::new(
-            self.relative_worldspace.min.x * window_width, 
-            self.relative_worldspace.min.y * window_height,
-            self.relative_worldspace.max.x * window_width, 
-            self.relative_worldspace.max.y * window_height
-        );
+        self.worldspace = RectKit::relative_rect_to_world(self.relative_worldspace, window_width, window_height);
         self
     }
 
@@ -345,15 +556,106 @@ impl RectKit {
     /// \
     /// Requires the `Window`'s dimensions.
     pub fn relative_to_screen(&mut self, window_width: f32, window_height: f32) -> &mut Self {
-        self.screenspace = Rect::new(
-            self.relative_screenspace.min.x / window_width, 
-            self.relative_screenspace.min.y / window_height, 
-            self.relative_screenspace.max.x / window_width, 
-            self.relative_screenspace.max.y / window_height
-        );
+        self.screenspace = RectKit::relative_rect_to_screen(self.relative_screenspace, window_width, window_height);
         self
     }
 
+    /// Translates a **worldspace** [`Rect`] to **screenspace** without needing a [`RectKit`]. 
+    /// Same math as [`RectKit::world_to_screen`].
+    pub fn world_rect_to_screen(worldspace: Rect, window_width: f32, window_height: f32) -> Rect {
+        RectKit::world_rect_to_screen_with_camera(worldspace, window_width, window_height, CameraView::default())
+    }
+
+    /// [`RectKit::world_rect_to_screen`], but seen through a camera that may be offset or zoomed.
+    pub fn world_rect_to_screen_with_camera(worldspace: Rect, window_width: f32, window_height: f32, camera: CameraView) -> Rect {
+        Rect::from_center_size(
+            camera.world_to_screen_pos(worldspace.center(), window_width, window_height),
+            worldspace.size() / camera.scale
+        )
+    }
+
+    /// Translates a **screenspace** [`Rect`] to **worldspace** without needing a [`RectKit`]. 
+    /// Same math as [`RectKit::screen_to_world`].
+    pub fn screen_rect_to_world(screenspace: Rect, window_width: f32, window_height: f32) -> Rect {
+        RectKit::screen_rect_to_world_with_camera(screenspace, window_width, window_height, CameraView::default())
+    }
+
+    /// [`RectKit::screen_rect_to_world`], but seen through a camera that may be offset or zoomed.
+    pub fn screen_rect_to_world_with_camera(screenspace: Rect, window_width: f32, window_height: f32, camera: CameraView) -> Rect {
+        Rect::from_center_size(
+            camera.screen_to_world_pos(screenspace.center(), window_width, window_height),
+            screenspace.size() * camera.scale
+        )
+    }
+
+    /// Translates a **worldspace** [`Rect`] to relative **worldspace**. Same math as [`RectKit::world_to_relative`].
+    pub fn world_rect_to_relative(worldspace: Rect, window_width: f32, window_height: f32) -> Rect {
+        Rect::new(
+            worldspace.min.x / window_width, 
+            worldspace.min.y / window_height, 
+            worldspace.max.x / window_width, 
+            worldspace.max.y / window_height
+        )
+    }
+
+    /// Translates a **screenspace** [`Rect`] to relative **screenspace**. Same math as [`RectKit::screen_to_relative`].
+    pub fn screen_rect_to_relative(screenspace: Rect, window_width: f32, window_height: f32) -> Rect {
+        Rect::new(
+            screenspace.min.x / window_width, 
+            screenspace.min.y / window_height, 
+            screenspace.max.x / window_width, 
+            screenspace.max.y / window_height
+        )
+    }
+
+    /// Translates a relative **worldspace** [`Rect`] to **worldspace**. Same math as [`RectKit::relative_to_world`].
+    pub fn relative_rect_to_world(relative_worldspace: Rect, window_width: f32, window_height: f32) -> Rect {
+        Rect::new(
+            relative_worldspace.min.x * window_width, 
+            relative_worldspace.min.y * window_height,
+            relative_worldspace.max.x * window_width, 
+            relative_worldspace.max.y * window_height
+        )
+    }
+
+    /// Translates a relative **screenspace** [`Rect`] to **screenspace**. Same math as [`RectKit::relative_to_screen`].
+    pub fn relative_rect_to_screen(relative_screenspace: Rect, window_width: f32, window_height: f32) -> Rect {
+        Rect::new(
+            relative_screenspace.min.x * window_width, 
+            relative_screenspace.min.y * window_height, 
+            relative_screenspace.max.x * window_width, 
+            relative_screenspace.max.y * window_height
+        )
+    }
+
+    /// Translates a single **worldspace** point, such as a cursor position, to **screenspace**. 
+    /// Matches the center of [`RectKit::world_rect_to_screen`].
+    pub fn world_pos_to_screen(worldspace_pos: Vec2, window_width: f32, window_height: f32) -> Vec2 {
+        CameraView::default().world_to_screen_pos(worldspace_pos, window_width, window_height)
+    }
+
+    /// Translates a single **screenspace** point to **worldspace**. 
+    /// Matches the center of [`RectKit::screen_rect_to_world`].
+    pub fn screen_pos_to_world(screenspace_pos: Vec2, window_width: f32, window_height: f32) -> Vec2 {
+        CameraView::default().screen_to_world_pos(screenspace_pos, window_width, window_height)
+    }
+
+    /// Flips the y of a **screenspace** point to **worldspace**, for libraries that work in y-down coordinates. 
+    /// The y ends up measured up from the center of the `Window`, and the x is left alone.  
+    /// \
+    /// For the x too, use [`RectKit::screen_pos_to_world`]. A `window_height` of `0.0` flips a delta instead of a point.
+    pub fn flip_y_screen_to_world(point: Vec2, window_height: f32) -> Vec2 {
+        Vec2::new(point.x, (window_height / 2.0) - point.y)
+    }
+
+    /// Flips the y of a **worldspace** point to **screenspace**, undoing [`RectKit::flip_y_screen_to_world`]. 
+    /// The y ends up measured down from the top of the `Window`, and the x is left alone.  
+    /// \
+    /// For the x too, use [`RectKit::world_pos_to_screen`]. A `window_height` of `0.0` flips a delta instead of a point.
+    pub fn flip_y_world_to_screen(point: Vec2, window_height: f32) -> Vec2 {
+        Vec2::new(point.x, (window_height / 2.0) - point.y)
+    }
+
     /// Checks to see if [`RectKit::worldspace`] is inside a window's **worldspace** [`Rect`].  
     /// \
     /// Be sure to pass in the dimensions of the correct `Window`!
@@ -369,15 +671,73 @@ impl RectKit {
 
     /// Checks to see if the [`RectKit::screenspace`] is inside a window's **screenspace** [`Rect`].  
     /// \
+    /// The window edges are inclusive, so a [`RectKit`] flush against the right or bottom edge counts as inside.  
     /// Be sure to pass in the dimensions of the correct `Window`!
     pub fn is_inside_screenspace_window(&self, window_width: f32, window_height: f32) -> bool {
+        let screenspace = self.screenspace();
 
-        let window_rect = Rect::from_corners(
-            Vec2::ZERO, 
-            Vec2::new(window_width,window_height)
-        );
+        screenspace.min.x >= 0.0 && screenspace.min.y >= 0.0
+            && screenspace.max.x <= window_width && screenspace.max.y <= window_height
+    }
+
+    /// Returns an absolutely positioned `bevy_ui` [`Style`] covering [`RectKit::relative_screenspace`], 
+    /// with `left`, `top`, `width`, and `height` as percentages of the parent [`Node`].  
+    /// \
+    /// Every other [`Style`] field is left at default.
+    pub fn to_absolute_style(&self) -> Style {
+        Style {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(self.relative_screenspace.min.x * 100.0),
+            top: Val::Percent(self.relative_screenspace.min.y * 100.0),
+            width: Val::Percent(self.relative_screenspace.width() * 100.0),
+            height: Val::Percent(self.relative_screenspace.height() * 100.0),
+            ..default()
+        }
+    }
+}
+
+/// Where a [`TerritoryTabsCamera`] sits in **worldspace** and how far it's zoomed out.  
+/// \
+/// [`RectKit`] assumes the **worldspace** origin is the center of the `Window` at a scale of `1.0`, 
+/// which is what [`CameraView::default`] describes. Anything else should be passed to the `_with_camera` conversions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraView {
+    /// **Worldspace** position at the center of the `Window`.
+    pub offset: Vec2,
+    /// **Worldspace** units per logical pixel, same as [`OrthographicProjection::scale`].
+    pub scale: f32
+}
+impl Default for CameraView {
+    fn default() -> Self {
+        CameraView { offset: Vec2::ZERO, scale: 1.0 }
+    }
+}
+impl CameraView {
+    pub fn new(offset: Vec2, scale: f32) -> Self {
+        CameraView { offset, scale }
+    }
+
+    /// Reads the view from a 2D camera's transform and projection.
+    pub fn from_camera(camera_transform: &GlobalTransform, projection: &OrthographicProjection) -> Self {
+        CameraView { offset: camera_transform.translation().truncate(), scale: projection.scale }
+    }
 
-        window_rect.contains(self.screenspace().min) && window_rect.contains(self.screenspace().max)
+    /// Converts a **worldspace** point to **screenspace**.
+    pub fn world_to_screen_pos(&self, worldspace_pos: Vec2, window_width: f32, window_height: f32) -> Vec2 {
+        let from_center = (worldspace_pos - self.offset) / self.scale;
+        Vec2::new(
+            (window_width / 2.0) + from_center.x,
+            (window_height / 2.0) - from_center.y
+        )
+    }
+
+    /// Converts a **screenspace** point to **worldspace**.
+    pub fn screen_to_world_pos(&self, screenspace_pos: Vec2, window_width: f32, window_height: f32) -> Vec2 {
+        let from_center = Vec2::new(
+            screenspace_pos.x - (window_width / 2.0),
+            (window_height / 2.0) - screenspace_pos.y
+        );
+        from_center * self.scale + self.offset
     }
 }
 
@@ -390,6 +750,49 @@ pub struct TerritoryTabs;
 #[derive(Component)]
 pub struct TerritoryTabsCamera;
 
+/// How many [`TerritoryTabs`] `Window`s get a [`TerritoryDebugGizmos`] group of their own. 
+/// Any more still get a render layer of their own, they just don't draw debug gizmos.
+pub const DEBUG_GIZMO_SLOTS: usize = 4;
+
+/// Debug gizmos for the `Window` in one [`WindowRenderLayer`] slot, drawn only on that slot's render layer.
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct TerritoryDebugGizmos<const SLOT: usize>;
+
+/// Which render layer slot a [`TerritoryTabs`] `Window` uses, so its [`TerritoryTabsCamera`] 
+/// only draws that `Window`'s own debug gizmos.  
+/// \
+/// Layer `0` is shared by every camera. Each slot adds one more layer on top of it, and no two `Window`s share a slot.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowRenderLayer {
+    slot: usize
+}
+impl WindowRenderLayer {
+    /// Creates a [`WindowRenderLayer`] for a slot. Only the first [`DEBUG_GIZMO_SLOTS`] have debug gizmos.
+    pub fn new(slot: usize) -> Self {
+        WindowRenderLayer { slot }
+    }
+
+    /// Gets the slot, which picks the [`TerritoryDebugGizmos`] group.
+    pub fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// Checks if this slot has a [`TerritoryDebugGizmos`] group to draw with.
+    pub fn has_debug_gizmos(&self) -> bool {
+        self.slot < DEBUG_GIZMO_SLOTS
+    }
+
+    /// Gets the layer only this slot's debug gizmos are drawn on.
+    pub fn gizmo_layer(&self) -> Layer {
+        1 + self.slot
+    }
+
+    /// Gets the [`RenderLayers`] for the `Window`'s [`TerritoryTabsCamera`].
+    pub fn camera_layers(&self) -> RenderLayers {
+        RenderLayers::layer(0).with(self.gizmo_layer())
+    }
+}
+
 #[derive(Component)]
 /// Identifies the UI Root Node associated with a [`Window`] [`Entity`].
 pub struct TerritoryTabsUIRoot {
@@ -401,6 +804,196 @@ pub struct TerritoryTabsUIRoot {
     pub associated_window_entity: Entity
 }
 
+/// Where the cursor is in a [`TerritoryTabs`] `Window`, as seen by that `Window`'s own [`TerritoryTabsCamera`].  
+/// \
+/// Every `Window` keeps its own, so several `Window`s never mix up each other's cursor. 
+/// All fields are `None` while the cursor is outside the `Window`.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct WindowCursor {
+    /// Cursor position with the origin at the top left of the `Window`, `+y` going down.
+    pub screenspace_pos: Option<Vec2>,
+    /// Cursor position in the 2D world of the `Window`'s camera, `+y` going up.
+    pub worldspace_pos: Option<Vec2>,
+    /// [`Territory`] [`Entity`] under the cursor, if any. Includes the resize handles around the edges.
+    pub territory: Option<Entity>,
+    /// [`Territory`] [`Entity`] whose content area is under the cursor, if any. See [`Territory::content_contains_worldspace`].
+    pub content_territory: Option<Entity>
+}
+
+/// Broad-phase for finding the [`Territory`] under the cursor, kept on each [`TerritoryTabs`] `Window`.  
+/// \
+/// **Worldspace** is split into square cells, and each cell lists the [`Territory`]s overlapping it, in `Window` child order.
+/// Only the ones in the cursor's cell need an actual point test.
+#[derive(Component, Clone, Debug, Default)]
+pub struct TerritoryHitGrid {
+    pub cells: HashMap<IVec2, Vec<Entity>>
+}
+impl TerritoryHitGrid {
+    /// Width and height of a cell in **worldspace**.
+    pub const CELL_SIZE: f32 = 128.0;
+
+    /// Cell holding this **worldspace** position.
+    pub fn cell_of(worldspace_pos: Vec2) -> IVec2 {
+        (worldspace_pos / TerritoryHitGrid::CELL_SIZE).floor().as_ivec2()
+    }
+
+    /// Forgets every [`Territory`].
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Adds a [`Territory`] to every cell its **worldspace** [`Rect`] overlaps.
+    pub fn insert(&mut self, territory_entity: Entity, worldspace: Rect) {
+        let min_cell = TerritoryHitGrid::cell_of(worldspace.min);
+        let max_cell = TerritoryHitGrid::cell_of(worldspace.max);
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(territory_entity);
+            }
+        }
+    }
+
+    /// [`Territory`]s that might contain this **worldspace** position. Anything else definitely doesn't.
+    pub fn candidates(&self, worldspace_pos: Vec2) -> &[Entity] {
+        self.cells
+            .get(&TerritoryHitGrid::cell_of(worldspace_pos))
+            .map_or(&[], |cell| cell.as_slice())
+    }
+}
+
+/// The [`Territory`] last interacted with in a [`TerritoryTabs`] `Window`.  
+/// \
+/// Lives on the `Window` [`Entity`], so each `Window` keeps its own focus. 
+/// Anything acting on "the focused [`Territory`]" should only look at the `Window` it's working in.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FocusedTerritory(pub Option<Entity>);
+impl FocusedTerritory {
+    /// Gets the focused [`Territory`] [`Entity`], if any.
+    pub fn get(&self) -> Option<Entity> {
+        self.0
+    }
+}
+
+/// Marker for the one [`Territory`] interacted with last, across every `Window`.  
+/// \
+/// Only ever moved, never copied, so at most one [`Territory`] has it. 
+/// Its base node is drawn above every other [`Territory`] in its `Window`.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Focused;
+
+/// Two way lookup between `Window` entities and the [`TerritoryTabsUIRoot`] node entities associated with them.  
+/// \
+/// Kept up to date by systems watching [`TerritoryTabsUIRoot`] being added and removed, 
+/// so nobody has to scan every root node to find the one they want. Yet another stand-in for entity relations!
+#[derive(Resource, Default)]
+pub struct WindowRootIndex {
+    window_to_root: HashMap<Entity, Entity>,
+    root_to_window: HashMap<Entity, Entity>
+}
+
+impl WindowRootIndex {
+    /// Gets the root node [`Entity`] associated with a `Window` [`Entity`], if any.
+    pub fn root_of(&self, window_entity: Entity) -> Option<Entity> {
+        self.window_to_root.get(&window_entity).copied()
+    }
+
+    /// Gets the `Window` [`Entity`] associated with a root node [`Entity`], if any.
+    pub fn window_of(&self, root_entity: Entity) -> Option<Entity> {
+        self.root_to_window.get(&root_entity).copied()
+    }
+
+    /// Associates a `Window` [`Entity`] with a root node [`Entity`], replacing any previous association either had.
+    pub fn insert(&mut self, window_entity: Entity, root_entity: Entity) -> &mut Self {
+        if let Some(old_root) = self.window_to_root.insert(window_entity, root_entity) {
+            self.root_to_window.remove(&old_root);
+        }
+        if let Some(old_window) = self.root_to_window.insert(root_entity, window_entity) {
+            if old_window != window_entity {
+                self.window_to_root.remove(&old_window);
+            }
+        }
+        self
+    }
+
+    /// Removes a root node [`Entity`] from the index, returning the `Window` [`Entity`] it was associated with.
+    pub fn remove_root(&mut self, root_entity: Entity) -> Option<Entity> {
+        let window_entity = self.root_to_window.remove(&root_entity)?;
+        self.window_to_root.remove(&window_entity);
+        Some(window_entity)
+    }
+
+    /// Iterates over every `(window, root)` [`Entity`] pair.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.window_to_root.iter().map(|(window_entity, root_entity)| (*window_entity, *root_entity))
+    }
+
+    /// Number of indexed `Window`s.
+    pub fn len(&self) -> usize {
+        self.window_to_root.len()
+    }
+
+    /// Returns `true` if no `Window`s are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.window_to_root.is_empty()
+    }
+}
+
+/// While this resource exists, a [`Territory`] with a [`crate::systems_territory::TerritoryDespawnRequest`] is only despawned 
+/// once something answers its [`crate::systems_territory::TerritoryDespawnRequested`] with a 
+/// [`crate::systems_territory::TerritoryDespawnApproved`]. Insert it to get a chance to veto, like for unsaved tab content.  
+/// \
+/// Without it, every request is approved right away.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct TerritoryDespawnGuard;
+
+/// Every touch currently down, in the order they started, and the `Window` each one is in.  
+/// \
+/// Lets a touch stand in for the cursor when a `Window` doesn't have one. 
+/// Only the oldest touch in a `Window` does, so extra fingers are ignored instead of fighting over the same drag.
+#[derive(Resource, Default)]
+pub struct WindowTouches {
+    touches: Vec<(Entity, u64, Vec2)>
+}
+
+impl WindowTouches {
+    /// Starts, moves, or ends a touch to match a [`TouchInput`].
+    pub fn update(&mut self, touch_input: &TouchInput) {
+        let existing_index = self.touches.iter().position(|(_, id, _)| *id == touch_input.id);
+        match (touch_input.phase, existing_index) {
+            (TouchPhase::Started, Some(index)) | 
+            (TouchPhase::Moved, Some(index)) => {
+                self.touches[index] = (touch_input.window, touch_input.id, touch_input.position);
+            },
+            (TouchPhase::Started, None) | 
+            (TouchPhase::Moved, None) => {
+                self.touches.push((touch_input.window, touch_input.id, touch_input.position));
+            },
+            (TouchPhase::Ended, Some(index)) | 
+            (TouchPhase::Canceled, Some(index)) => {
+                self.touches.remove(index);
+            },
+            (TouchPhase::Ended, None) | 
+            (TouchPhase::Canceled, None) => {}
+        }
+    }
+
+    /// Gets the id and **screenspace** position of the oldest touch still down in a `Window`.
+    pub fn primary(&self, window_entity: Entity) -> Option<(u64, Vec2)> {
+        self.touches.iter()
+            .find(|(touch_window, _, _)| *touch_window == window_entity)
+            .map(|(_, id, position)| (*id, *position))
+    }
+
+    /// Gets the cursor position in a `Window`, or the [`WindowTouches::primary`] touch if there's no cursor. 
+    /// Comes with the id of the touch, or `None` for the cursor, so a switch between the two can be told apart from movement.
+    pub fn pointer(&self, window_entity: Entity, window: &Window) -> Option<(Option<u64>, Vec2)> {
+        match window.cursor_position() {
+            Some(cursor_position) => Some((None, cursor_position)),
+            None => self.primary(window_entity).map(|(id, position)| (Some(id), position))
+        }
+    }
+}
+
 /// Denotes the [`Entity`] as containing the base node for a [`Territory`] [`Entity`].
 #[derive(Component)]
 pub struct TerritoryBaseNode;
@@ -409,6 +1002,36 @@ pub struct TerritoryBaseNode;
 #[derive(Component)]
 pub struct TerritoryDragNode;
 
+/// Area of a [`Territory`] covered by its drag node, set in [`GlobalTerritorySettings::drag_zone`].  
+/// \
+/// By default the whole [`Territory`] can be grabbed and dragged around. 
+/// With `title_bar_only`, only a strip `title_bar_height` tall along the top starts a drag.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DragZone {
+    /// Whether only the top strip of the [`Territory`] starts a drag.
+    pub title_bar_only: bool,
+    /// Height of the top strip when `title_bar_only` is set. Defaults to the height of a single icon.
+    pub title_bar_height: f32
+}
+impl Default for DragZone {
+    fn default() -> Self {
+        DragZone {
+            title_bar_only: false,
+            title_bar_height: SIGNET_SIZE.y
+        }
+    }
+}
+impl DragZone {
+    /// Gets the height of the drag node: the full [`Territory`], or just the title bar.
+    pub fn node_height(&self) -> Val {
+        if self.title_bar_only {
+            Val::Px(self.title_bar_height.max(0.0))
+        } else {
+            Val::Percent(100.0)
+        }
+    }
+}
+
 /// Denotes the [`Entity`] as containing the resize grid node for a [`Territory`] [`Entity`].
 #[derive(Component)]
 pub struct TerritoryResizeGridNode;
@@ -417,33 +1040,496 @@ pub struct TerritoryResizeGridNode;
 #[derive(Component)]
 pub struct TerritoryResizeButtonNode;
 
-/// App State communicating the operating Mode of the `Territory Tabs` UI.
-#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
-pub enum TerritoryTabsMode {
-    #[default]
-    /// The user has managed to remove everything, leaving them stuck. A button to spawn a Territory should be presented.
-    Empty,
-    /// Nominal state. The user is operating features present in the UI.
-    Operating,
-    /// User is changing the layout. Helper overlays should be spawned. 
-    MovingTerritories,
-    /// User is repositioning a feature, and may spawn a new Territory.
-    MovingTabs
+/// Denotes the [`Entity`] as containing the clipped content viewport node for a [`Territory`] [`Entity`].
+#[derive(Component)]
+pub struct TerritoryContentNode;
+
+/// Denotes the [`Entity`] as containing the tab bar node of a [`Territory`] [`Entity`], sitting above the [`TerritoryContentNode`].
+#[derive(Component)]
+pub struct TerritoryTabBarNode;
+
+/// Denotes the [`Entity`] as containing the hint shown in the [`TerritoryContentNode`] when its [`Territory`] has no tabs.
+#[derive(Component)]
+pub struct TerritoryEmptyHintNode;
+
+/// Denotes the [`Entity`] as containing the translucent preview node of where a [`Territory`] with a [`DeferredExpanse`] will land.
+/// Sits under the `Window`'s root node along with an [`Overlay`](crate::components_ui::Overlay) marker.
+#[derive(Component)]
+pub struct TerritoryMovePreviewNode {
+    /// [`Territory`] [`Entity`] being previewed.
+    pub territory: Entity
+}
+
+/// A button in a [`Territory`]'s tab bar standing in for one of its [`Tab`](crate::components_ui::Tab)s. Pressing it selects that tab.
+#[derive(Component)]
+pub struct TerritoryTabButton {
+    /// [`Territory`] [`Entity`] the tab is in.
+    pub territory: Entity,
+    /// [`Tab`](crate::components_ui::Tab) [`Entity`] this button selects.
+    pub tab: Entity
+}
+
+/// The `+` button in a [`Territory`]'s tab bar. Pressing it requests a new tab of the given [`TabType`].
+#[derive(Component)]
+pub struct TerritoryAddTabButton {
+    /// [`Territory`] [`Entity`] the new tab will be added to.
+    pub territory: Entity,
+    /// Type of tab that will be requested.
+    pub tab_type: TabType
+}
+
+/// Denotes the [`Entity`] as containing the full `Window` node that centers an [`EmptyWindowSpawnButton`]. 
+/// Only around while the app is in [`TerritoryTabsState::Empty`](crate::systems_common::TerritoryTabsState::Empty).
+#[derive(Component)]
+pub struct EmptyWindowSpawnNode {
+    /// `Window` [`Entity`] the node is in.
+    pub window: Entity
+}
+
+/// The "New Territory" button shown when there are no [`Territory`]s left, so the UI can be recovered without dev controls. 
+/// Pressing it requests a [`Territory`] of [`GlobalTerritorySettings::default_size`] in the middle of the `Window`.
+#[derive(Component)]
+pub struct EmptyWindowSpawnButton {
+    /// `Window` [`Entity`] the new [`Territory`] will be spawned in.
+    pub window: Entity
+}
+
+/// Denotes the [`Entity`] as containing the scrolling node that holds the content of a [`Territory`] [`Entity`].
+/// Always a child of the [`TerritoryContentNode`].
+#[derive(Component)]
+pub struct TerritoryScrollNode;
+
+/// Denotes the [`Entity`] as containing the content drawn for the active [`Tab`](crate::components_ui::Tab) of a [`Territory`] [`Entity`], 
+/// by its [`TabRenderer`](crate::resources_ui::TabRenderer). Always a child of the [`TerritoryScrollNode`].
+#[derive(Component)]
+pub struct TabContentNode {
+    /// [`Tab`](crate::components_ui::Tab) [`Entity`] the content was drawn for.
+    pub tab: Entity
+}
+
+/// Scroll state of a [`Territory`]'s content, stored on the [`TerritoryContentNode`].  
+/// \
+/// Heights are in logical pixels and are measured from the nodes after layout.
+/// The offset is how far the [`TerritoryScrollNode`] has been scrolled down, and is always clamped to the content bounds.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContentScroll {
+    /// Distance the content has been scrolled down from the top.
+    pub offset: f32,
+    /// Height of the [`TerritoryScrollNode`], the content being scrolled.
+    pub content_height: f32,
+    /// Height of the [`TerritoryContentNode`], the visible area the content is clipped to.
+    pub viewport_height: f32
+}
+
+impl ContentScroll {
+    /// Logical pixels scrolled per line when the mouse wheel reports in lines.
+    pub const LINE_HEIGHT: f32 = 20.0;
+
+    /// Gets the current scroll offset.
+    pub fn offset(&self) -> f32 { self.offset }
+
+    /// Largest possible offset. Zero if the content fits inside the viewport.
+    pub fn max_offset(&self) -> f32 {
+        (self.content_height - self.viewport_height).max(0.0)
+    }
+
+    /// Scroll some `delta` down, or up if negative, clamped to the content bounds.
+    pub fn scroll_by(&mut self, delta: f32) -> &mut Self {
+        self.offset = (self.offset + delta).clamp(0.0, self.max_offset());
+        self
+    }
+
+    /// Set new measured heights, clamping the current offset to the new content bounds.
+    pub fn set_heights(&mut self, content_height: f32, viewport_height: f32) -> &mut Self {
+        self.content_height = content_height;
+        self.viewport_height = viewport_height;
+        self.scroll_by(0.0)
+    }
 }
 
 /// User has marked this UI element as `Locked`, and they don't want any systems moving it around!
 #[derive(Component)]
 pub struct Locked;
 
-/// Defines what library will be used to display UI. Add to a `Window` entity to set a default. Add to a `Territory`
-/// or a `Tab` entity to override that default.
+/// Replaces [`GlobalTerritorySettings::min_size`] for a single [`Territory`], like a terminal that needs more room.  
+/// \
+/// Holds both when the [`Territory`] is resized and when it's pushed by a neighbor. Never more than the `Window` size.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MinSizeOverride(pub Vec2);
+
+/// Marks a [`Territory`] as floating above the tiled layout, like a tool palette.  
+/// \
+/// Unlike the temporary `Overlay`, this is meant to stick around. A `Floating` [`Territory`] can still be dragged, 
+/// resized, and is kept inside the `Window`, but it never collides with, pushes, or gets pushed by other [`Territory`]s.
+#[derive(Component)]
+pub struct Floating;
+
+/// Marks a [`Territory`] as maximized to fill its `Window`, remembering where it was so it can be restored.  
+/// \
+/// Like [`Floating`], a maximized [`Territory`] sits outside the tiled layout, so nothing pushes it or gets pushed by it.
 #[derive(Component, Clone, Copy)]
+pub struct Maximized {
+    /// The expanse the [`Territory`] had before it was maximized.
+    pub previous_expanse: RectKit
+}
+impl Maximized {
+    /// Longest gap between two presses on a drag node that still counts as a double-click.
+    pub const DOUBLE_CLICK_SECONDS: f32 = 0.4;
+
+    /// Gets the expanse the [`Territory`] had before it was maximized.
+    pub fn previous_expanse(&self) -> RectKit {
+        self.previous_expanse
+    }
+}
+
+/// Marks a [`Territory`] as docked to one side of its `Window`, spanning that whole side like an IDE panel.  
+/// \
+/// It keeps its thickness across the side, and is re-docked whenever it or its `Window` changes. 
+/// Two [`Territory`]s docked to the same side stack outward in, and North and South panels fit between East and West ones.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Docked(pub DockSide);
+
+/// Side of a `Window` a [`Docked`] [`Territory`] spans.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockSide {
+    North,
+    East,
+    South,
+    West
+}
+impl DockSide {
+    /// Docks are laid out in this order, so North and South panels only span what the East and West ones leave.
+    pub const LAYOUT_ORDER: [Self; 4] = [Self::West, Self::East, Self::North, Self::South];
+
+    /// Gets the side of a **worldspace** `window_rect` closest to a **worldspace** [`Rect`] inside it.
+    pub fn nearest(worldspace: Rect, window_rect: Rect) -> Self {
+        [
+            (Self::North, window_rect.max.y - worldspace.max.y),
+            (Self::East, window_rect.max.x - worldspace.max.x),
+            (Self::South, worldspace.min.y - window_rect.min.y),
+            (Self::West, worldspace.min.x - window_rect.min.x)
+        ]
+            .into_iter()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(side, _)| side)
+            .unwrap_or(Self::West)
+    }
+
+    /// Gets how thick a **worldspace** [`Rect`] is across this side. Width for East and West, height for North and South.
+    pub fn thickness(&self, worldspace: Rect) -> f32 {
+        match self {
+            Self::North | Self::South => worldspace.height(),
+            Self::East | Self::West => worldspace.width()
+        }
+    }
+
+    /// Gets the edge of a [`Territory`] docked to this side that faces into the `Window`. 
+    /// It's the only one that can be resized, since the others are held against the `Window` and any other docks.
+    pub fn inner_edge(&self) -> ResizableEdges {
+        match self {
+            Self::North => ResizableEdges::SOUTH,
+            Self::East => ResizableEdges::WEST,
+            Self::South => ResizableEdges::NORTH,
+            Self::West => ResizableEdges::EAST
+        }
+    }
+
+    /// Gets how far a **worldspace** [`Rect`] is from this side of a **worldspace** `window_rect`.
+    pub fn distance(&self, worldspace: Rect, window_rect: Rect) -> f32 {
+        match self {
+            Self::North => window_rect.max.y - worldspace.max.y,
+            Self::East => window_rect.max.x - worldspace.max.x,
+            Self::South => worldspace.min.y - window_rect.min.y,
+            Self::West => worldspace.min.x - window_rect.min.x
+        }
+    }
+
+    /// Carves a docked **worldspace** [`Rect`] `thickness` thick off this side of `free_space`, spanning all of it. 
+    /// `free_space` then shrinks past the docked [`Rect`] and a `gap`, ready for the next dock.
+    pub fn dock(&self, thickness: f32, free_space: &mut Rect, gap: Vec2) -> Rect {
+        let thickness = thickness.min(self.thickness(*free_space)).max(0.0);
+        let mut docked_rect = *free_space;
+        match self {
+            Self::North => {
+                docked_rect.min.y = free_space.max.y - thickness;
+                free_space.max.y = (docked_rect.min.y - gap.y).max(free_space.min.y);
+            },
+            Self::East => {
+                docked_rect.min.x = free_space.max.x - thickness;
+                free_space.max.x = (docked_rect.min.x - gap.x).max(free_space.min.x);
+            },
+            Self::South => {
+                docked_rect.max.y = free_space.min.y + thickness;
+                free_space.min.y = (docked_rect.max.y + gap.y).min(free_space.max.y);
+            },
+            Self::West => {
+                docked_rect.max.x = free_space.min.x + thickness;
+                free_space.min.x = (docked_rect.max.x + gap.x).min(free_space.max.x);
+            }
+        }
+        docked_rect
+    }
+
+    /// Moves a **worldspace** [`Rect`] out of a `blocked` one docked to this side, by pulling in its side facing the dock. 
+    /// If that would take it under `min_size`, it's slid away from the dock whole instead.
+    pub fn clear(&self, mut worldspace: Rect, blocked: Rect, min_size: Vec2) -> Rect {
+        let (axis, inner_edge) = match self {
+            Self::North => (1, blocked.min.y),
+            Self::East => (0, blocked.min.x),
+            Self::South => (1, blocked.max.y),
+            Self::West => (0, blocked.max.x)
+        };
+        let toward_max = matches!(self, Self::North | Self::East);
+        let shrunk_size = if toward_max {
+            inner_edge - worldspace.min[axis]
+        } else {
+            worldspace.max[axis] - inner_edge
+        };
+        if shrunk_size >= min_size[axis] {
+            if toward_max { worldspace.max[axis] = inner_edge; } else { worldspace.min[axis] = inner_edge; }
+        } else {
+            let slide = if toward_max { inner_edge - worldspace.max[axis] } else { inner_edge - worldspace.min[axis] };
+            worldspace.min[axis] += slide;
+            worldspace.max[axis] += slide;
+        }
+        worldspace
+    }
+}
+
+/// Marks a [`Territory`] as taking up the same fraction of its `Window` when the `Window` is resized, 
+/// instead of keeping its **worldspace** size and position.  
+/// \
+/// The fraction is whatever [`RectKit::relative_screenspace`] it had before the resize, so moving it moves the anchor too.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RelativeAnchored;
+
+/// Easing curves for an [`AnimateMove`], mapping linear progress in `0.0..=1.0` to eased progress.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EaseFunction {
+    Linear,
+    QuadraticIn,
+    #[default]
+    QuadraticOut,
+    QuadraticInOut,
+    CubicOut
+}
+impl EaseFunction {
+    /// Eased progress for a linear `t`, which is clamped to `0.0..=1.0`.
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EaseFunction::Linear => t,
+            EaseFunction::QuadraticIn => t * t,
+            EaseFunction::QuadraticOut => 1.0 - (1.0 - t) * (1.0 - t),
+            EaseFunction::QuadraticInOut => {
+                if t < 0.5 { 2.0 * t * t } else { 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0 }
+            },
+            EaseFunction::CubicOut => 1.0 - (1.0 - t).powi(3)
+        }
+    }
+}
+
+/// Opts a [`Territory`] into gliding its base node to a new expanse, instead of snapping there.  
+/// \
+/// Only the visuals are animated. [`Territory::expanse`] changes right away, so collision stays correct.
+/// A [`Territory`] being dragged or resized itself, with a [`MotionOrigin`], still snaps to follow the cursor.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct AnimateMove {
+    /// How long the glide takes.
+    pub duration: Duration,
+    /// How the glide is eased.
+    pub curve: EaseFunction
+}
+impl Default for AnimateMove {
+    fn default() -> Self {
+        AnimateMove { duration: Duration::from_millis(150), curve: EaseFunction::QuadraticOut }
+    }
+}
+
+/// An in-flight [`AnimateMove`] of a [`Territory`]'s base node, in [`RectKit::relative_screenspace`].  
+/// \
+/// Removed once the base node arrives.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MoveAnimation {
+    /// Where the base node started.
+    pub from: Rect,
+    /// Where the base node is headed.
+    pub to: Rect,
+    /// How long the base node has been moving.
+    pub elapsed: Duration
+}
+impl MoveAnimation {
+    /// Linear progress of the animation, from `0.0` to `1.0`.
+    pub fn progress(&self, animate_move: &AnimateMove) -> f32 {
+        if animate_move.duration.is_zero() {
+            return 1.0;
+        }
+        (self.elapsed.as_secs_f32() / animate_move.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Where the base node should be drawn right now.
+    pub fn current_rect(&self, animate_move: &AnimateMove) -> Rect {
+        let eased = animate_move.curve.ease(self.progress(animate_move));
+        Rect::from_corners(self.from.min.lerp(self.to.min, eased), self.from.max.lerp(self.to.max, eased))
+    }
+}
+
+/// Where every [`Territory`] in the `Window` was when a drag or resize of this [`Territory`] started.  
+/// \
+/// Lets the motion be canceled, putting back the dragged [`Territory`] and any neighbors it pushed along the way.
+/// Once canceled, the rest of the drag is ignored until it ends.
+#[derive(Component, Clone, Default)]
+pub struct MotionOrigin {
+    /// Every [`Territory`] in the `Window` and its expanse from before the motion.
+    pub expanses: Vec<(Entity, RectKit)>,
+    /// Whether the motion was started from a resize button instead of the drag node.
+    pub resizing: bool,
+    /// Whether the motion was canceled.
+    pub canceled: bool
+}
+impl MotionOrigin {
+    /// Whether the motion was canceled.
+    pub fn canceled(&self) -> bool {
+        self.canceled
+    }
+
+    /// Whether the motion is a resize instead of a drag.
+    pub fn resizing(&self) -> bool {
+        self.resizing
+    }
+}
+
+/// Where a [`Territory`] will land once the drag or resize in its `Window` ends, 
+/// while [`GlobalTerritorySettings::live_resize`] is off.  
+/// \
+/// The [`Territory`]'s own expanse stays put until then. Input keeps building on this expanse instead, 
+/// and every [`MoveRequest`] applied during the motion replaces it.
+#[derive(Component, Clone, Copy)]
+pub struct DeferredExpanse {
+    /// Proposed location of the [`Territory`].
+    pub expanse: RectKit
+}
+impl DeferredExpanse {
+    /// Gets the proposed location of the [`Territory`].
+    pub fn expanse(&self) -> RectKit {
+        self.expanse
+    }
+}
+
+/// Which sides of a [`Territory`] can be resized, as a set of bit flags. Defaults to [`ResizableEdges::ALL`].  
+/// \
+/// Only handles for enabled directions are spawned, and resizes in disabled directions are thrown out.
+/// Corner directions need both of their sides enabled.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResizableEdges(u8);
+
+impl Default for ResizableEdges {
+    fn default() -> Self {
+        ResizableEdges::ALL
+    }
+}
+
+impl std::ops::BitOr for ResizableEdges {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        ResizableEdges(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ResizableEdges {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl ResizableEdges {
+    pub const NONE: Self = ResizableEdges(0);
+    pub const NORTH: Self = ResizableEdges(1 << 0);
+    pub const EAST: Self = ResizableEdges(1 << 1);
+    pub const SOUTH: Self = ResizableEdges(1 << 2);
+    pub const WEST: Self = ResizableEdges(1 << 3);
+    pub const ALL: Self = ResizableEdges(0b1111);
+
+    /// Gets the raw bits.
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if every edge in `edges` is enabled here.
+    pub fn contains(&self, edges: ResizableEdges) -> bool {
+        self.0 & edges.0 == edges.0
+    }
+
+    /// Gets the edges a [`ResizeDirection`] moves. Corners move two, and [`ResizeDirection::All`] moves every one.
+    pub fn from_direction(resize_direction: &ResizeDirection) -> Self {
+        match resize_direction {
+            ResizeDirection::North { .. } => ResizableEdges::NORTH,
+            ResizeDirection::NorthEast { .. } => ResizableEdges::NORTH | ResizableEdges::EAST,
+            ResizeDirection::East { .. } => ResizableEdges::EAST,
+            ResizeDirection::SouthEast { .. } => ResizableEdges::SOUTH | ResizableEdges::EAST,
+            ResizeDirection::South { .. } => ResizableEdges::SOUTH,
+            ResizeDirection::SouthWest { .. } => ResizableEdges::SOUTH | ResizableEdges::WEST,
+            ResizeDirection::West { .. } => ResizableEdges::WEST,
+            ResizeDirection::NorthWest { .. } => ResizableEdges::NORTH | ResizableEdges::WEST,
+            ResizeDirection::All { .. } => ResizableEdges::ALL
+        }
+    }
+
+    /// Returns `true` if a resize in this [`ResizeDirection`] is allowed.
+    pub fn allows(&self, resize_direction: &ResizeDirection) -> bool {
+        self.contains(ResizableEdges::from_direction(resize_direction))
+    }
+
+    /// Pulls these moving edges of a **worldspace** [`Rect`] back in until it fits inside `max_size`.
+    /// The opposite edges stay put, so a northern edge grows upward and stops at `max_size.y`.  
+    /// \
+    /// An axis that's too big with neither of its edges moving is left alone, since this resize didn't grow it. 
+    /// If both of an axis' edges are moving, both are pulled in evenly around its center.
+    pub fn clamp_to_max_size(&self, mut worldspace: Rect, max_size: Vec2) -> Rect {
+        let center = worldspace.center();
+        if worldspace.width() > max_size.x {
+            match (self.contains(ResizableEdges::EAST), self.contains(ResizableEdges::WEST)) {
+                (true, true) => {
+                    worldspace.min.x = center.x - max_size.x * 0.5;
+                    worldspace.max.x = center.x + max_size.x * 0.5;
+                },
+                (true, false) => worldspace.max.x = worldspace.min.x + max_size.x,
+                (false, true) => worldspace.min.x = worldspace.max.x - max_size.x,
+                (false, false) => {}
+            }
+        }
+        if worldspace.height() > max_size.y {
+            match (self.contains(ResizableEdges::NORTH), self.contains(ResizableEdges::SOUTH)) {
+                (true, true) => {
+                    worldspace.min.y = center.y - max_size.y * 0.5;
+                    worldspace.max.y = center.y + max_size.y * 0.5;
+                },
+                (true, false) => worldspace.max.y = worldspace.min.y + max_size.y,
+                (false, true) => worldspace.min.y = worldspace.max.y - max_size.y,
+                (false, false) => {}
+            }
+        }
+        worldspace
+    }
+}
+
+/// Defines what library will be used to display UI. Add to a `Window` entity to set a default. Add to a `Territory`
+/// or a `Tab` entity to override that default. `Window`s without one get the [`DefaultDisplayLibrary`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayLibrary {
     BevyUi,
     BevyEgui,
+    #[default]
     BevySickle
 }
 
+/// [`DisplayLibrary`] given to every new [`TerritoryTabs`] `Window` that wasn't spawned with its own. 
+/// Set from the plugin, and [`DisplayLibrary::BevySickle`] by default.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DefaultDisplayLibrary(pub DisplayLibrary);
+
 /// Every UI library that handles resizing has this exact enum. This idea with having our own here 
 /// is to implement extension traits for translating to each library, but only in the modules that interact 
 /// with that library. Hopefully this will maintain both a decoupled architecture with the 
@@ -457,14 +1543,30 @@ pub enum ResizeDirection {
     South { southward_magnitude: ResizeMagnitude },
     SouthWest { southward_magnitude: ResizeMagnitude, westward_magnitude: ResizeMagnitude },
     West { westward_magnitude: ResizeMagnitude },
-    NorthWest { northward_magnitude: ResizeMagnitude, westward_magnitude: ResizeMagnitude }
+    NorthWest { northward_magnitude: ResizeMagnitude, westward_magnitude: ResizeMagnitude },
+    /// Every side at once, like growing or shrinking a [`Territory`] from its center. Has no resize button.
+    All { 
+        northward_magnitude: ResizeMagnitude, 
+        eastward_magnitude: ResizeMagnitude, 
+        southward_magnitude: ResizeMagnitude, 
+        westward_magnitude: ResizeMagnitude 
+    }
 }
 
 impl ResizeDirection {
     
-    /// Width of the resizing bar buttons, and both the height and width of the corner ones.
+    /// Default [`GlobalTerritorySettings::resize_handle_size`].
     pub const SIZE: f32 = 5.0;
 
+    /// Sign convention for the northern side. **Screenspace** `+y` goes down, so a negative `y` delta advances it.
+    pub const NORTH_ADVANCES_POSITIVE: bool = false;
+    /// Sign convention for the eastern side. A positive `x` delta advances it.
+    pub const EAST_ADVANCES_POSITIVE: bool = true;
+    /// Sign convention for the southern side. **Screenspace** `+y` goes down, so a positive `y` delta advances it.
+    pub const SOUTH_ADVANCES_POSITIVE: bool = true;
+    /// Sign convention for the western side. A negative `x` delta advances it.
+    pub const WEST_ADVANCES_POSITIVE: bool = false;
+
     /// Helper for iterating through all the ordinal directions.
     pub const ORDINAL: [Self; 8] = [
         Self::North { northward_magnitude: ResizeMagnitude::None },
@@ -477,6 +1579,14 @@ impl ResizeDirection {
         Self::NorthWest { northward_magnitude: ResizeMagnitude::None, westward_magnitude: ResizeMagnitude::None }
     ];
 
+    /// [`ResizeDirection::All`] with every [`ResizeMagnitude::None`].
+    pub const ALL: Self = Self::All { 
+        northward_magnitude: ResizeMagnitude::None, 
+        eastward_magnitude: ResizeMagnitude::None, 
+        southward_magnitude: ResizeMagnitude::None, 
+        westward_magnitude: ResizeMagnitude::None 
+    };
+
     /// Gets the [`ResizeMagnitude`] wrapped within a single-sided cardinal direction.
     /// For multisided, call [`ResizeDirection::get_cardinal_directions`] first and iterate.
     ///   
@@ -487,8 +1597,8 @@ impl ResizeDirection {
             ResizeDirection::East { eastward_magnitude } => { *eastward_magnitude },
             ResizeDirection::South { southward_magnitude } => { *southward_magnitude },
             ResizeDirection::West { westward_magnitude } => { *westward_magnitude },
-            ResizeDirection::NorthEast {..} | ResizeDirection::SouthEast {..} | ResizeDirection::SouthWest {..} | ResizeDirection::NorthWest {..}
-            => { ResizeMagnitude::None }
+            ResizeDirection::NorthEast {..} | ResizeDirection::SouthEast {..} | ResizeDirection::SouthWest {..} | ResizeDirection::NorthWest {..} 
+            | ResizeDirection::All {..} => { ResizeMagnitude::None }
         }
     }
 
@@ -530,10 +1640,38 @@ impl ResizeDirection {
                     southward_magnitude: northward_magnitude.get_opposite(),
                     eastward_magnitude: westward_magnitude.get_opposite()
                 } 
+            },
+            Self::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                ResizeDirection::All {
+                    northward_magnitude: southward_magnitude.get_opposite(),
+                    eastward_magnitude: westward_magnitude.get_opposite(),
+                    southward_magnitude: northward_magnitude.get_opposite(),
+                    westward_magnitude: eastward_magnitude.get_opposite()
+                }
             }
         }
     }
 
+    /// Gets the corner of a **worldspace** [`Rect`] that stays put while resizing in this direction. 
+    /// [`ResizeDirection::NorthWest`] gives the south east corner.  
+    ///   
+    /// Sides only move along one axis, so along the other axis this is the [`Rect`]'s center. 
+    /// [`ResizeDirection::All`] moves both sides of each axis, so it's anchored at the center.
+    pub fn anchor_corner(&self, worldspace_rect: Rect) -> Vec2 {
+        let resizing_edges = ResizableEdges::from_direction(self);
+        let anchor = |min_edge: ResizableEdges, max_edge: ResizableEdges, min: f32, max: f32, center: f32| {
+            match (resizing_edges.contains(min_edge), resizing_edges.contains(max_edge)) {
+                (false, true) => min,
+                (true, false) => max,
+                _ => center
+            }
+        };
+        Vec2::new(
+            anchor(ResizableEdges::WEST, ResizableEdges::EAST, worldspace_rect.min.x, worldspace_rect.max.x, worldspace_rect.center().x),
+            anchor(ResizableEdges::SOUTH, ResizableEdges::NORTH, worldspace_rect.min.y, worldspace_rect.max.y, worldspace_rect.center().y)
+        )
+    }
+
     /// Returns a [`Vec`] of the [`ResizeDirection`] broken down into its composite cardinal points.  
     ///   
     /// [`ResizeDirection::SouthWest`] will give `vec!(ResizeDirection::South, ResizeDirection::West)`. All magnitudes transfer.
@@ -567,15 +1705,33 @@ impl ResizeDirection {
             Self::NorthWest { northward_magnitude, westward_magnitude } => { 
                 result_vec.push(ResizeDirection::North { northward_magnitude: *northward_magnitude }); 
                 result_vec.push(ResizeDirection::West { westward_magnitude: *westward_magnitude }); 
+            },
+            Self::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                result_vec.push(ResizeDirection::North { northward_magnitude: *northward_magnitude }); 
+                result_vec.push(ResizeDirection::East { eastward_magnitude: *eastward_magnitude }); 
+                result_vec.push(ResizeDirection::South { southward_magnitude: *southward_magnitude }); 
+                result_vec.push(ResizeDirection::West { westward_magnitude: *westward_magnitude }); 
             }
         }
         result_vec
     }
 
+    /// Gets the resize arrow [`CursorIcon`] for hovering a resize button in this direction.
+    pub fn cursor_icon(&self) -> CursorIcon {
+        match self {
+            ResizeDirection::North {..} | ResizeDirection::South {..} => CursorIcon::NsResize,
+            ResizeDirection::East {..} | ResizeDirection::West {..} => CursorIcon::EwResize,
+            ResizeDirection::NorthEast {..} | ResizeDirection::SouthWest {..} => CursorIcon::NeswResize,
+            ResizeDirection::NorthWest {..} | ResizeDirection::SouthEast {..} => CursorIcon::NwseResize,
+            ResizeDirection::All {..} => CursorIcon::Move
+        }
+    }
+
     /// If you're using a 3x3 CSS grid node to place the resize drag buttons, 
     /// you can use this to get the appropriate (row, column) location.  
     ///   
-    /// If called on a [`ResizeDirection::West`], then you'll get `(GridPlacement::start(2), GridPlacement::start(1))`.
+    /// If called on a [`ResizeDirection::West`], then you'll get `(GridPlacement::start(2), GridPlacement::start(1))`. 
+    /// [`ResizeDirection::All`] has no button, and gets the middle cell.
     pub fn get_css_grid_location(&self) -> (GridPlacement, GridPlacement) {
         let (row, column) = match self {
             Self::North {..} => { ( 1, 2) },
@@ -585,7 +1741,8 @@ impl ResizeDirection {
             Self::South {..} => { (3, 2) },
             Self::SouthWest {..} => { (3, 1) },
             Self::West {..} => { (2, 1) },
-            Self::NorthWest {..} => { (1, 1) }
+            Self::NorthWest {..} => { (1, 1) },
+            Self::All {..} => { (2, 2) }
         };
         (GridPlacement::start(row), GridPlacement::start(column))
     }
@@ -669,13 +1826,36 @@ impl ResizeDirection {
                     ResizeMagnitude::Retreating(x) => { rect.min.x += x }
                 }
             },
+            Self::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                match northward_magnitude {
+                    ResizeMagnitude::None => {  },
+                    ResizeMagnitude::Advancing(y) => { rect.min.y -= y },
+                    ResizeMagnitude::Retreating(y) => { rect.min.y += y }
+                };
+                match eastward_magnitude {
+                    ResizeMagnitude::None => {  },
+                    ResizeMagnitude::Advancing(x) => { rect.max.x += x },
+                    ResizeMagnitude::Retreating(x) => { rect.max.x -= x }
+                };
+                match southward_magnitude {
+                    ResizeMagnitude::None => {  },
+                    ResizeMagnitude::Advancing(y) => { rect.max.y += y },
+                    ResizeMagnitude::Retreating(y) => { rect.max.y -= y }
+                };
+                match westward_magnitude {
+                    ResizeMagnitude::None => {  },
+                    ResizeMagnitude::Advancing(x) => { rect.min.x -= x },
+                    ResizeMagnitude::Retreating(x) => { rect.min.x += x }
+                }
+            }
         }
         rect
     }
 
     /// Add the correct mouse delta [`Vec2`], depending on [`ResizeDirection`], to a [`Rect`] in **screenspace** coordinates.  
     ///  
-    /// If, say, [`ResizeDirection::SouthWest`], then the returned [`Rect`] will be the result of `rect.min.x += delta.x; rect.max.y += delta.y`:
+    /// If, say, [`ResizeDirection::SouthWest`], then the returned [`Rect`] will be the result of `rect.min.x += delta.x; rect.max.y += delta.y`. 
+    /// [`ResizeDirection::All`] moves the south east corner by `delta` and the north west corner the other way, around the center.
     pub fn add_delta_to_rect(&self, mut rect: Rect, delta: Vec2) -> Rect {
         match self {
             Self::North {..} => { rect.min.y += delta.y; },
@@ -686,10 +1866,47 @@ impl ResizeDirection {
             Self::SouthWest {..} => { rect.min.x += delta.x; rect.max.y += delta.y },
             Self::West {..} => { rect.min.x += delta.x },
             Self::NorthWest {..} => { rect.min += delta },
+            Self::All {..} => { rect.min -= delta; rect.max += delta },
         }
         rect
     }
 
+    /// Reshapes a **screenspace** [`Rect`] from a corner resize so its `width / height` is `aspect_ratio`, 
+    /// growing whichever side fell short while the opposite corner stays put. [`ResizeDirection::All`] grows around the center instead.  
+    ///   
+    /// Single-side resizes are returned untouched, since there's no second side to adjust.
+    pub fn keep_aspect_ratio(&self, rect: Rect, aspect_ratio: f32) -> Rect {
+        if matches!(self, Self::North {..} | Self::East {..} | Self::South {..} | Self::West {..}) 
+            || !aspect_ratio.is_finite() || aspect_ratio <= 0.0 {
+            return rect;
+        }
+
+        let mut size = rect.size();
+        if size.x / size.y > aspect_ratio {
+            size.y = size.x / aspect_ratio;
+        }
+        else {
+            size.x = size.y * aspect_ratio;
+        }
+        if matches!(self, Self::All {..}) {
+            return Rect::from_center_size(rect.center(), size);
+        }
+
+        // Screenspace, so the southern side is at max.y.
+        let resizing_edges = ResizableEdges::from_direction(self);
+        let east = resizing_edges.contains(ResizableEdges::EAST);
+        let south = resizing_edges.contains(ResizableEdges::SOUTH);
+        let anchor = Vec2::new(
+            if east { rect.min.x } else { rect.max.x },
+            if south { rect.min.y } else { rect.max.y }
+        );
+        let growth = Vec2::new(
+            if east { size.x } else { -size.x },
+            if south { size.y } else { -size.y }
+        );
+        Rect::from_corners(anchor, anchor + growth)
+    }
+
     /// Returns `true` if the [`ResizeDirection`] has more than one advancing or retreating magnitude.
     pub fn is_multi_side_resize(&self) -> bool {
         let mut counter = 0;
@@ -710,6 +1927,11 @@ impl ResizeDirection {
             Self::NorthWest { northward_magnitude, westward_magnitude } => { 
                 if matches!(northward_magnitude, ResizeMagnitude::Advancing(_) | ResizeMagnitude::Retreating(_)) { counter += 1;} 
                 if matches!(westward_magnitude, ResizeMagnitude::Advancing(_) | ResizeMagnitude::Retreating(_)) { counter += 1;} 
+            },
+            Self::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                counter += [northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude].into_iter()
+                    .filter(|magnitude| !magnitude.is_none())
+                    .count();
             }
         }
         if counter > 1 { true } else { false }
@@ -747,6 +1969,10 @@ impl ResizeDirection {
             Self::NorthWest { northward_magnitude, westward_magnitude } => { 
                 if matches!(northward_magnitude, ResizeMagnitude::Advancing(_) | ResizeMagnitude::Retreating(_)) { return false; }
                 if matches!(westward_magnitude, ResizeMagnitude::Advancing(_) | ResizeMagnitude::Retreating(_)) { return false; }
+            },
+            Self::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                return [northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude].into_iter()
+                    .all(ResizeMagnitude::is_none);
             }
         }
         true
@@ -784,106 +2010,101 @@ impl ResizeDirection {
             Self::NorthWest { northward_magnitude, westward_magnitude } => { 
                 if matches!(northward_magnitude, ResizeMagnitude::Retreating(_)) { return true; }
                 if matches!(westward_magnitude, ResizeMagnitude::Retreating(_)) { return true; }
+            },
+            Self::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                return [northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude].into_iter()
+                    .any(ResizeMagnitude::is_retreating);
             }
         }
         false
     }
 
-    /// Using a given **screenspace** delta, set all [`ResizeMagnitude`]s.
+    /// Using a given **screenspace** delta, set all [`ResizeMagnitude`]s.  
+    ///   
+    /// Each side uses its sign convention from [`ResizeDirection::NORTH_ADVANCES_POSITIVE`] and friends. 
+    /// [`ResizeDirection::All`] takes `delta` as the south east corner's move, with the north west corner moving the other way, 
+    /// like [`ResizeDirection::add_delta_to_rect`].
     pub fn set_magnitudes_from_delta(&mut self, delta: Vec2) -> &mut Self {
         match self {
             ResizeDirection::North { northward_magnitude } => { 
-                *northward_magnitude = match delta.y {
-                    y if y < 0.0 => { ResizeMagnitude::Advancing(y.abs()) },
-                    y if y == 0.0 => { ResizeMagnitude::None },
-                    y if y > 0.0 => { ResizeMagnitude::Retreating(y) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.y); ResizeMagnitude::None }
-                };
+                *northward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::NORTH_ADVANCES_POSITIVE);
             },
             ResizeDirection::NorthEast { northward_magnitude, eastward_magnitude } => {
-                *northward_magnitude = match delta.y {
-                    y if y < 0.0 => { ResizeMagnitude::Advancing(y.abs()) },
-                    y if y == 0.0 => { ResizeMagnitude::None },
-                    y if y > 0.0 => { ResizeMagnitude::Retreating(y) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.y); ResizeMagnitude::None }
-                };
-                *eastward_magnitude = match delta.x {
-                    x if x < 0.0 => { ResizeMagnitude::Retreating(x.abs()) },
-                    x if x == 0.0 => { ResizeMagnitude::None },
-                    x if x > 0.0 => { ResizeMagnitude::Advancing(x) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.x); ResizeMagnitude::None }
-                };
+                *northward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::NORTH_ADVANCES_POSITIVE);
+                *eastward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::EAST_ADVANCES_POSITIVE);
             },
             ResizeDirection::East { eastward_magnitude } => {
-                *eastward_magnitude = match delta.x {
-                    x if x < 0.0 => { ResizeMagnitude::Retreating(x.abs()) },
-                    x if x == 0.0 => { ResizeMagnitude::None },
-                    x if x > 0.0 => { ResizeMagnitude::Advancing(x) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.x); ResizeMagnitude::None }
-                };
+                *eastward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::EAST_ADVANCES_POSITIVE);
             },
             ResizeDirection::SouthEast { southward_magnitude, eastward_magnitude } => {
-                *southward_magnitude = match delta.y {
-                    y if y < 0.0 => { ResizeMagnitude::Retreating(y.abs()) },
-                    y if y == 0.0 => { ResizeMagnitude::None },
-                    y if y > 0.0 => { ResizeMagnitude::Advancing(y) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.y); ResizeMagnitude::None }
-                };
-                *eastward_magnitude = match delta.x {
-                    x if x < 0.0 => { ResizeMagnitude::Retreating(x.abs()) },
-                    x if x == 0.0 => { ResizeMagnitude::None },
-                    x if x > 0.0 => { ResizeMagnitude::Advancing(x) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.x); ResizeMagnitude::None }
-                };
+                *southward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::SOUTH_ADVANCES_POSITIVE);
+                *eastward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::EAST_ADVANCES_POSITIVE);
             },
             ResizeDirection::South { southward_magnitude } => {
-                *southward_magnitude = match delta.y {
-                    y if y < 0.0 => { ResizeMagnitude::Retreating(y.abs()) },
-                    y if y == 0.0 => { ResizeMagnitude::None },
-                    y if y > 0.0 => { ResizeMagnitude::Advancing(y) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.y); ResizeMagnitude::None }
-                };
+                *southward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::SOUTH_ADVANCES_POSITIVE);
             },
             ResizeDirection::SouthWest { southward_magnitude, westward_magnitude } => {
-                *southward_magnitude = match delta.y {
-                    y if y < 0.0 => { ResizeMagnitude::Retreating(y.abs()) },
-                    y if y == 0.0 => { ResizeMagnitude::None },
-                    y if y > 0.0 => { ResizeMagnitude::Advancing(y) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.y); ResizeMagnitude::None }
-                };
-                *westward_magnitude = match delta.x {
-                    x if x < 0.0 => { ResizeMagnitude::Advancing(x.abs()) },
-                    x if x == 0.0 => { ResizeMagnitude::None },
-                    x if x > 0.0 => { ResizeMagnitude::Retreating(x) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.x); ResizeMagnitude::None }
-                };
+                *southward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::SOUTH_ADVANCES_POSITIVE);
+                *westward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::WEST_ADVANCES_POSITIVE);
             },
-            ResizeDirection::West { westward_magnitude }=> {
-                *westward_magnitude = match delta.x {
-                    x if x < 0.0 => { ResizeMagnitude::Advancing(x.abs()) },
-                    x if x == 0.0 => { ResizeMagnitude::None },
-                    x if x > 0.0 => { ResizeMagnitude::Retreating(x) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.x); ResizeMagnitude::None }
-                };
+            ResizeDirection::West { westward_magnitude } => {
+                *westward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::WEST_ADVANCES_POSITIVE);
             },
             ResizeDirection::NorthWest { northward_magnitude, westward_magnitude } => {
-                *northward_magnitude = match delta.y {
-                    y if y < 0.0 => { ResizeMagnitude::Advancing(y.abs()) },
-                    y if y == 0.0 => { ResizeMagnitude::None },
-                    y if y > 0.0 => { ResizeMagnitude::Retreating(y) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.y); ResizeMagnitude::None }
-                };
-                *westward_magnitude = match delta.x {
-                    x if x < 0.0 => { ResizeMagnitude::Advancing(x.abs()) },
-                    x if x == 0.0 => { ResizeMagnitude::None },
-                    x if x > 0.0 => { ResizeMagnitude::Retreating(x) },
-                    _ => { warn!("Unexpected match result from {:?}", delta.x); ResizeMagnitude::None }
-                };
+                *northward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::NORTH_ADVANCES_POSITIVE);
+                *westward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::WEST_ADVANCES_POSITIVE);
+            },
+            ResizeDirection::All { northward_magnitude, eastward_magnitude, southward_magnitude, westward_magnitude } => {
+                *northward_magnitude = ResizeMagnitude::from_signed_extent(-delta.y, Self::NORTH_ADVANCES_POSITIVE);
+                *eastward_magnitude = ResizeMagnitude::from_signed_extent(delta.x, Self::EAST_ADVANCES_POSITIVE);
+                *southward_magnitude = ResizeMagnitude::from_signed_extent(delta.y, Self::SOUTH_ADVANCES_POSITIVE);
+                *westward_magnitude = ResizeMagnitude::from_signed_extent(-delta.x, Self::WEST_ADVANCES_POSITIVE);
             }
         };
         self
     }
 
+    /// Works out which [`ResizeDirection`] turns the **screenspace** `current` [`Rect`] into `proposed`, magnitudes included.  
+    ///   
+    /// Every side moving gives [`ResizeDirection::All`]. Returns `None` if no side moved, 
+    /// or if some but not all of two opposite sides moved, since no single direction does that.
+    pub fn from_screenspace_change(current: Rect, proposed: Rect) -> Option<Self> {
+        let moved = |from: f32, to: f32| (to - from).abs() > f32::EPSILON * 100.0;
+        let north = moved(current.min.y, proposed.min.y);
+        let east = moved(current.max.x, proposed.max.x);
+        let south = moved(current.max.y, proposed.max.y);
+        let west = moved(current.min.x, proposed.min.x);
+
+        // Each side of an all sides resize can move its own distance.
+        if north && east && south && west {
+            return Some(Self::All {
+                northward_magnitude: ResizeMagnitude::from_signed_extent(proposed.min.y - current.min.y, Self::NORTH_ADVANCES_POSITIVE),
+                eastward_magnitude: ResizeMagnitude::from_signed_extent(proposed.max.x - current.max.x, Self::EAST_ADVANCES_POSITIVE),
+                southward_magnitude: ResizeMagnitude::from_signed_extent(proposed.max.y - current.max.y, Self::SOUTH_ADVANCES_POSITIVE),
+                westward_magnitude: ResizeMagnitude::from_signed_extent(proposed.min.x - current.min.x, Self::WEST_ADVANCES_POSITIVE)
+            });
+        }
+
+        let mut resize_direction = match (north, east, south, west) {
+            (true, false, false, false) => Self::ORDINAL[0],
+            (true, true, false, false) => Self::ORDINAL[1],
+            (false, true, false, false) => Self::ORDINAL[2],
+            (false, true, true, false) => Self::ORDINAL[3],
+            (false, false, true, false) => Self::ORDINAL[4],
+            (false, false, true, true) => Self::ORDINAL[5],
+            (false, false, false, true) => Self::ORDINAL[6],
+            (true, false, false, true) => Self::ORDINAL[7],
+            _ => { return None; }
+        };
+
+        let delta = Vec2::new(
+            if east { proposed.max.x - current.max.x } else { proposed.min.x - current.min.x },
+            if north { proposed.min.y - current.min.y } else { proposed.max.y - current.max.y }
+        );
+        resize_direction.set_magnitudes_from_delta(delta);
+        Some(resize_direction)
+    }
+
 }
 
 /// What is the trend of the [`ResizeDirection`]? Is it growing or shrinking the [`Rect`]?
@@ -896,6 +2117,27 @@ pub enum ResizeMagnitude {
 }
 
 impl ResizeMagnitude {
+    /// Creates a [`ResizeMagnitude`] from a signed distance along one axis.  
+    ///   
+    /// `advancing_is_positive` is the sign convention of the side being moved. 
+    /// If the sign of `raw` matches it, the side is [`ResizeMagnitude::Advancing`], otherwise [`ResizeMagnitude::Retreating`].
+    /// Zero gives [`ResizeMagnitude::None`].
+    pub fn from_signed_extent(raw: f32, advancing_is_positive: bool) -> Self {
+        if raw.is_nan() {
+            warn!("Unexpected resize extent {:?}", raw);
+            return ResizeMagnitude::None;
+        }
+        if raw == 0.0 {
+            return ResizeMagnitude::None;
+        }
+        if (raw > 0.0) == advancing_is_positive {
+            ResizeMagnitude::Advancing(raw.abs())
+        }
+        else {
+            ResizeMagnitude::Retreating(raw.abs())
+        }
+    }
+
     /// Get the little [`f32`] number inside representing logical pixels, 
     /// without having to use clunky `var.0` syntax.  
     ///   
@@ -968,8 +2210,9 @@ impl ResizeMagnitude {
 
 /// Contains every [`Territory`] [`Entity`] neighbor that this one is linked to, separated by what side they're linked on.  
 ///   
-/// Used for graph traversals when handling linked move requests.
-#[derive(Component)]
+/// Used for graph traversals when handling linked move requests. 
+/// Kept up to date from [`Territory`] adjacency by [`crate::systems_territory::rebuild_cardinal_connections`].
+#[derive(Component, Clone, Debug, PartialEq)]
 pub struct CardinalConnections {
     pub northern: Vec<Entity>,
     pub eastern: Vec<Entity>,
@@ -984,6 +2227,41 @@ impl Default for CardinalConnections {
 }
 
 impl CardinalConnections {
+    /// How far off two facing sides can be, beyond [`GlobalTerritorySettings::outer_margins`], and still connect. 
+    /// Also how much two sides have to overlap along their length, so touching corners don't connect.
+    pub const ADJACENCY_TOLERANCE: f32 = 1.0;
+
+    /// Works out the connections of a **worldspace** [`Rect`] to each of its `neighbors`.  
+    /// \
+    /// A neighbor is connected on a side if its facing side is [`GlobalTerritorySettings::outer_margins`] away, 
+    /// give or take [`CardinalConnections::ADJACENCY_TOLERANCE`], and the two sides overlap along their length.
+    pub fn from_adjacency(worldspace: Rect, neighbors: impl IntoIterator<Item = (Entity, Rect)>, outer_margins: Vec2) -> Self {
+        let tolerance = CardinalConnections::ADJACENCY_TOLERANCE;
+        let is_adjacent = |gap: f32, margin: f32| (gap - margin).abs() <= tolerance || gap.abs() <= tolerance;
+        let spans_overlap = |min_a: f32, max_a: f32, min_b: f32, max_b: f32| max_a.min(max_b) - min_a.max(min_b) > tolerance;
+
+        let mut connections = CardinalConnections::default();
+        for (neighbor_entity, neighbor_rect) in neighbors {
+            if spans_overlap(worldspace.min.x, worldspace.max.x, neighbor_rect.min.x, neighbor_rect.max.x) {
+                if is_adjacent(neighbor_rect.min.y - worldspace.max.y, outer_margins.y) {
+                    connections.northern.push(neighbor_entity);
+                }
+                else if is_adjacent(worldspace.min.y - neighbor_rect.max.y, outer_margins.y) {
+                    connections.southern.push(neighbor_entity);
+                }
+            }
+            if spans_overlap(worldspace.min.y, worldspace.max.y, neighbor_rect.min.y, neighbor_rect.max.y) {
+                if is_adjacent(neighbor_rect.min.x - worldspace.max.x, outer_margins.x) {
+                    connections.eastern.push(neighbor_entity);
+                }
+                else if is_adjacent(worldspace.min.x - neighbor_rect.max.x, outer_margins.x) {
+                    connections.western.push(neighbor_entity);
+                }
+            }
+        }
+        connections
+    }
+
     /// Gets a copy of the northern connections in an [`Entity`] [`Vec`].
     pub fn northern(&self) -> Vec<Entity> {
         self.northern.clone()
@@ -1027,10 +2305,87 @@ impl CardinalConnections {
             ResizeDirection::South{..} => { result_vec.extend(&self.southern) },
             ResizeDirection::SouthWest{..} => { result_vec.extend(&self.southern); result_vec.extend(&self.western) },
             ResizeDirection::West{..} => { result_vec.extend(&self.western) },
-            ResizeDirection::NorthWest{..} => { result_vec.extend(&self.northern); result_vec.extend(&self.western) }
+            ResizeDirection::NorthWest{..} => { result_vec.extend(&self.northern); result_vec.extend(&self.western) },
+            ResizeDirection::All{..} => { result_vec.extend(self.get_all_vec()) }
         }
         result_vec
     }
+
+    /// Gets every neighbor linked in a [`ResizeDirection`]. Magnitudes are ignored. 
+    /// Corner directions get both adjacent sides, like [`CardinalConnections::get_resize_direction_vec`].
+    pub fn neighbors_in(&self, direction: ResizeDirection) -> Vec<Entity> {
+        self.get_resize_direction_vec(direction)
+    }
+}
+
+/// [`SystemParam`] for asking about a [`Territory`]'s neighbors without fetching its [`CardinalConnections`] by hand.  
+/// \
+/// Answers from the [`CardinalConnections`] as of the last [`crate::systems_territory::rebuild_cardinal_connections`].
+#[derive(SystemParam)]
+pub struct TerritoryGraph<'w, 's> {
+    territory_query: Query<'w, 's, (&'static Territory, &'static CardinalConnections)>
+}
+
+impl TerritoryGraph<'_, '_> {
+    /// Gets every neighbor of a [`Territory`] in a [`ResizeDirection`]. 
+    /// Empty if it has none there, or isn't a connected [`Territory`].
+    pub fn neighbors(&self, territory_entity: Entity, direction: ResizeDirection) -> Vec<Entity> {
+        self.territory_query.get(territory_entity)
+            .map(|(_, connections)| connections.neighbors_in(direction))
+            .unwrap_or_default()
+    }
+
+    /// Gets the neighbor of a [`Territory`] in a [`ResizeDirection`] whose center is closest to its own. 
+    /// Meant for picking a single target, like moving focus with the keyboard.
+    pub fn nearest_neighbor(&self, territory_entity: Entity, direction: ResizeDirection) -> Option<Entity> {
+        let (territory, connections) = self.territory_query.get(territory_entity).ok()?;
+        let center = territory.expanse.worldspace().center();
+        connections.neighbors_in(direction).into_iter()
+            .filter_map(|neighbor_entity| {
+                let (neighbor, _) = self.territory_query.get(neighbor_entity).ok()?;
+                Some((neighbor_entity, neighbor.expanse.worldspace().center().distance_squared(center)))
+            })
+            .min_by(|(_, distance_a), (_, distance_b)| distance_a.total_cmp(distance_b))
+            .map(|(neighbor_entity, _)| neighbor_entity)
+    }
+
+    /// Whether two [`Territory`]s are linked on any side.
+    pub fn are_neighbors(&self, territory_entity: Entity, other_entity: Entity) -> bool {
+        self.territory_query.get(territory_entity)
+            .is_ok_and(|(_, connections)| connections.get_all_vec().contains(&other_entity))
+    }
+}
+
+/// [`SystemParam`] for going through the [`Territory`]s of a `Window` in the order they're drawn, 
+/// without collecting the `Window`'s children and sorting them by hand.
+#[derive(SystemParam)]
+pub struct TerritoryStack<'w, 's> {
+    window_query: Query<'w, 's, &'static Children, With<Window>>,
+    territory_query: Query<'w, 's, (Entity, &'static Territory)>,
+    base_node_query: Query<'w, 's, &'static ZIndex, With<TerritoryBaseNode>>
+}
+
+impl TerritoryStack<'_, '_> {
+    /// Gets every [`Territory`] in a `Window` front to back, so the one drawn on top comes first.  
+    /// \
+    /// Sorted by the global, then local, [`ZIndex`] of each base node, with a missing one or base node counting as zero. 
+    /// Ties keep the order the [`Territory`]s were added to the `Window`, latest first, since bevy_ui draws later siblings on top.
+    pub fn territories_in_window(&self, window_entity: Entity) -> impl Iterator<Item = (Entity, &Territory)> {
+        let z_index_of = |territory: &Territory| {
+            match territory.base_node().and_then(|base_node_entity| self.base_node_query.get(base_node_entity).ok()) {
+                Some(ZIndex::Global(z_index)) => (*z_index, 0),
+                Some(ZIndex::Local(z_index)) => (0, *z_index),
+                None => (0, 0)
+            }
+        };
+        let mut territories: Vec<(Entity, &Territory)> = self.window_query.get(window_entity)
+            .map(|window_children| self.territory_query.iter_many(window_children).collect())
+            .unwrap_or_default();
+        territories.reverse();
+        // Stable, so the reversed insertion order holds between equal ZIndex values.
+        territories.sort_by_key(|(_, territory)| std::cmp::Reverse(z_index_of(territory)));
+        territories.into_iter()
+    }
 }
 
 /// Marks a [`TerritoryTabs`] UI element as having been commanded to move without changing size. Entities with this component will be processed 
@@ -1039,7 +2394,8 @@ impl CardinalConnections {
 pub struct DragRequest {
     /// Collection of [`Rect`]s describing the [`DragRequest`]'s proposed location in the `Window`.
     pub proposed_expanse: RectKit,
-    /// Drag vector in **screenspace** coordinates. Flip the y to get a worldspace delta.
+    /// Drag vector in **screenspace** coordinates. Flip the y to get a worldspace delta, 
+    /// such as with [`RectKit::flip_y_screen_to_world`] and a `window_height` of `0.0`.
     pub drag_delta: Vec2
 }
 
@@ -1179,6 +2535,12 @@ impl MoveRequest {
         }
     }
 
+    /// Creates a [`MoveRequestType::Unknown`] [`MoveRequest`] to a **screenspace** [`Rect`], 
+    /// for display libraries that only know where the UI element ended up.
+    pub fn from_screenspace_rect(screenspace: Rect, window_width: f32, window_height: f32) -> Self {
+        MoveRequest::new(RectKit::from_screenspace(screenspace, window_width, window_height), MoveRequestType::Unknown)
+    }
+
     /// Gets the [`RectKit`] containing the proposed [`Rect`]s UI element wants to move to.
     pub fn proposed_expanse(&self) -> RectKit {self.proposed_expanse}
 
@@ -1268,7 +2630,11 @@ pub struct Territory {
     /// [`Entity`] ID of the node area where the [`Territory`] will sense drag interactions.
     pub drag_node: Option<Entity>,
     /// [`Entity`] ID of the base resize grid node.
-    pub resize_node: Option<Entity>
+    pub resize_node: Option<Entity>,
+    /// [`Entity`] ID of the clipped content viewport node, where tab content is displayed and scrolled.
+    pub content_node: Option<Entity>,
+    /// [`Entity`] IDs of the tabs organized in this [`Territory`], in display order.
+    pub tabs: Vec<Entity>
 
 }
 impl Default for Territory {
@@ -1277,18 +2643,24 @@ impl Default for Territory {
             expanse: RectKit::default(),
             base_node: None,
             drag_node: None,
-            resize_node: None
+            resize_node: None,
+            content_node: None,
+            tabs: Vec::new()
         }
     }
 }
 impl Territory {
+    /// Width of the visual border drawn around every [`Territory`], in logical pixels.
+    pub const BORDER_WIDTH: f32 = 1.0;
+
     pub fn new(
         expanse: RectKit,
         base_node: Option<Entity>,
         drag_node: Option<Entity>,
-        resize_node: Option<Entity>
+        resize_node: Option<Entity>,
+        content_node: Option<Entity>
     ) -> Self {
-            Territory { expanse, base_node, drag_node, resize_node }
+            Territory { expanse, base_node, drag_node, resize_node, content_node, tabs: Vec::new() }
         }
 
     /// Creates a [`Territory`] with all zero-sized [`Rect`]s.
@@ -1301,6 +2673,11 @@ impl Territory {
         self.expanse
     }
 
+    /// Gets the **screenspace** [`Rect`] of the [`Territory`]. Same as [`RectKit::screenspace`] on its expanse.
+    pub fn screenspace_rect(&self) -> Rect {
+        self.expanse.screenspace()
+    }
+
     /// Gets the current base node.
     pub fn base_node(&self) -> Option<Entity> {
         self.base_node
@@ -1316,6 +2693,266 @@ impl Territory {
         self.resize_node
     }
 
+    /// Gets the current content viewport node.
+    pub fn content_node(&self) -> Option<Entity> {
+        self.content_node
+    }
+
+    /// Gets the tabs organized in this [`Territory`], in display order.
+    pub fn tabs(&self) -> &[Entity] {
+        &self.tabs
+    }
+
+    /// Returns `true` if no tabs are organized in this [`Territory`].
+    pub fn has_no_tabs(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Checks if a **worldspace** position is anywhere inside this [`Territory`], resize handles included.
+    pub fn contains_worldspace(&self, worldspace_pos: Vec2) -> bool {
+        self.expanse.worldspace().contains(worldspace_pos)
+    }
+
+    /// Checks if a **screenspace** position is anywhere inside this [`Territory`], resize handles included.
+    pub fn contains_screenspace(&self, screenspace_pos: Vec2) -> bool {
+        self.expanse.screenspace().contains(screenspace_pos)
+    }
+
+    /// Gets the **worldspace** area of this [`Territory`], resize handles included.
+    pub fn area(&self) -> f32 {
+        self.expanse.worldspace().size().element_product()
+    }
+
+    /// Gets the **worldspace** [`Rect`] left over for content, once the ring of resize handles, the border, 
+    /// and [`GlobalTerritorySettings::inner_margins`] are taken off each side. Collapses to zero size rather than inverting.
+    pub fn content_rect(&self, settings: &GlobalTerritorySettings) -> Rect {
+        let worldspace = self.expanse.worldspace();
+        let inset = Vec2::splat(settings.resize_handle_size + Territory::BORDER_WIDTH) + settings.inner_margins;
+        Rect::from_center_size(worldspace.center(), (worldspace.size() - inset * 2.0).max(Vec2::ZERO))
+    }
+
+    /// [`Territory::content_rect`] in **screenspace**, for drawing with UI libraries.
+    pub fn content_rect_screenspace(&self, settings: &GlobalTerritorySettings) -> Rect {
+        let screenspace = self.expanse.screenspace();
+        let content_size = self.content_rect(settings).size();
+        Rect::from_center_size(screenspace.center(), content_size)
+    }
+
+    /// Checks if a **worldspace** position is over the content area of this [`Territory`].  
+    /// \
+    /// Unlike checking [`RectKit::worldspace`], the resize handles and border don't count, 
+    /// so hovering an edge to resize is never mistaken for hovering the content.
+    pub fn content_contains_worldspace(&self, worldspace_pos: Vec2, settings: &GlobalTerritorySettings) -> bool {
+        self.content_rect(settings).contains(worldspace_pos)
+    }
+
+}
+
+/// Gets the **worldspace** conflict [`Rect`] where two [`Territory`]s overlap, if they do.  
+/// \
+/// [`Territory`]s that only share an edge don't overlap.
+pub fn territories_overlap(a: &Territory, b: &Territory) -> Option<Rect> {
+    let conflict_rect = a.expanse.worldspace().intersect(b.expanse.worldspace());
+    (!conflict_rect.is_empty()).then_some(conflict_rect)
+}
+
+/// Gets the **worldspace** [`Rect`] two [`Territory`]s would cover if combined into one, clamped to the `window_rect`.  
+/// \
+/// Returns `None` if that [`Rect`] would overlap any of the `others`, or if nothing is left inside the `Window`.
+pub fn combined_territory_rect<'a>(
+    a: &Territory, 
+    b: &Territory, 
+    window_rect: Rect, 
+    others: impl IntoIterator<Item = &'a Territory>
+) -> Option<Rect> {
+    let combined_rect = window_rect.intersect(a.expanse.worldspace().union(b.expanse.worldspace()));
+    if combined_rect.is_empty() {
+        return None;
+    }
+    let overlaps_others = others.into_iter()
+        .any(|other| !combined_rect.intersect(other.expanse.worldspace()).is_empty());
+    (!overlaps_others).then_some(combined_rect)
+}
+
+/// How far apart two relative [`Rect`]s can be while still counting as the same place in a [`TerritoryLayout`].
+pub(crate) const LAYOUT_TOLERANCE: f32 = 0.0001;
+
+/// A single [`Territory`] as recorded in a [`TerritoryLayout`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TerritoryLayoutEntry {
+    /// The `Window` the [`Territory`] is a child of.
+    pub window_entity: Entity,
+    /// Where the [`Territory`] is, in relative **worldspace** so the entry doesn't depend on `Window` size.
+    pub relative_worldspace: Rect,
+    /// How the [`Territory`] is represented in UI.
+    pub display_library: DisplayLibrary,
+    /// Which sides of the [`Territory`] get resize handles.
+    pub resizable_edges: ResizableEdges
+}
+impl TerritoryLayoutEntry {
+    /// Records a [`Territory`] along with the components needed to spawn it again.
+    pub fn new(
+        window_entity: Entity, 
+        territory: &Territory, 
+        display_library: DisplayLibrary, 
+        resizable_edges: ResizableEdges
+    ) -> Self {
+        TerritoryLayoutEntry { 
+            window_entity, 
+            relative_worldspace: territory.expanse.relative_worldspace(), 
+            display_library, 
+            resizable_edges 
+        }
+    }
+}
+
+/// Snapshot of where every [`Territory`] is, keyed by [`Territory`] [`Entity`].  
+/// \
+/// Two layouts are compared with [`LayoutDiff::between`]. Keys are matched as-is, 
+/// so both layouts need to agree on which [`Entity`] is which [`Territory`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TerritoryLayout {
+    pub territories: HashMap<Entity, TerritoryLayoutEntry>
+}
+impl FromIterator<(Entity, TerritoryLayoutEntry)> for TerritoryLayout {
+    fn from_iter<I: IntoIterator<Item = (Entity, TerritoryLayoutEntry)>>(iter: I) -> Self {
+        TerritoryLayout { territories: iter.into_iter().collect() }
+    }
+}
+impl TerritoryLayout {
+    /// Adds or replaces the entry for a [`Territory`].
+    pub fn insert(&mut self, territory_entity: Entity, entry: TerritoryLayoutEntry) -> &mut Self {
+        self.territories.insert(territory_entity, entry);
+        self
+    }
+
+    /// Gets the entry for a [`Territory`], if it's in this layout.
+    pub fn get(&self, territory_entity: Entity) -> Option<&TerritoryLayoutEntry> {
+        self.territories.get(&territory_entity)
+    }
+
+    /// Iterates over every `(Territory, entry)` pair, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &TerritoryLayoutEntry)> + '_ {
+        self.territories.iter().map(|(territory_entity, entry)| (*territory_entity, entry))
+    }
+
+    /// Number of [`Territory`]s in this layout.
+    pub fn len(&self) -> usize {
+        self.territories.len()
+    }
+
+    /// Returns `true` if this layout has no [`Territory`]s.
+    pub fn is_empty(&self) -> bool {
+        self.territories.is_empty()
+    }
+}
+
+/// A [`Territory`] as written to a layout file by [`crate::systems_territory::save_layout`].  
+/// \
+/// [`Entity`] IDs don't survive a save and load, so neighbors are stored as indices into [`SavedLayout::territories`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SavedTerritory {
+    /// Relative **worldspace** [`Rect`] as `[min.x, min.y, max.x, max.y]`, so the layout doesn't depend on `Window` size.
+    pub relative_worldspace: [f32; 4],
+    /// How the [`Territory`] is represented in UI.
+    pub display_library: DisplayLibrary,
+    /// Which sides of the [`Territory`] get resize handles.
+    pub resizable_edges: ResizableEdges,
+    /// [`CardinalConnections`], as indices of other saved [`Territory`]s.
+    pub connections: SavedConnections
+}
+#[cfg(feature = "serde")]
+impl SavedTerritory {
+    /// Gets the saved relative **worldspace** [`Rect`].
+    pub fn relative_worldspace(&self) -> Rect {
+        let [min_x, min_y, max_x, max_y] = self.relative_worldspace;
+        Rect::new(min_x, min_y, max_x, max_y)
+    }
+}
+
+/// [`CardinalConnections`] in a [`SavedTerritory`], with each neighbor an index into [`SavedLayout::territories`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SavedConnections {
+    pub northern: Vec<usize>,
+    pub eastern: Vec<usize>,
+    pub southern: Vec<usize>,
+    pub western: Vec<usize>
+}
+#[cfg(feature = "serde")]
+impl SavedConnections {
+    /// Returns `true` if the saved [`Territory`] wasn't linked to anything.
+    pub fn is_empty(&self) -> bool {
+        self.northern.is_empty() && self.eastern.is_empty() && self.southern.is_empty() && self.western.is_empty()
+    }
+}
+
+/// Every [`Territory`] in one `Window`, as written to and read from RON by 
+/// [`crate::systems_territory::save_layout`] and [`crate::systems_territory::load_layout`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SavedLayout {
+    pub territories: Vec<SavedTerritory>
+}
+
+/// The changes needed to turn one [`TerritoryLayout`] into another. Every list is sorted by [`Entity`].  
+/// \
+/// A [`Territory`] that changed `Window`, [`DisplayLibrary`], or [`ResizableEdges`] can't be moved there, 
+/// so it shows up as both removed and added.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayoutDiff {
+    /// [`Territory`]s only in the target layout, under the key they had there.
+    pub added: Vec<(Entity, TerritoryLayoutEntry)>,
+    /// [`Territory`]s only in the source layout.
+    pub removed: Vec<Entity>,
+    /// [`Territory`]s that changed position but kept their size, with their new relative **worldspace** [`Rect`].
+    pub moved: Vec<(Entity, Rect)>,
+    /// [`Territory`]s that changed size, with their new relative **worldspace** [`Rect`].
+    pub resized: Vec<(Entity, Rect)>
+}
+impl LayoutDiff {
+    /// Works out what changed going from the `from` layout to the `to` layout.
+    pub fn between(from: &TerritoryLayout, to: &TerritoryLayout) -> Self {
+        let mut layout_diff = LayoutDiff::default();
+
+        for (territory_entity, from_entry) in from.iter() {
+            let Some(to_entry) = to.get(territory_entity) else {
+                layout_diff.removed.push(territory_entity);
+                continue;
+            };
+
+            if from_entry.window_entity != to_entry.window_entity 
+                || from_entry.display_library != to_entry.display_library 
+                || from_entry.resizable_edges != to_entry.resizable_edges {
+                layout_diff.removed.push(territory_entity);
+                layout_diff.added.push((territory_entity, *to_entry));
+            }
+            else if !from_entry.relative_worldspace.size().abs_diff_eq(to_entry.relative_worldspace.size(), LAYOUT_TOLERANCE) {
+                layout_diff.resized.push((territory_entity, to_entry.relative_worldspace));
+            }
+            else if !from_entry.relative_worldspace.min.abs_diff_eq(to_entry.relative_worldspace.min, LAYOUT_TOLERANCE) {
+                layout_diff.moved.push((territory_entity, to_entry.relative_worldspace));
+            }
+        }
+
+        for (territory_entity, to_entry) in to.iter() {
+            if from.get(territory_entity).is_none() {
+                layout_diff.added.push((territory_entity, *to_entry));
+            }
+        }
+
+        layout_diff.added.sort_by_key(|(territory_entity, _)| *territory_entity);
+        layout_diff.removed.sort();
+        layout_diff.moved.sort_by_key(|(territory_entity, _)| *territory_entity);
+        layout_diff.resized.sort_by_key(|(territory_entity, _)| *territory_entity);
+        layout_diff
+    }
+
+    /// Returns `true` if the two layouts were already the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty() && self.resized.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -1380,6 +3017,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resize_magnitudes_from_delta_follow_sign_conventions() {
+        use ResizeMagnitude::{Advancing, Retreating};
+
+        // (direction, magnitudes from a (+10, +10) delta, magnitudes from a (-10, -10) delta)
+        let expected = [
+            (ResizeDirection::ORDINAL[0], vec![Retreating(10.0)], vec![Advancing(10.0)]),
+            (ResizeDirection::ORDINAL[1], vec![Retreating(10.0), Advancing(10.0)], vec![Advancing(10.0), Retreating(10.0)]),
+            (ResizeDirection::ORDINAL[2], vec![Advancing(10.0)], vec![Retreating(10.0)]),
+            (ResizeDirection::ORDINAL[3], vec![Advancing(10.0), Advancing(10.0)], vec![Retreating(10.0), Retreating(10.0)]),
+            (ResizeDirection::ORDINAL[4], vec![Advancing(10.0)], vec![Retreating(10.0)]),
+            (ResizeDirection::ORDINAL[5], vec![Advancing(10.0), Retreating(10.0)], vec![Retreating(10.0), Advancing(10.0)]),
+            (ResizeDirection::ORDINAL[6], vec![Retreating(10.0)], vec![Advancing(10.0)]),
+            (ResizeDirection::ORDINAL[7], vec![Retreating(10.0), Retreating(10.0)], vec![Advancing(10.0), Advancing(10.0)]),
+        ];
+
+        for (direction, positive_magnitudes, negative_magnitudes) in expected {
+            for (delta, expected_magnitudes) in [(Vec2::splat(10.0), positive_magnitudes), (Vec2::splat(-10.0), negative_magnitudes)] {
+                let mut resized_direction = direction;
+                let magnitudes: Vec<ResizeMagnitude> = resized_direction
+                    .set_magnitudes_from_delta(delta)
+                    .get_cardinal_directions()
+                    .iter()
+                    .map(|cardinal_direction| cardinal_direction.get_single_magnitude())
+                    .collect();
+                assert_eq!(magnitudes, expected_magnitudes, "Wrong magnitudes for {:?} with delta {:?}.", direction, delta);
+            }
+        }
+    }
+
+    #[test]
+    fn resize_magnitudes_from_delta_move_the_rect_like_the_raw_delta() {
+        let rect = Rect::new(100.0, 100.0, 200.0, 200.0);
+        let deltas = [
+            Vec2::new(10.0, 10.0), Vec2::new(-10.0, -10.0), Vec2::new(10.0, -10.0), Vec2::new(-10.0, 10.0),
+            Vec2::new(7.0, 0.0), Vec2::new(0.0, -7.0), Vec2::ZERO
+        ];
+
+        for direction in ResizeDirection::ORDINAL {
+            let edges = ResizableEdges::from_direction(&direction);
+            for delta in deltas {
+                // Every moving side follows the raw screenspace delta along its axis.
+                let mut expected_rect = rect;
+                if edges.contains(ResizableEdges::NORTH) { expected_rect.min.y += delta.y; }
+                if edges.contains(ResizableEdges::EAST) { expected_rect.max.x += delta.x; }
+                if edges.contains(ResizableEdges::SOUTH) { expected_rect.max.y += delta.y; }
+                if edges.contains(ResizableEdges::WEST) { expected_rect.min.x += delta.x; }
+
+                let mut resized_direction = direction;
+                resized_direction.set_magnitudes_from_delta(delta);
+                assert_eq!(resized_direction.apply_to_rect(rect), expected_rect, "{:?} moved the rect differently than delta {:?}.", resized_direction, delta);
+
+                for cardinal_direction in resized_direction.get_cardinal_directions() {
+                    let axis_delta = match cardinal_direction {
+                        ResizeDirection::North {..} | ResizeDirection::South {..} => delta.y,
+                        _ => delta.x
+                    };
+                    if axis_delta == 0.0 {
+                        assert_eq!(cardinal_direction.get_single_magnitude(), ResizeMagnitude::None, "{:?} has a magnitude from a zero delta.", cardinal_direction);
+                    } else {
+                        assert_eq!(cardinal_direction.get_single_magnitude().get(), axis_delta.abs(), "{:?} has the wrong magnitude for delta {:?}.", cardinal_direction, delta);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn flipped_y_matches_point_conversions_and_round_trips() {
+        let (window_width, window_height) = (1000.0, 600.0);
+        let screenspace_pos = Vec2::new(250.0, 100.0);
+
+        let worldspace_pos = RectKit::flip_y_screen_to_world(screenspace_pos, window_height);
+        assert_eq!(worldspace_pos, Vec2::new(250.0, 200.0), "Flipped y is not measured up from the Window center.");
+        assert_eq!(
+            worldspace_pos.y, 
+            RectKit::screen_pos_to_world(screenspace_pos, window_width, window_height).y, 
+            "Flipped y disagrees with the full point conversion."
+        );
+        assert_eq!(RectKit::flip_y_world_to_screen(worldspace_pos, window_height), screenspace_pos, "Flipping back did not round trip.");
+        assert_eq!(RectKit::flip_y_screen_to_world(Vec2::new(5.0, 8.0), 0.0), Vec2::new(5.0, -8.0), "Zero height did not flip a delta.");
+    }
+
+    #[test]
+    fn move_request_from_screenspace_rect_fills_a_consistent_rect_kit() {
+        let screenspace = Rect::new(100.0, 50.0, 300.0, 250.0);
+        let move_request = MoveRequest::from_screenspace_rect(screenspace, 1000.0, 500.0);
+        let proposed_expanse = move_request.proposed_expanse();
+
+        assert!(matches!(move_request.move_type(), MoveRequestType::Unknown), "Display libraries can't tell drags from resizes.");
+        assert!(proposed_expanse.validate(1000.0, 500.0), "Proposed RectKit is inconsistent.");
+        assert_eq!(proposed_expanse.screenspace(), screenspace);
+        assert_eq!(proposed_expanse.worldspace(), Rect::new(-400.0, 0.0, -200.0, 200.0));
+
+        let territory = Territory { expanse: proposed_expanse, ..Territory::empty() };
+        assert_eq!(territory.screenspace_rect(), screenspace, "Territory screenspace accessor disagrees with its expanse.");
+    }
+
     #[test]
     fn territory_movement_methods_move_correctly() {
         let mut test_terr = Territory::empty();
@@ -1434,4 +3169,288 @@ mod tests {
             "Move world corners failure."
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn rect_kit_produces_absolute_percentage_style() {
+        let test_kit = RectKit::from_relative_screenspace(Rect::new(0.25, 0.1, 0.75, 0.6), 1000.0, 1000.0);
+        let style = test_kit.to_absolute_style();
+
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.left, Val::Percent(25.0));
+        assert_eq!(style.top, Val::Percent(10.0));
+        assert_eq!(style.width, Val::Percent(50.0));
+        assert_eq!(style.height, Val::Percent(50.0));
+    }
+
+    #[test]
+    fn relative_screenspace_scales_up_to_window_size() {
+        let test_kit = RectKit::from_relative_screenspace(Rect::new(0.0, 0.0, 0.5, 0.5), 1000.0, 1000.0);
+
+        assert_eq!(test_kit.screenspace(), Rect::new(0.0, 0.0, 500.0, 500.0));
+        assert_eq!(test_kit.worldspace(), Rect::new(-500.0, 0.0, 0.0, 500.0));
+    }
+
+    #[test]
+    fn rect_kit_validates_against_window_dimensions() {
+        let test_kit = RectKit::from_screenspace(Rect::new(100.0, 100.0, 300.0, 200.0), 1000.0, 800.0);
+
+        assert!(test_kit.validate(1000.0, 800.0), "Freshly converted RectKit failed validation.");
+        assert!(!test_kit.validate(1200.0, 800.0), "RectKit passed validation against a resized Window.");
+        assert!(!test_kit.validate(0.0, 0.0), "RectKit passed validation against a zero-sized Window.");
+    }
+
+    #[test]
+    fn edge_flush_rect_kit_is_inside_screenspace_window() {
+        let flush_kit = RectKit::from_screenspace(Rect::new(800.0, 600.0, 1000.0, 800.0), 1000.0, 800.0);
+        assert!(flush_kit.is_inside_screenspace_window(1000.0, 800.0), "RectKit flush against the bottom right edges was outside.");
+
+        let origin_kit = RectKit::from_screenspace(Rect::new(0.0, 0.0, 200.0, 200.0), 1000.0, 800.0);
+        assert!(origin_kit.is_inside_screenspace_window(1000.0, 800.0), "RectKit flush against the top left edges was outside.");
+    }
+
+    #[test]
+    fn rect_kit_one_pixel_past_the_edge_is_outside_screenspace_window() {
+        let right_kit = RectKit::from_screenspace(Rect::new(801.0, 100.0, 1001.0, 300.0), 1000.0, 800.0);
+        assert!(!right_kit.is_inside_screenspace_window(1000.0, 800.0), "RectKit one pixel past the right edge was inside.");
+
+        let bottom_kit = RectKit::from_screenspace(Rect::new(100.0, 601.0, 300.0, 801.0), 1000.0, 800.0);
+        assert!(!bottom_kit.is_inside_screenspace_window(1000.0, 800.0), "RectKit one pixel past the bottom edge was inside.");
+
+        let left_kit = RectKit::from_screenspace(Rect::new(-1.0, 100.0, 199.0, 300.0), 1000.0, 800.0);
+        assert!(!left_kit.is_inside_screenspace_window(1000.0, 800.0), "RectKit one pixel past the left edge was inside.");
+    }
+
+    #[test]
+    fn fit_within_worldspace_leaves_a_rect_already_inside_alone() {
+        let mut test_kit = RectKit::from_worldspace(Rect::new(-100.0, -100.0, 100.0, 100.0), 1000.0, 800.0);
+        let bounds = Rect::new(-400.0, -300.0, 400.0, 300.0);
+
+        test_kit.fit_within_worldspace(bounds, 1000.0, 800.0);
+        assert_eq!(test_kit.worldspace(), Rect::new(-100.0, -100.0, 100.0, 100.0), "Rect already inside was changed.");
+    }
+
+    #[test]
+    fn fit_within_worldspace_moves_an_offset_rect_back_inside() {
+        let mut test_kit = RectKit::from_worldspace(Rect::new(300.0, -350.0, 500.0, -150.0), 1000.0, 800.0);
+        let bounds = Rect::new(-400.0, -300.0, 400.0, 300.0);
+
+        test_kit.fit_within_worldspace(bounds, 1000.0, 800.0);
+        assert_eq!(test_kit.worldspace(), Rect::new(200.0, -300.0, 400.0, -100.0), "Offset Rect was not moved back inside.");
+        assert!(test_kit.validate(1000.0, 800.0), "Moved RectKit disagrees with itself.");
+    }
+
+    #[test]
+    fn fit_within_worldspace_clips_only_the_oversized_axis() {
+        // Wider than the bounds, but short enough to just be moved vertically.
+        let mut test_kit = RectKit::from_worldspace(Rect::new(-450.0, 250.0, 500.0, 350.0), 1000.0, 800.0);
+        let bounds = Rect::new(-400.0, -300.0, 400.0, 300.0);
+
+        test_kit.fit_within_worldspace(bounds, 1000.0, 800.0);
+        assert_eq!(test_kit.worldspace(), Rect::new(-400.0, 200.0, 400.0, 300.0), "Oversized Rect was not clipped to the bounds.");
+        assert!(test_kit.validate(1000.0, 800.0), "Clipped RectKit disagrees with itself.");
+    }
+
+    #[test]
+    fn rect_kit_lerp_halfway_is_the_midpoint() {
+        let from_kit = RectKit::from_worldspace(Rect::new(-200.0, -100.0, 0.0, 100.0), 1000.0, 800.0);
+        let to_kit = RectKit::from_worldspace(Rect::new(0.0, 0.0, 400.0, 300.0), 1000.0, 800.0);
+
+        let halfway_kit = from_kit.lerp(&to_kit, 0.5, 1000.0, 800.0);
+        assert_eq!(halfway_kit.worldspace(), Rect::new(-100.0, -50.0, 200.0, 200.0), "Halfway lerp is not the midpoint.");
+        assert!(halfway_kit.validate(1000.0, 800.0), "Lerped RectKit disagrees with itself.");
+        assert_eq!(from_kit.lerp(&to_kit, 2.0, 1000.0, 800.0).worldspace(), to_kit.worldspace(), "Lerp past the end was not clamped.");
+        assert_eq!(from_kit.lerp(&to_kit, -1.0, 1000.0, 800.0).worldspace(), from_kit.worldspace(), "Lerp before the start was not clamped.");
+    }
+
+    #[test]
+    fn rect_kit_lerp_rebuilds_a_kit_from_another_window_size() {
+        // Same relative spot, but converted before the Window was doubled in size.
+        let stale_kit = RectKit::from_relative_screenspace(Rect::new(0.0, 0.0, 0.5, 0.5), 500.0, 400.0);
+        let current_kit = RectKit::from_relative_screenspace(Rect::new(0.0, 0.0, 0.5, 0.5), 1000.0, 800.0);
+
+        let lerped_kit = stale_kit.lerp(&current_kit, 0.25, 1000.0, 800.0);
+        assert_eq!(lerped_kit.worldspace(), current_kit.worldspace(), "Stale RectKit was not rebuilt for the current Window.");
+        assert!(lerped_kit.validate(1000.0, 800.0), "Lerped RectKit disagrees with itself.");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "stale dimensions")]
+    fn moving_with_stale_window_dimensions_panics_in_debug() {
+        let mut test_kit = RectKit::from_screenspace(Rect::new(100.0, 100.0, 300.0, 200.0), 1200.0, 800.0);
+        test_kit.move_screenspace_pos(10.0, 0.0, 1000.0, 800.0);
+    }
+
+    #[test]
+    fn rect_kit_conversions_account_for_zoomed_camera() {
+        let camera = CameraView::new(Vec2::new(100.0, 50.0), 2.0);
+        let mut test_kit = RectKit::empty();
+        test_kit.worldspace = Rect::new(100.0, 50.0, 300.0, 250.0);
+        test_kit.world_to_screen_with_camera(1000.0, 1000.0, camera);
+
+        // Zoomed out 2x, so the 200 unit square covers 100 pixels, starting at the camera's center.
+        assert_eq!(test_kit.screenspace(), Rect::new(500.0, 400.0, 600.0, 500.0));
+
+        test_kit.worldspace = Rect::default();
+        test_kit.screen_to_world_with_camera(1000.0, 1000.0, camera);
+        assert_eq!(test_kit.worldspace(), Rect::new(100.0, 50.0, 300.0, 250.0), "Round trip through a zoomed camera drifted.");
+
+        assert_eq!(camera.screen_to_world_pos(Vec2::ZERO, 1000.0, 1000.0), Vec2::new(-900.0, 1050.0));
+        assert_eq!(CameraView::default().world_to_screen_pos(Vec2::ZERO, 1000.0, 1000.0), Vec2::new(500.0, 500.0));
+    }
+
+    #[test]
+    fn corner_resizes_keep_aspect_ratio_and_sides_do_not() {
+        let south_east = ResizeDirection::SouthEast { southward_magnitude: ResizeMagnitude::None, eastward_magnitude: ResizeMagnitude::None };
+        let north_west = ResizeDirection::NorthWest { northward_magnitude: ResizeMagnitude::None, westward_magnitude: ResizeMagnitude::None };
+        let east = ResizeDirection::East { eastward_magnitude: ResizeMagnitude::None };
+
+        // Dragged wider, so the height catches up and the north west corner stays put.
+        assert_eq!(south_east.keep_aspect_ratio(Rect::new(0.0, 0.0, 160.0, 50.0), 2.0), Rect::new(0.0, 0.0, 160.0, 80.0));
+        // Dragged taller, so the width catches up and the south east corner stays put.
+        assert_eq!(north_west.keep_aspect_ratio(Rect::new(40.0, -10.0, 100.0, 50.0), 2.0), Rect::new(-20.0, -10.0, 100.0, 50.0));
+        assert_eq!(east.keep_aspect_ratio(Rect::new(0.0, 0.0, 160.0, 50.0), 2.0), Rect::new(0.0, 0.0, 160.0, 50.0), "Side resize was reshaped.");
+    }
+
+    #[test]
+    fn touching_territories_do_not_overlap() {
+        let territory_at = |screenspace: Rect| {
+            let mut territory = Territory::empty();
+            territory.expanse = RectKit::from_screenspace(screenspace, 1000.0, 1000.0);
+            territory
+        };
+        let west = territory_at(Rect::new(0.0, 0.0, 200.0, 100.0));
+        let east = territory_at(Rect::new(200.0, 0.0, 400.0, 100.0));
+        let overlapping = territory_at(Rect::new(150.0, 50.0, 250.0, 150.0));
+
+        assert_eq!(west.area(), 20000.0);
+        assert_eq!(territories_overlap(&west, &east), None, "Territories sharing an edge were said to overlap.");
+        let conflict_rect = territories_overlap(&west, &overlapping).expect("Overlapping Territories were not caught.");
+        assert_eq!(conflict_rect.size(), Vec2::new(50.0, 50.0));
+        assert_eq!(territories_overlap(&overlapping, &west), Some(conflict_rect), "Overlap is not symmetric.");
+    }
+
+    #[test]
+    fn grid_snap_never_collapses_below_min_size() {
+        let grid_snap = GridSnap { enabled: true, cell: Vec2::new(50.0, 0.0) };
+        let snapped_rect = grid_snap.snap_rect(Rect::new(-400.0, 13.0, -380.0, 77.0), SIGNET_SIZE);
+        assert_eq!(snapped_rect.min.x, -400.0);
+        assert_eq!(snapped_rect.max.x, -350.0, "Snap collapsed the Territory below its minimum width.");
+        assert_eq!((snapped_rect.min.y, snapped_rect.max.y), (13.0, 77.0), "Axis with no grid was snapped anyway.");
+    }
+
+    #[test]
+    fn pure_conversions_match_rect_kit() {
+        let (width, height) = (1280.0, 720.0);
+        let worldspace = Rect::new(-300.0, -100.0, 150.0, 260.0);
+        let untouched = RectKit::from_worldspace(worldspace, width, height);
+
+        assert_eq!(RectKit::world_rect_to_screen(worldspace, width, height), untouched.screenspace());
+        assert_eq!(RectKit::world_rect_to_relative(worldspace, width, height), untouched.relative_worldspace());
+        assert_eq!(RectKit::screen_rect_to_world(untouched.screenspace(), width, height), untouched.worldspace());
+        assert_eq!(RectKit::screen_rect_to_relative(untouched.screenspace(), width, height), untouched.relative_screenspace());
+
+        let mut mutated = untouched;
+        mutated.relative_to_world(width, height).relative_to_screen(width, height);
+        assert_eq!(RectKit::relative_rect_to_world(untouched.relative_worldspace(), width, height), mutated.worldspace());
+        assert_eq!(
+            RectKit::relative_rect_to_screen(untouched.relative_screenspace(), width, height),
+            mutated.screenspace(),
+            "Pure relative to screen conversion drifted from RectKit::relative_to_screen."
+        );
+
+        // A cursor in the middle of the Territory lands in the middle of its screenspace Rect.
+        let cursor_worldspace_pos = worldspace.center();
+        assert_eq!(RectKit::world_pos_to_screen(cursor_worldspace_pos, width, height), untouched.screenspace().center());
+        assert_eq!(RectKit::screen_pos_to_world(untouched.screenspace().center(), width, height), cursor_worldspace_pos);
+    }
+
+    #[test]
+    fn hit_grid_only_offers_nearby_territories() {
+        // 100 Territories tiling a 1000 x 1000 window.
+        let mut hit_grid = TerritoryHitGrid::default();
+        let mut territories = Vec::new();
+        for index in 0..100 {
+            let territory_entity = Entity::from_raw(index);
+            let min = Vec2::new((index % 10) as f32, (index / 10) as f32) * 100.0 - Vec2::splat(500.0);
+            let worldspace = Rect::from_corners(min, min + Vec2::splat(100.0));
+            hit_grid.insert(territory_entity, worldspace);
+            territories.push((territory_entity, worldspace));
+        }
+
+        for cursor_pos in [Vec2::new(-455.0, -455.0), Vec2::new(10.0, -30.0), Vec2::new(499.0, 499.0)] {
+            let candidates = hit_grid.candidates(cursor_pos);
+            assert!(candidates.len() <= 9, "Hit grid offered {} of 100 Territories at {:?}.", candidates.len(), cursor_pos);
+            for (territory_entity, worldspace) in &territories {
+                if worldspace.contains(cursor_pos) {
+                    assert!(candidates.contains(territory_entity), "Hovered Territory was left out at {:?}.", cursor_pos);
+                }
+            }
+        }
+        assert!(hit_grid.candidates(Vec2::new(5000.0, 5000.0)).is_empty());
+    }
+
+    #[test]
+    fn max_size_clamp_holds_the_opposite_edge() {
+        let max_size = Vec2::new(300.0, 200.0);
+        let proposed = Rect::new(-100.0, -100.0, 400.0, 400.0);
+
+        let north_east = ResizableEdges::NORTH | ResizableEdges::EAST;
+        assert_eq!(north_east.clamp_to_max_size(proposed, max_size), Rect::new(-100.0, -100.0, 200.0, 100.0));
+        let south_west = ResizableEdges::SOUTH | ResizableEdges::WEST;
+        assert_eq!(south_west.clamp_to_max_size(proposed, max_size), Rect::new(100.0, 200.0, 400.0, 400.0));
+
+        // Too wide, but this resize doesn't touch the width.
+        assert_eq!(ResizableEdges::NORTH.clamp_to_max_size(proposed, max_size), Rect::new(-100.0, -100.0, 400.0, 100.0));
+        assert_eq!(ResizableEdges::ALL.clamp_to_max_size(proposed, max_size), Rect::new(0.0, 50.0, 300.0, 250.0), "Both edges were not clamped around the center.");
+        assert_eq!(ResizableEdges::ALL.clamp_to_max_size(proposed, Vec2::splat(f32::INFINITY)), proposed);
+    }
+
+    #[test]
+    fn all_sides_resize_moves_every_edge_around_the_center() {
+        let current = Rect::new(100.0, 100.0, 300.0, 200.0);
+        let proposed = Rect::new(90.0, 80.0, 310.0, 220.0);
+
+        let resize_direction = ResizeDirection::from_screenspace_change(current, proposed).expect("Every side moving was not a resize.");
+        assert!(matches!(resize_direction, ResizeDirection::All { .. }), "Every side moving was not an all sides resize.");
+        assert_eq!(resize_direction.apply_to_rect(current), proposed);
+        assert_eq!(ResizableEdges::from_direction(&resize_direction), ResizableEdges::ALL);
+        assert!(!(ResizableEdges::NORTH | ResizableEdges::EAST | ResizableEdges::SOUTH).allows(&resize_direction), "A disabled western edge allowed an all sides resize.");
+        assert_eq!(resize_direction.anchor_corner(current), current.center(), "All sides resize was not anchored at the center.");
+
+        let mut grown_direction = ResizeDirection::ALL;
+        grown_direction.set_magnitudes_from_delta(Vec2::new(10.0, 20.0));
+        assert_eq!(grown_direction.apply_to_rect(current), ResizeDirection::ALL.add_delta_to_rect(current, Vec2::new(10.0, 20.0)));
+        assert!(!grown_direction.has_any_retreating() && grown_direction.is_multi_side_resize());
+    }
+
+    #[test]
+    fn content_containment_excludes_resize_handles() {
+        let settings = GlobalTerritorySettings::default();
+        let mut territory = Territory::empty();
+        territory.expanse.set_worldspace(Rect::new(-100.0, -100.0, 100.0, 100.0), 1000.0, 1000.0);
+
+        let on_handle_ring = Vec2::new(-100.0 + ResizeDirection::SIZE * 0.5, 0.0);
+        assert!(territory.expanse.worldspace().contains(on_handle_ring), "Handle ring should be inside the full Territory.");
+        assert!(!territory.content_contains_worldspace(on_handle_ring, &settings), "Handle ring should not count as content.");
+        assert!(territory.content_contains_worldspace(Vec2::ZERO, &settings), "Center should count as content.");
+    }
+
+    #[test]
+    fn content_rect_is_inset_by_handles_border_and_margins() {
+        let settings = GlobalTerritorySettings::default();
+        let mut territory = Territory::empty();
+        territory.expanse.set_screenspace(Rect::new(100.0, 100.0, 700.0, 300.0), 1000.0, 1000.0);
+
+        // 5 for the resize handles, 1 for the border, and 3 for the inner margins, on every side.
+        let inset = settings.resize_handle_size + Territory::BORDER_WIDTH + settings.inner_margins.x;
+        assert_eq!(inset, 9.0);
+        assert_eq!(territory.content_rect(&settings).size(), Vec2::new(582.0, 182.0), "Content was not inset on every side.");
+        assert_eq!(territory.content_rect(&settings).center(), territory.expanse.worldspace().center());
+        assert_eq!(
+            territory.content_rect_screenspace(&settings), 
+            Rect::new(109.0, 109.0, 691.0, 291.0), 
+            "Screenspace content was not inset the same way."
+        );
+    }
+}
@@ -6,7 +6,7 @@ use bevy::prelude::*;
 pub struct Overlay;
 
 // Identifies entity as a Tab, which can be active or inactive, and represent a type of UI.
-#[derive(Component)]
+#[derive(Component, Clone, Debug)]
 pub struct Tab {
     pub active: bool,
     pub name: String, 
@@ -35,16 +35,22 @@ impl Tab {
             TabType::ECS => Tab {name: "ECS".to_string(), icon: '🍱', tab_type, ..Default::default()},
             TabType::Glossary => Tab {name: "Glossary".to_string(), icon: '📖', tab_type, ..Default::default()},
             TabType::SiteView => Tab {name: "Site View".to_string(), icon: '👁', tab_type, ..Default::default()},
+            TabType::Custom { id } => Tab {name: format!("Custom {}", id), icon: '🧩', tab_type, ..Default::default()},
             }
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub enum TabType {
+    #[default]
     FileSystem,
     DevBox,
     ECS,
     Glossary,
     SiteView,
+    /// A tab kind defined outside of this crate. The `id` is up to the app, 
+    /// which can use it to pick what gets drawn in the tab's content.
+    Custom { id: u32 },
 }
 
 // Denotes entity as visual assistant for visualizing the placement of things.
@@ -101,6 +107,40 @@ impl Placeholder {
     }
 }
 
+/// Marks the node following the cursor during a Tab move, showing which [`Tab`] is being dragged.
+#[derive(Component)]
+pub struct TabGhost {
+    pub tab: Entity
+}
+impl TabGhost {
+    /// How far the ghost sits from the cursor, in logical pixels, so it doesn't cover what's under it.
+    pub const CURSOR_OFFSET: Vec2 = Vec2 { x: 12.0, y: 12.0 };
+}
+
+/// Marks a [`Placeholder`] started by clicking empty space, which is committed when the click is released.
+#[derive(Component)]
+pub struct ClickSpawnPlaceholder;
+
+/// Which `Territory`s a [`PlaceholderType::CombineTerritories`] [`Placeholder`] would merge.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CombinePlaceholder {
+    /// `Territory` being dragged, which is emptied into the target.
+    pub source: Entity,
+    /// `Territory` under the cursor that would take the source's tabs, if they can be combined.
+    pub target: Option<Entity>
+}
+
+/// Which [`Tab`] a [`PlaceholderType::TabMove`] [`Placeholder`] is carrying, and the `Territory` it was picked up from.  
+/// \
+/// Stays put when the [`Placeholder`] turns into a [`PlaceholderType::SpawnTerritory`] one outside of any `Territory`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TabMovePlaceholder {
+    /// [`Tab`] being dragged.
+    pub tab: Entity,
+    /// `Territory` the [`Tab`] came from. Dropping it back here, or anywhere nothing can take it, leaves it where it was.
+    pub origin: Entity
+}
+
 pub enum PlaceholderType {
     SpawnTerritory,
     TabMove,
@@ -1,17 +1,31 @@
 //! UI display logic for displaying a [`Territory`] with bevy_ui.
 
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy::utils::{HashMap, HashSet};
+use leafwing_input_manager::prelude::ActionState;
+use std::time::Duration;
 
 use crate::components_territory::*;
+use crate::components_ui::{Overlay, Tab, TabType};
+use crate::input_manager::DevControls;
+use crate::resources_ui::{TabContentContext, TabRenderers, TabTextConfig, TerritoryStyle};
 use crate::systems_territory::*;
 
 /// Trait extension for the [`Territory`] component, so I can move all the verbose [`Node`] stuff into its own module. 
 pub trait TerritoryNodes{
     fn base_node_template(&self) -> impl Bundle;
     fn border_node_template(&self) -> impl Bundle;
-    fn drag_node_template(&self) -> impl Bundle;
-    fn resize_node_template(&self) -> impl Bundle;
+    fn drag_node_template(&self, drag_zone: DragZone) -> impl Bundle;
+    fn resize_node_template(&self, resize_handle_size: f32) -> impl Bundle;
     fn resize_button_template(&self, resize_direction: ResizeDirection) -> impl Bundle;
+    fn panel_node_template(&self) -> impl Bundle;
+    fn tab_bar_node_template(&self) -> impl Bundle;
+    fn add_tab_button_template(&self) -> impl Bundle;
+    fn content_node_template(&self) -> impl Bundle;
+    fn scroll_node_template(&self) -> impl Bundle;
+    fn empty_hint_node_template(&self) -> impl Bundle;
 }
 
 impl TerritoryNodes for Territory {
@@ -24,13 +38,8 @@ impl TerritoryNodes for Territory {
             Name::new("[NODE] Territory Base Node"),
             NodeBundle {
                 style: Style {
-                    position_type: PositionType::Absolute,
-                    width: Val::Percent(self.expanse.relative_screenspace.width() * 100.0),
-                    height: Val::Percent(self.expanse.relative_screenspace.height() * 100.0),
-                    left: Val::Percent(self.expanse.relative_screenspace.min.x * 100.0),
-                    top: Val::Percent(self.expanse.relative_screenspace.min.y * 100.0),
                     overflow: Overflow::clip(),
-                    ..default()
+                    ..self.expanse.to_absolute_style()
                 },
                 background_color: BackgroundColor(Color::srgb_u8(60, 91, 111)),
                 focus_policy: bevy::ui::FocusPolicy::Block,
@@ -51,7 +60,7 @@ impl TerritoryNodes for Territory {
                 style: Style {
                     width: Val::Percent(100.0),
                     height: Val::Percent(100.0),
-                    border: UiRect::all(Val::Px(1.0)),
+                    border: UiRect::all(Val::Px(Territory::BORDER_WIDTH)),
                     ..default()
                 },
                 border_color: BorderColor(Color::srgb_u8(93, 235, 215)),
@@ -63,15 +72,16 @@ impl TerritoryNodes for Territory {
     /// Returns a [`Bundle`] of a template, named, drag [`Node`].  
     /// \
     /// This will be the area of the [`Territory`] that will drag it around.
-    /// Note that native Bevy UI does not have drag or resize interactions, 
-    /// so that functionality will have to be added by a third party crate.
-    fn drag_node_template(&self) -> impl Bundle {
+    /// Native Bevy UI has no drag interaction, so [`territory_drag_move_request`] 
+    /// follows the cursor while this node's [`Interaction`] is pressed. 
+    /// The [`DragZone`] decides if it covers the whole [`Territory`] or only its title bar.
+    fn drag_node_template(&self, drag_zone: DragZone) -> impl Bundle {
         (
             Name::new("[NODE] Territory Drag Node"),
             ButtonBundle {
                 style: Style {
                     width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
+                    height: drag_zone.node_height(),
                     border: UiRect::all(Val::Px(2.0)),
                     ..default()
                 },
@@ -85,13 +95,10 @@ impl TerritoryNodes for Territory {
 
     /// Returns a [`Bundle`] of a template, named, grid [`Node`] for the resize buttons.  
     /// \
-    /// A simple 3 x 3 CSS Grid for placing the eight resize directions and a central content area.
-    fn resize_node_template(&self) -> impl Bundle {
-        let resize_grid = vec![
-            GridTrack::px(ResizeDirection::SIZE),
-            GridTrack::flex(1.0),
-            GridTrack::px(ResizeDirection::SIZE)
-        ];
+    /// A simple 3 x 3 CSS Grid for placing the eight resize directions and a central content area, 
+    /// with the outer tracks [`GlobalTerritorySettings::resize_handle_size`] wide.
+    fn resize_node_template(&self, resize_handle_size: f32) -> impl Bundle {
+        let resize_grid = resize_grid_tracks(resize_handle_size);
         (
             Name::new("[NODE] Territory Resize Grid Node"),
             NodeBundle {
@@ -141,6 +148,10 @@ impl TerritoryNodes for Territory {
             },
             ResizeDirection::NorthWest{..} => {
                 name = format!("[NODE] Territory Resize Button Node - {:?}", resize_direction);
+            },
+            ResizeDirection::All{..} => {
+                warn!("There is no resize button for resizing every side at once!");
+                name = format!("[NODE] Territory Resize Button Node - {:?}", resize_direction);
             }
         };
 
@@ -163,71 +174,262 @@ impl TerritoryNodes for Territory {
         )
     }
 
+    /// Returns a [`Bundle`] of a template, named, panel [`Node`].  
+    /// \
+    /// Sits in the central area of the resize grid and stacks the tab bar on top of the content viewport.
+    fn panel_node_template(&self) -> impl Bundle {
+        (
+            Name::new("[NODE] Territory Panel Node"),
+            NodeBundle {
+                style: Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    grid_row: GridPlacement::start(2),
+                    grid_column: GridPlacement::start(2),
+                    ..default()
+                },
+                ..default()
+            }
+        )
+    }
+
+    /// Returns a [`Bundle`] of a template, named, tab bar [`Node`].  
+    /// \
+    /// A single row of tabs, one signet tall, ending with the add tab button.
+    fn tab_bar_node_template(&self) -> impl Bundle {
+        (
+            Name::new("[NODE] Territory Tab Bar Node"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    height: Val::Px(SIGNET_SIZE.y),
+                    flex_shrink: 0.0,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgb_u8(45, 70, 86)),
+                ..default()
+            },
+            TerritoryTabBarNode
+        )
+    }
+
+    /// Returns a [`Bundle`] of a template, named, add tab button [`Node`].  
+    /// \
+    /// The `+` text is spawned as a child of this button. 
+    /// The [`TerritoryAddTabButton`] is inserted once the [`Territory`] [`Entity`] exists.
+    fn add_tab_button_template(&self) -> impl Bundle {
+        (
+            Name::new("[NODE] Territory Add Tab Button Node"),
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Px(SIGNET_SIZE.x),
+                    height: Val::Px(SIGNET_SIZE.y),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::NONE),
+                ..default()
+            }
+        )
+    }
+
+    /// Returns a [`Bundle`] of a template, named, content viewport [`Node`].  
+    /// \
+    /// Fills the panel below the tab bar and clips anything that overflows it.
+    /// Lets interactions pass through to the drag node underneath.
+    fn content_node_template(&self) -> impl Bundle {
+        (
+            Name::new("[NODE] Territory Content Node"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    min_height: Val::Px(0.0),
+                    flex_grow: 1.0,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+            ContentScroll::default(),
+            TerritoryContentNode
+        )
+    }
+
+    /// Returns a [`Bundle`] of a template, named, scroll [`Node`].  
+    /// \
+    /// Tab content goes in here. It is as tall as its content, and is offset upwards when scrolled.
+    fn scroll_node_template(&self) -> impl Bundle {
+        (
+            Name::new("[NODE] Territory Scroll Node"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    top: Val::Px(0.0),
+                    ..default()
+                },
+                ..default()
+            },
+            TerritoryScrollNode
+        )
+    }
+
+    /// Returns a [`Bundle`] of a template, named, empty hint [`Node`].  
+    /// \
+    /// Centered over the content viewport, with the hint text spawned as a child. 
+    /// Hidden whenever the [`Territory`] has tabs.
+    fn empty_hint_node_template(&self) -> impl Bundle {
+        (
+            Name::new("[NODE] Territory Empty Hint Node"),
+            NodeBundle {
+                style: Style {
+                    display: if self.has_no_tabs() { Display::Flex } else { Display::None },
+                    position_type: PositionType::Absolute,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            TerritoryEmptyHintNode
+        )
+    }
+
 }
 
 /// The first system to respond to a [`TerritorySpawnRequest`]. Actually spawns the [`Territory`] entity and associated components.
-/// This system also spawns bevy_ui nodes for UI representation, which subsequent library-specific spawn systems can build on top of.
+/// This system also spawns bevy_ui nodes for UI representation, which subsequent library-specific spawn systems can build on top of.  
+/// \
+/// Requests for anything other than a configured [`TerritoryTabs`] `Window` are answered with a [`TerritorySpawnRejected`].
 pub fn spawn_territory (
     mut commands: Commands,
     mut territory_spawn_request_event: EventReader<TerritorySpawnRequest>,
-    root_node_query: Query<(Entity, &TerritoryTabsUIRoot)>
+    mut territory_spawn_rejected_event: EventWriter<TerritorySpawnRejected>,
+    window_root_index: Res<WindowRootIndex>,
+    tab_text_config: Res<TabTextConfig>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    territory_tabs_window_query: Query<&Window, With<TerritoryTabs>>
 ) {
     for spawn_event in territory_spawn_request_event.read() {
+
+        // A Territory outside of a Territory Tabs window would be orphaned with nothing to display it.
+        let Ok(window) = territory_tabs_window_query.get(spawn_event.window_entity) else {
+            warn!("Territory spawn requested in {:?}, which is not a Territory Tabs window. Spawn rejected!", spawn_event.window_entity);
+            territory_spawn_rejected_event.send(TerritorySpawnRejected { 
+                window_entity: spawn_event.window_entity, 
+                reason: TerritorySpawnRejection::NotTerritoryTabsWindow 
+            });
+            continue;
+        };
         
-        // Spawn new Territory with the requested RectKit.
+        // Spawn new Territory with the requested RectKit, resolving a relative one against the window size.
         let mut new_territory = Territory::empty();
-        new_territory.expanse = spawn_event.expanse;
+        new_territory.expanse = match spawn_event.relative_screenspace {
+            Some(relative_screenspace) => {
+                let unit_rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+                let clamped_rect = relative_screenspace.intersect(unit_rect);
+                if clamped_rect != relative_screenspace {
+                    warn!("Relative Territory spawn rect {:?} is outside of 0.0 to 1.0, clamping it to {:?}.", relative_screenspace, clamped_rect);
+                }
+                RectKit::from_relative_screenspace(clamped_rect, window.width(), window.height())
+            },
+            None => spawn_event.expanse
+        };
 
         // Find the correct bevy_ui root node entity associated with our spawn event window entity.
-        // This is messy and should be refactored when Bevy's entity relations features arrive.
-        let mut root_node_entity = Entity::PLACEHOLDER;
-        for (ui_root_entity, ui_root_window) in & root_node_query {
-            if ui_root_window.associated_window_entity == spawn_event.window_entity {
-                root_node_entity = ui_root_entity;
-            }
-        }
-
-        // Again, entity relations should render this unnecessary in the future.
-        if root_node_entity == Entity::PLACEHOLDER {
-            error!("Unable to find [ROOT NODE] entity for this window, Territory spawn canceled!");
-            break;
-        }
+        let Some(root_node_entity) = window_root_index.root_of(spawn_event.window_entity) else {
+            error!("Unable to find [ROOT NODE] entity for this window, Territory spawn rejected!");
+            territory_spawn_rejected_event.send(TerritorySpawnRejected { 
+                window_entity: spawn_event.window_entity, 
+                reason: TerritorySpawnRejection::MissingRootNode 
+            });
+            continue;
+        };
         
         // If the entire Territory UI is being handled by egui's immediate mode library, then no nodes are required.
         // For all others, spawn the needed node entities and stash the needed entity IDs in the Territory component.
         let base_node_option;
         let drag_node_option;
         let resize_node_option;
+        let content_node_option;
+        let add_tab_button_option;
         match spawn_event.display_library {
             DisplayLibrary::BevyEgui => { 
                 base_node_option = None;
                 drag_node_option = None;
                 resize_node_option = None;
+                content_node_option = None;
+                add_tab_button_option = None;
             },
             DisplayLibrary::BevyUi | 
             DisplayLibrary::BevySickle => {
                 let base_node_entity = commands.spawn(new_territory.base_node_template()).id();
                 let border_node_entity = commands.spawn(new_territory.border_node_template()).id();
-                let drag_node_entity = commands.spawn(new_territory.drag_node_template()).id();
-                let resize_node_entity = commands.spawn(new_territory.resize_node_template()).id();
+                let drag_node_entity = commands.spawn(new_territory.drag_node_template(territory_settings.drag_zone)).id();
+                let resize_node_entity = commands.spawn(new_territory.resize_node_template(territory_settings.resize_handle_size)).id();
 
                 commands.entity(base_node_entity).add_child(border_node_entity);
                 commands.entity(border_node_entity).add_child(drag_node_entity);
 
                 commands.entity(base_node_entity).add_child(resize_node_entity);
-                for resize_direction in ResizeDirection::ORDINAL {
+                for resize_direction in ResizeDirection::ORDINAL
+                    .into_iter()
+                    .filter(|resize_direction| spawn_event.resizable_edges.allows(resize_direction)) {
                     let new_resize_button = commands.spawn(new_territory.resize_button_template(resize_direction)).id();
                     commands.entity(resize_node_entity).add_child(new_resize_button);
                 }
 
+                // Tab bar and content go in the center of the resize grid, with the content below where it can scroll.
+                let panel_node_entity = commands.spawn(new_territory.panel_node_template()).id();
+                let tab_bar_node_entity = commands.spawn(new_territory.tab_bar_node_template()).id();
+                let content_node_entity = commands.spawn(new_territory.content_node_template()).id();
+                let scroll_node_entity = commands.spawn(new_territory.scroll_node_template()).id();
+                commands.entity(resize_node_entity).add_child(panel_node_entity);
+                commands.entity(panel_node_entity).push_children(&[tab_bar_node_entity, content_node_entity]);
+                commands.entity(content_node_entity).add_child(scroll_node_entity);
+
+                let add_tab_button_entity = commands.spawn(new_territory.add_tab_button_template())
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_sections(tab_text_config.sections(
+                            "+", 
+                            TextStyle { font_size: SIGNET_SIZE.y * 0.8, color: Color::srgb_u8(93, 235, 215), ..default() }
+                        )));
+                    })
+                    .id();
+                commands.entity(tab_bar_node_entity).add_child(add_tab_button_entity);
+
+                let empty_hint_node_entity = commands.spawn(new_territory.empty_hint_node_template())
+                    .with_children(|hint| {
+                        hint.spawn(TextBundle::from_sections(tab_text_config.sections(
+                            "No tabs here yet. Press + to add one.", 
+                            TextStyle { font_size: 14.0, color: Color::srgb_u8(140, 170, 185), ..default() }
+                        )));
+                    })
+                    .id();
+                commands.entity(content_node_entity).add_child(empty_hint_node_entity);
+
                 base_node_option = Some(base_node_entity);
                 drag_node_option = Some(drag_node_entity);
                 resize_node_option = Some(resize_node_entity);
+                content_node_option = Some(content_node_entity);
+                add_tab_button_option = Some(add_tab_button_entity);
             }
         }
         new_territory.base_node = base_node_option;
         new_territory.drag_node = drag_node_option;
         new_territory.resize_node = resize_node_option;
+        new_territory.content_node = content_node_option;
 
         // Spawn Territory.
         let new_territory_entity = commands.spawn(
@@ -236,10 +438,18 @@ pub fn spawn_territory (
                 new_territory,
                 SpatialBundle::default(),
                 spawn_event.display_library,
+                spawn_event.resizable_edges,
                 CardinalConnections::default()
             )
         ).id();
 
+        // Now that the Territory exists, point the add tab button at it.
+        if let Some(add_tab_button_entity) = add_tab_button_option {
+            commands.entity(add_tab_button_entity).insert(
+                TerritoryAddTabButton { territory: new_territory_entity, tab_type: TabType::default() }
+            );
+        }
+
         // Add new Territory to the spawn Window.
         commands.entity(spawn_event.window_entity).add_child(new_territory_entity);
 
@@ -251,30 +461,110 @@ pub fn spawn_territory (
     }
 }
 
-/// Handles all [`TerritoryDespawnRequest`], cleaning up the [`Territory`] and all associated nodes.
+/// Handles all [`TerritoryDespawnRequest`], sending a [`TerritoryDespawnRequested`] for each [`Territory`]. 
+/// Duplicate requests for the same Territory in one frame are only acted on once.  
+/// \
+/// Without a [`TerritoryDespawnGuard`], the [`Territory`] and all associated nodes are cleaned up right away. 
+/// With one, that waits for [`despawn_approved_territories`].
 pub fn despawn_territory (
     mut commands: Commands,
     mut territory_despawn_request_event: EventReader<TerritoryDespawnRequest>,
+    mut territory_despawn_requested_event: EventWriter<TerritoryDespawnRequested>,
+    despawn_guard: Option<Res<TerritoryDespawnGuard>>,
     territory_query: Query<&Territory>
 ) {
+    let mut despawned_territories: HashSet<Entity> = HashSet::new();
     for despawn_event in territory_despawn_request_event.read() {
-        if let Ok(despawning_territory) = territory_query.get(despawn_event.despawned_territory) {
-            // Despawn base UI Node, if it exists.
-            if let Some(despawning_base_node) = despawning_territory.base_node() {
-                commands.entity(despawning_base_node).despawn_recursive();
+        if !despawned_territories.insert(despawn_event.despawned_territory) {
+            debug!("Duplicate despawn requested for Territory {:?}, ignoring.", despawn_event.despawned_territory);
+            continue;
+        }
+        if !territory_query.contains(despawn_event.despawned_territory) {
+            continue;
+        }
+        territory_despawn_requested_event.send(TerritoryDespawnRequested { territory: despawn_event.despawned_territory });
+        if despawn_guard.is_none() {
+            despawn_territory_and_nodes(&mut commands, &territory_query, despawn_event.despawned_territory);
+        }
+    }
+}
+
+/// Despawns each [`Territory`] with a [`TerritoryDespawnApproved`], and all associated nodes, while a [`TerritoryDespawnGuard`] is in place.
+pub fn despawn_approved_territories (
+    mut commands: Commands,
+    mut territory_despawn_approved_event: EventReader<TerritoryDespawnApproved>,
+    despawn_guard: Option<Res<TerritoryDespawnGuard>>,
+    territory_query: Query<&Territory>
+) {
+    if despawn_guard.is_none() {
+        territory_despawn_approved_event.clear();
+        return;
+    }
+    let mut despawned_territories: HashSet<Entity> = HashSet::new();
+    for approved_event in territory_despawn_approved_event.read() {
+        if despawned_territories.insert(approved_event.territory) {
+            despawn_territory_and_nodes(&mut commands, &territory_query, approved_event.territory);
+        }
+    }
+}
+
+/// Despawns a [`Territory`] along with its base node, if it's still around.
+fn despawn_territory_and_nodes(commands: &mut Commands, territory_query: &Query<&Territory>, territory_entity: Entity) {
+    let Ok(despawning_territory) = territory_query.get(territory_entity) else {
+        debug!("Territory {:?} was already gone when its despawn went through.", territory_entity);
+        return;
+    };
+    // Despawn base UI Node, if it exists.
+    if let Some(despawning_base_node) = despawning_territory.base_node() {
+        commands.entity(despawning_base_node).despawn_recursive();
+    }
+    // Despawn Territory.
+    commands.entity(territory_entity).despawn_recursive();
+}
+
+/// Finds [`Territory`]s whose nodes were despawned out from under them, which would otherwise leave them alive but invisible.
+/// The dangling references are cleared and the [`Territory`] is sent off with a [`TerritoryDespawnRequest`].
+pub fn despawn_territories_with_dangling_nodes (
+    mut territory_query: Query<(Entity, &mut Territory)>,
+    node_query: Query<(), With<Node>>,
+    mut territory_despawn_request_event: EventWriter<TerritoryDespawnRequest>
+) {
+    for (territory_entity, mut territory) in &mut territory_query {
+        let is_dangling = |node: Option<Entity>| node.is_some_and(|node_entity| !node_query.contains(node_entity));
+        if !(is_dangling(territory.base_node) 
+            || is_dangling(territory.drag_node) 
+            || is_dangling(territory.resize_node) 
+            || is_dangling(territory.content_node)) {
+            continue;
+        }
+
+        warn!("Territory {:?} lost one of its nodes, despawning it.", territory_entity);
+        let territory = territory.as_mut();
+        for node in [
+            &mut territory.base_node, 
+            &mut territory.drag_node, 
+            &mut territory.resize_node, 
+            &mut territory.content_node
+        ] {
+            if is_dangling(*node) {
+                *node = None;
             }
-            // Despawn Territory.
-            commands.entity(despawn_event.despawned_territory).despawn_recursive();
         }
+        territory_despawn_request_event.send(TerritoryDespawnRequest { despawned_territory: territory_entity });
     }
 }
 
-/// When detecting a [`Territory`] change, update the position of its base node.
+/// When detecting a [`Territory`] change, update the position of its base node.  
+/// \
+/// A [`Territory`] with [`AnimateMove`] gets a [`MoveAnimation`] from where its base node is drawn instead, 
+/// unless it's the one being dragged or resized.
 pub fn update_territory_base_node (
-    territory_query: Query<&Territory, Changed<Territory>>,
+    mut commands: Commands,
+    territory_query: Query<(Entity, &Territory), Changed<Territory>>,
+    animated_territory_query: Query<(), (With<AnimateMove>, Without<MotionOrigin>)>,
     mut base_node_query: Query<&mut Style, With<TerritoryBaseNode>>
 ) {
-    for territory in & territory_query {
+    for (territory_entity, territory) in & territory_query {
 
         let Some(base_node_entity) = territory.base_node() else {
             continue;
@@ -284,10 +574,1283 @@ pub fn update_territory_base_node (
             continue;
         };
 
-        base_node_style.width = Val::Percent(territory.expanse.relative_screenspace.width() * 100.0);
-        base_node_style.height = Val::Percent(territory.expanse.relative_screenspace.height() * 100.0);
-        base_node_style.left = Val::Percent(territory.expanse.relative_screenspace.min.x * 100.0);
-        base_node_style.top = Val::Percent(territory.expanse.relative_screenspace.min.y * 100.0);
+        let target_rect = territory.expanse.relative_screenspace();
+        if animated_territory_query.contains(territory_entity) {
+            if let Some(drawn_rect) = relative_rect_of_style(&base_node_style) {
+                // Percentages don't round trip exactly, so only a real move is animated.
+                if !drawn_rect.min.abs_diff_eq(target_rect.min, 1e-5) || !drawn_rect.max.abs_diff_eq(target_rect.max, 1e-5) {
+                    commands.entity(territory_entity).insert(MoveAnimation { 
+                        from: drawn_rect, 
+                        to: target_rect, 
+                        elapsed: Duration::ZERO 
+                    });
+                }
+                else {
+                    commands.entity(territory_entity).remove::<MoveAnimation>();
+                }
+                continue;
+            }
+        }
+        commands.entity(territory_entity).remove::<MoveAnimation>();
+
+        let positioned_style = territory.expanse.to_absolute_style();
+        base_node_style.width = positioned_style.width;
+        base_node_style.height = positioned_style.height;
+        base_node_style.left = positioned_style.left;
+        base_node_style.top = positioned_style.top;
+
+    }
+}
+
+/// Keeps already spawned [`Territory`]s in line with [`GlobalTerritorySettings`] changed at runtime.  
+/// \
+/// Content nodes are padded by [`GlobalTerritorySettings::inner_margins`], which new [`Territory`]s get too, 
+/// resize grids are re-gridded to [`GlobalTerritorySettings::resize_handle_size`], 
+/// and drag nodes are resized to fit [`GlobalTerritorySettings::drag_zone`]. 
+/// A [`Territory`] smaller than a raised minimum size gets a [`MoveRequest`] to grow into it, pushing its neighbors as usual. 
+/// [`CardinalConnections`] pick up new [`GlobalTerritorySettings::outer_margins`] in [`rebuild_cardinal_connections`].
+#[allow(clippy::too_many_arguments)]
+pub fn apply_territory_settings (
+    mut commands: Commands,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<&Window, With<TerritoryTabs>>,
+    territory_query: Query<(Entity, Ref<Territory>, &Parent, Option<&MinSizeOverride>)>,
+    moving_query: Query<(), With<MoveRequest>>,
+    mut content_node_query: Query<&mut Style, (With<TerritoryContentNode>, Without<TerritoryResizeGridNode>)>,
+    mut resize_node_query: Query<&mut Style, (With<TerritoryResizeGridNode>, Without<TerritoryDragNode>)>,
+    mut drag_node_query: Query<&mut Style, (With<TerritoryDragNode>, Without<TerritoryContentNode>)>
+) {
+    let settings_changed = territory_settings.is_changed();
+    let content_padding = UiRect::axes(Val::Px(territory_settings.inner_margins.x), Val::Px(territory_settings.inner_margins.y));
+    let resize_grid = resize_grid_tracks(territory_settings.resize_handle_size);
+    let drag_node_height = territory_settings.drag_zone.node_height();
+
+    for (territory_entity, territory, territory_parent, min_size_override) in & territory_query {
+        if !settings_changed && !territory.is_added() {
+            continue;
+        }
+
+        if let Some(mut content_style) = territory.content_node().and_then(|content_node| content_node_query.get_mut(content_node).ok()) {
+            if content_style.padding != content_padding {
+                content_style.padding = content_padding;
+            }
+        }
+        if let Some(mut resize_style) = territory.resize_node().and_then(|resize_node| resize_node_query.get_mut(resize_node).ok()) {
+            if resize_style.grid_template_rows != resize_grid {
+                resize_style.grid_template_rows = resize_grid.clone();
+                resize_style.grid_template_columns = resize_grid.clone();
+            }
+        }
+        if let Some(mut drag_style) = territory.drag_node().and_then(|drag_node| drag_node_query.get_mut(drag_node).ok()) {
+            if drag_style.height != drag_node_height {
+                drag_style.height = drag_node_height;
+            }
+        }
+
+        if !settings_changed || moving_query.contains(territory_entity) {
+            continue;
+        }
+        let Ok(window) = window_query.get(territory_parent.get()) else {
+            continue;
+        };
+        let screenspace = territory.expanse.screenspace();
+        let min_size = territory_settings.min_size_for(min_size_override, Vec2::new(window.width(), window.height()));
+        let grown_size = screenspace.size().max(min_size);
+        if grown_size == screenspace.size() {
+            continue;
+        }
+        // Grow from the top left corner, like a resize from the bottom right.
+        commands.entity(territory_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::from_corners(screenspace.min, screenspace.min + grown_size), window.width(), window.height()),
+            MoveRequestType::Unknown
+        ));
+    }
+}
+
+/// Spawns and despawns a [`Territory`]'s resize buttons to match its [`ResizableEdges`] when they change after it spawned.  
+/// \
+/// Like at spawn, a corner button is only kept if both of its sides are enabled.
+pub fn sync_resize_buttons_to_edges (
+    mut commands: Commands,
+    territory_query: Query<(&Territory, Ref<ResizableEdges>)>,
+    resize_grid_query: Query<&Children, With<TerritoryResizeGridNode>>,
+    resize_button_query: Query<(Entity, &ResizeDirection), With<TerritoryResizeButtonNode>>
+) {
+    for (territory, resizable_edges) in & territory_query {
+        if !resizable_edges.is_changed() {
+            continue;
+        }
+        let Some(resize_node_entity) = territory.resize_node() else {
+            continue;
+        };
+        let resize_buttons: Vec<(Entity, ResizeDirection)> = resize_grid_query.get(resize_node_entity)
+            .map(|resize_grid_children| resize_button_query.iter_many(resize_grid_children)
+                .map(|(resize_button_entity, resize_direction)| (resize_button_entity, *resize_direction))
+                .collect())
+            .unwrap_or_default();
+
+        for (resize_button_entity, resize_direction) in &resize_buttons {
+            if !resizable_edges.allows(resize_direction) {
+                commands.entity(*resize_button_entity).despawn_recursive();
+            }
+        }
+        for resize_direction in ResizeDirection::ORDINAL {
+            let has_button = resize_buttons.iter()
+                .any(|(_, existing_direction)| std::mem::discriminant(existing_direction) == std::mem::discriminant(&resize_direction));
+            if resizable_edges.allows(&resize_direction) && !has_button {
+                let new_resize_button = commands.spawn(territory.resize_button_template(resize_direction)).id();
+                commands.entity(resize_node_entity).add_child(new_resize_button);
+            }
+        }
+    }
+}
+
+/// Puts a centered [`EmptyWindowSpawnButton`] in every `Window` with a root node that doesn't have one yet.  
+/// \
+/// Runs on entering [`TerritoryTabsState::Empty`](crate::systems_common::TerritoryTabsState::Empty), 
+/// and again whenever a root node is indexed during it, since `Window`s can open after the app is already empty.
+pub fn spawn_empty_window_spawn_buttons (
+    mut commands: Commands,
+    window_root_index: Res<WindowRootIndex>,
+    tab_text_config: Res<TabTextConfig>,
+    spawn_node_query: Query<&EmptyWindowSpawnNode>
+) {
+    for (window_entity, root_node_entity) in window_root_index.iter() {
+        if spawn_node_query.iter().any(|spawn_node| spawn_node.window == window_entity) {
+            continue;
+        }
+
+        let spawn_node_entity = commands.spawn((
+            Name::new("[NODE] Empty Window Spawn Node"),
+            EmptyWindowSpawnNode { window: window_entity },
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            }
+        ))
+            .with_children(|spawn_node| {
+                spawn_node.spawn((
+                    Name::new("[NODE] Empty Window Spawn Button"),
+                    EmptyWindowSpawnButton { window: window_entity },
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(Color::srgb_u8(60, 91, 111)),
+                        ..default()
+                    }
+                ))
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_sections(tab_text_config.sections(
+                            "New Territory", 
+                            TextStyle { font_size: 16.0, color: Color::srgb_u8(93, 235, 215), ..default() }
+                        )));
+                    });
+            })
+            .id();
+        commands.entity(root_node_entity).add_child(spawn_node_entity);
+        debug!("Spawned the New Territory button for empty Window {:?}", window_entity);
+    }
+}
+
+/// Sends a [`TerritorySpawnRequest`] for a [`GlobalTerritorySettings::default_size`] [`Territory`], 
+/// centered in the `Window` and held to its size, when an [`EmptyWindowSpawnButton`] is pressed. 
+/// A `Window` without a [`DisplayLibrary`] spawns it with the [`DefaultDisplayLibrary`].
+pub fn empty_window_spawn_button_interaction (
+    territory_settings: Res<GlobalTerritorySettings>,
+    default_display_library: Option<Res<DefaultDisplayLibrary>>,
+    button_query: Query<(&Interaction, &EmptyWindowSpawnButton), Changed<Interaction>>,
+    window_query: Query<(&Window, Option<&DisplayLibrary>), With<TerritoryTabs>>,
+    mut territory_spawn_request: EventWriter<TerritorySpawnRequest>
+) {
+    let default_display_library = default_display_library.map(|default_display_library| default_display_library.0).unwrap_or_default();
+    for (interaction, spawn_button) in & button_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((window, display_library)) = window_query.get(spawn_button.window) else {
+            warn!("New Territory button pressed for {:?}, which is not a TerritoryTabs Window!", spawn_button.window);
+            continue;
+        };
+
+        let size = territory_settings.default_size.min(Vec2::new(window.width(), window.height()));
+        territory_spawn_request.send(TerritorySpawnRequest {
+            window_entity: spawn_button.window,
+            expanse: RectKit::from_worldspace(Rect::from_center_size(Vec2::ZERO, size), window.width(), window.height()),
+            relative_screenspace: None,
+            display_library: display_library.copied().unwrap_or(default_display_library),
+            resizable_edges: ResizableEdges::default()
+        });
+    }
+}
 
+/// Keeps a translucent [`TerritoryMovePreviewNode`] over every [`Territory`] with a [`DeferredExpanse`], showing where it will land.  
+/// \
+/// Previews are spawned under the `Window`'s root node, and despawned once their [`DeferredExpanse`] is committed or dropped.
+pub fn display_move_previews (
+    mut commands: Commands,
+    window_root_index: Res<WindowRootIndex>,
+    deferred_territory_query: Query<(Entity, &Parent, Ref<DeferredExpanse>)>,
+    mut preview_node_query: Query<(Entity, &TerritoryMovePreviewNode, &mut Style)>
+) {
+    let mut previewed_territories: HashSet<Entity> = HashSet::new();
+    for (preview_node_entity, preview_node, mut preview_style) in &mut preview_node_query {
+        let Ok((_, _, deferred_expanse)) = deferred_territory_query.get(preview_node.territory) else {
+            commands.entity(preview_node_entity).despawn_recursive();
+            continue;
+        };
+        previewed_territories.insert(preview_node.territory);
+        if deferred_expanse.is_changed() {
+            *preview_style = deferred_expanse.expanse().to_absolute_style();
+        }
+    }
+
+    for (territory_entity, territory_parent, deferred_expanse) in &deferred_territory_query {
+        if previewed_territories.contains(&territory_entity) {
+            continue;
+        }
+        let Some(root_node_entity) = window_root_index.root_of(territory_parent.get()) else {
+            warn!("Unable to find [ROOT NODE] entity for a Territory move preview!");
+            continue;
+        };
+        let preview_node_entity = commands.spawn((
+            Name::new("[NODE] Territory Move Preview"),
+            Overlay,
+            TerritoryMovePreviewNode { territory: territory_entity },
+            NodeBundle {
+                style: deferred_expanse.expanse().to_absolute_style(),
+                background_color: Color::srgba_u8(93, 235, 215, 60).into(),
+                z_index: ZIndex::Global(i32::MAX - 1), // Over every Territory, but under a Tab move ghost.
+                ..default()
+            }
+        )).id();
+        commands.entity(root_node_entity).add_child(preview_node_entity);
+    }
+}
+
+/// Tracks for both the rows and columns of a resize grid node, with resize handles `resize_handle_size` wide around a flexible middle.
+pub fn resize_grid_tracks(resize_handle_size: f32) -> Vec<RepeatedGridTrack> {
+    vec![
+        GridTrack::px(resize_handle_size),
+        GridTrack::flex(1.0),
+        GridTrack::px(resize_handle_size)
+    ]
+}
+
+/// Reads back the [`RectKit::relative_screenspace`] a base node [`Style`] is drawn at, if it was set by [`RectKit::to_absolute_style`].
+fn relative_rect_of_style(style: &Style) -> Option<Rect> {
+    let (Val::Percent(left), Val::Percent(top), Val::Percent(width), Val::Percent(height)) 
+        = (style.left, style.top, style.width, style.height) else {
+        return None;
+    };
+    Some(Rect::new(left / 100.0, top / 100.0, (left + width) / 100.0, (top + height) / 100.0))
+}
+
+/// Moves the base node of each [`Territory`] with a [`MoveAnimation`] along its [`AnimateMove`] curve, 
+/// removing the [`MoveAnimation`] once it arrives.
+pub fn animate_territory_base_node (
+    mut commands: Commands,
+    time: Res<Time>,
+    mut territory_query: Query<(Entity, &Territory, &AnimateMove, &mut MoveAnimation)>,
+    mut base_node_query: Query<&mut Style, With<TerritoryBaseNode>>
+) {
+    for (territory_entity, territory, animate_move, mut move_animation) in &mut territory_query {
+
+        move_animation.elapsed += time.delta();
+        if move_animation.progress(animate_move) >= 1.0 {
+            commands.entity(territory_entity).remove::<MoveAnimation>();
+        }
+
+        let Some(mut base_node_style) = territory.base_node()
+            .and_then(|base_node_entity| base_node_query.get_mut(base_node_entity).ok()) else {
+            continue;
+        };
+
+        let drawn_rect = move_animation.current_rect(animate_move);
+        base_node_style.left = Val::Percent(drawn_rect.min.x * 100.0);
+        base_node_style.top = Val::Percent(drawn_rect.min.y * 100.0);
+        base_node_style.width = Val::Percent(drawn_rect.width() * 100.0);
+        base_node_style.height = Val::Percent(drawn_rect.height() * 100.0);
     }
-}
\ No newline at end of file
+}
+
+/// Keeps the base node of every [`Floating`] or [`Maximized`] [`Territory`] drawn above the tiled ones, 
+/// and the base node of the [`Focused`] [`Territory`] above all of them.
+pub fn update_floating_base_node (
+    territory_query: Query<(Entity, &Territory, Has<Floating>, Has<Maximized>)>,
+    focused_query: Query<(), With<Focused>>,
+    mut base_node_query: Query<&mut ZIndex, With<TerritoryBaseNode>>
+) {
+    for (territory_entity, territory, is_floating, is_maximized) in & territory_query {
+        let Some(mut base_node_z_index) = territory.base_node()
+            .and_then(|base_node_entity| base_node_query.get_mut(base_node_entity).ok()) else {
+            continue;
+        };
+
+        let z_index = if focused_query.contains(territory_entity) { 
+            ZIndex::Local(2) 
+        } else if is_floating || is_maximized { 
+            ZIndex::Local(1) 
+        } else { 
+            ZIndex::Local(0) 
+        };
+        if *base_node_z_index != z_index {
+            *base_node_z_index = z_index;
+        }
+    }
+}
+
+/// Sets the background alpha of every base node from [`TerritoryStyle`], depending on whether its [`Territory`] is [`Focused`]. 
+/// Only the alpha is touched, so the base node keeps its color.
+pub fn update_territory_base_node_alpha (
+    territory_style: Res<TerritoryStyle>,
+    territory_query: Query<(&Territory, Has<Focused>)>,
+    mut base_node_query: Query<&mut BackgroundColor, With<TerritoryBaseNode>>
+) {
+    for (territory, focused) in & territory_query {
+        let Some(mut background_color) = territory.base_node()
+            .and_then(|base_node_entity| base_node_query.get_mut(base_node_entity).ok()) else {
+            continue;
+        };
+
+        let alpha = territory_style.alpha(focused);
+        if background_color.0.alpha() != alpha {
+            background_color.0.set_alpha(alpha);
+        }
+    }
+}
+
+/// Keeps each [`ContentScroll`] up to date with the laid out sizes of its content and viewport nodes.
+pub fn measure_territory_content (
+    mut content_node_query: Query<(&Node, &Children, &mut ContentScroll), With<TerritoryContentNode>>,
+    scroll_node_query: Query<&Node, With<TerritoryScrollNode>>
+) {
+    for (content_node, content_children, mut content_scroll) in &mut content_node_query {
+        let Some(scroll_node) = scroll_node_query.iter_many(content_children).next() else {
+            continue;
+        };
+
+        let content_height = scroll_node.size().y;
+        let viewport_height = content_node.size().y;
+
+        // Only touch the component if something changed, so we don't trigger change detection every frame.
+        if content_scroll.content_height != content_height || content_scroll.viewport_height != viewport_height {
+            content_scroll.set_heights(content_height, viewport_height);
+        }
+    }
+}
+
+/// Scrolls the content of the hovered [`Territory`] with the mouse wheel, clamped to the content bounds.  
+/// \
+/// Nothing scrolls while [`DevControls::ScrollResize`] is held, since the wheel is resizing the [`Territory`] instead.
+pub fn scroll_territory_content (
+    dev_controls: Option<Res<ActionState<DevControls>>>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut content_node_query: Query<(&RelativeCursorPosition, &mut ContentScroll), With<TerritoryContentNode>>
+) {
+    if dev_controls.is_some_and(|dev_controls| dev_controls.pressed(&DevControls::ScrollResize)) {
+        mouse_wheel_events.clear();
+        return;
+    }
+    for wheel_event in mouse_wheel_events.read() {
+        // Wheel up is positive, but that scrolls the content back towards the top.
+        let scroll_delta = match wheel_event.unit {
+            MouseScrollUnit::Line => { -wheel_event.y * ContentScroll::LINE_HEIGHT },
+            MouseScrollUnit::Pixel => { -wheel_event.y }
+        };
+
+        for (cursor_position, mut content_scroll) in &mut content_node_query {
+            if !cursor_position.mouse_over() {
+                continue;
+            }
+            content_scroll.scroll_by(scroll_delta);
+        }
+    }
+}
+
+/// Keeps the [`TabContentNode`] in each [`Territory`]'s [`TerritoryScrollNode`] drawn for its active [`Tab`].  
+/// \
+/// When the active [`Tab`] changes, or [`TabRenderers`] does, the old [`TabContentNode`] is despawned 
+/// and the [`TabRenderer`](crate::resources_ui::TabRenderer) registered for the active [`Tab`]'s [`TabType`] draws into a new one. 
+/// Nothing is drawn for a [`TabType`] without a renderer.
+#[allow(clippy::too_many_arguments)]
+pub fn render_active_tab_content (
+    mut commands: Commands,
+    tab_renderers: Res<TabRenderers>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    territory_query: Query<(Entity, &Territory)>,
+    tab_query: Query<(Entity, &Tab)>,
+    content_node_query: Query<&Children, With<TerritoryContentNode>>,
+    scroll_node_query: Query<(Entity, Option<&Children>), With<TerritoryScrollNode>>,
+    tab_content_query: Query<(Entity, &TabContentNode)>
+) {
+    for (territory_entity, territory) in & territory_query {
+        let Some((scroll_node_entity, scroll_children)) = territory.content_node()
+            .and_then(|content_node_entity| content_node_query.get(content_node_entity).ok())
+            .and_then(|content_children| scroll_node_query.iter_many(content_children).next()) else {
+            continue;
+        };
+        let active_tab = tab_query.iter_many(territory.tabs()).find(|(_, tab)| tab.active);
+
+        let drawn_tabs: Vec<(Entity, Entity)> = scroll_children
+            .map(|scroll_children| tab_content_query.iter_many(scroll_children)
+                .map(|(tab_content_entity, tab_content)| (tab_content_entity, tab_content.tab))
+                .collect())
+            .unwrap_or_default();
+        let up_to_date = !tab_renderers.is_changed() && match active_tab {
+            Some((active_tab_entity, _)) => drawn_tabs.len() == 1 && drawn_tabs[0].1 == active_tab_entity,
+            None => drawn_tabs.is_empty()
+        };
+        if up_to_date {
+            continue;
+        }
+
+        for (tab_content_entity, _) in drawn_tabs {
+            commands.entity(tab_content_entity).despawn_recursive();
+        }
+        let Some((active_tab_entity, active_tab)) = active_tab else { continue; };
+
+        let tab_content_entity = commands.spawn((
+            Name::new("[NODE] Tab Content Node"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            TabContentNode { tab: active_tab_entity }
+        )).id();
+        commands.entity(scroll_node_entity).add_child(tab_content_entity);
+
+        if let Some(renderer) = tab_renderers.get(active_tab.tab_type) {
+            renderer(&mut commands, &TabContentContext {
+                territory: territory_entity,
+                tab: active_tab_entity,
+                tab_type: active_tab.tab_type,
+                node: tab_content_entity,
+                content_rect: territory.content_rect_screenspace(&territory_settings)
+            });
+        }
+    }
+}
+
+/// When a [`ContentScroll`] changes, move its [`TerritoryScrollNode`] to match the offset.
+pub fn update_territory_scroll_node (
+    content_node_query: Query<(&ContentScroll, &Children), Changed<ContentScroll>>,
+    mut scroll_node_query: Query<&mut Style, With<TerritoryScrollNode>>
+) {
+    for (content_scroll, content_children) in & content_node_query {
+        let mut scroll_nodes = scroll_node_query.iter_many_mut(content_children);
+        while let Some(mut scroll_node_style) = scroll_nodes.fetch_next() {
+            scroll_node_style.top = Val::Px(-content_scroll.offset());
+        }
+    }
+}
+
+/// Shows the empty hint of a [`Territory`] while it has no tabs, and hides it otherwise.
+pub fn update_territory_empty_hint (
+    territory_query: Query<&Territory, Changed<Territory>>,
+    content_node_query: Query<&Children, With<TerritoryContentNode>>,
+    mut hint_node_query: Query<&mut Style, With<TerritoryEmptyHintNode>>
+) {
+    for territory in & territory_query {
+        let Some(content_children) = territory.content_node()
+            .and_then(|content_node_entity| content_node_query.get(content_node_entity).ok()) else {
+            continue;
+        };
+
+        let hint_display = if territory.has_no_tabs() { Display::Flex } else { Display::None };
+        let mut hint_nodes = hint_node_query.iter_many_mut(content_children);
+        while let Some(mut hint_node_style) = hint_nodes.fetch_next() {
+            if hint_node_style.display != hint_display {
+                hint_node_style.display = hint_display;
+            }
+        }
+    }
+}
+
+/// How far the cursor moved in a `Window` since the last call, tracked per `Window` in `last_cursor_positions`.  
+/// \
+/// Without a cursor, the [`WindowTouches::primary`] touch is followed instead. 
+/// Returns `None` if the same pointer wasn't in the `Window` for both calls, or it didn't move.
+fn cursor_delta(
+    last_cursor_positions: &mut HashMap<Entity, (Option<u64>, Vec2)>,
+    window_touches: Option<&WindowTouches>,
+    window_entity: Entity,
+    window: &Window
+) -> Option<Vec2> {
+    let pointer = match window_touches {
+        Some(window_touches) => window_touches.pointer(window_entity, window),
+        None => window.cursor_position().map(|cursor_position| (None, cursor_position))
+    };
+    let Some((pointer_id, cursor_position)) = pointer else {
+        last_cursor_positions.remove(&window_entity);
+        return None;
+    };
+    let (last_pointer_id, last_cursor_position) = last_cursor_positions.insert(window_entity, (pointer_id, cursor_position))?;
+    if last_pointer_id != pointer_id {
+        return None;
+    }
+    let delta = cursor_position - last_cursor_position;
+    (delta != Vec2::ZERO).then_some(delta)
+}
+
+/// Inserts a [`MotionOrigin`] on a [`Territory`] with [`DisplayLibrary::BevyUi`] when its drag node or a resize button is pressed, 
+/// and removes it once they're all released.  
+/// \
+/// The bevy_ui counterpart to the sickle motion origin system. Runs before the bevy_ui move request systems, 
+/// so the snapshot is from before the first move.
+pub fn track_motion_origin (
+    mut commands: Commands,
+    window_query: Query<&Children, With<TerritoryTabs>>,
+    territory_query: Query<(Entity, &Territory, &DisplayLibrary, Has<MotionOrigin>)>,
+    resize_grid_children_query: Query<&Children, With<TerritoryResizeGridNode>>,
+    interaction_query: Query<&Interaction>
+) {
+    for window_children in & window_query {
+        for (territory_entity, territory, display_library, has_motion_origin) in territory_query.iter_many(window_children) {
+
+            if !matches!(display_library, DisplayLibrary::BevyUi) {
+                continue;
+            }
+
+            let resize_buttons = territory.resize_node()
+                .and_then(|resize_node_entity| resize_grid_children_query.get(resize_node_entity).ok())
+                .map(|resize_grid_children| resize_grid_children.to_vec())
+                .unwrap_or_default();
+            let drag_pressed = interaction_query
+                .iter_many(territory.drag_node().iter())
+                .any(|interaction| *interaction == Interaction::Pressed);
+            let resize_pressed = interaction_query
+                .iter_many(&resize_buttons)
+                .any(|interaction| *interaction == Interaction::Pressed);
+
+            if !has_motion_origin && (drag_pressed || resize_pressed) {
+                let expanses = territory_query.iter_many(window_children)
+                    .map(|(other_entity, other_territory, _, _)| (other_entity, other_territory.expanse))
+                    .collect();
+                commands.entity(territory_entity).insert(MotionOrigin { expanses, resizing: resize_pressed, canceled: false });
+            }
+            else if has_motion_origin && !drag_pressed && !resize_pressed {
+                commands.entity(territory_entity).remove::<MotionOrigin>();
+            }
+        }
+    }
+}
+
+/// Follows the cursor while the drag node's [`Interaction`] is pressed, and creates a [`MoveRequest`] for the [`Territory`].  
+/// \
+/// The bevy_ui counterpart to the sickle drag system, for [`Territory`]s with [`DisplayLibrary::BevyUi`]. 
+/// A [`DeferredExpanse`] is moved instead of the [`Territory`]'s expanse when there is one.
+pub fn territory_drag_move_request (
+    mut commands: Commands,
+    mut last_cursor_positions: Local<HashMap<Entity, (Option<u64>, Vec2)>>,
+    window_touches: Option<Res<WindowTouches>>,
+    window_query: Query<
+        (Entity, &Window, &Children),
+        With<TerritoryTabs>
+    >,
+    territory_drag_query: Query<
+        (Entity, &Territory, &DisplayLibrary)
+    >,
+    deferred_expanse_query: Query<&DeferredExpanse>,
+    drag_node_query: Query<
+        &Interaction,
+        With<TerritoryDragNode>
+    >
+) {
+    for (window_entity, window, window_children) in & window_query {
+
+        // Track the cursor every frame, so a press doesn't pick up movement from before it.
+        let Some(drag_delta) = cursor_delta(&mut last_cursor_positions, window_touches.as_deref(), window_entity, window) else {
+            continue;
+        };
+
+        for (territory_entity, territory, display_library) in territory_drag_query.iter_many(window_children) {
+
+            // This system will only process a Territory that is being represented by bevy_ui.
+            if !matches!(display_library, DisplayLibrary::BevyUi) {
+                continue;
+            }
+
+            let Some(drag_node_entity) = territory.drag_node() else {
+                warn!("Found a Territory without a drag node!");
+                continue;
+            };
+
+            // Is the drag node being held down?
+            if !matches!(drag_node_query.get(drag_node_entity), Ok(Interaction::Pressed)) {
+                continue;
+            }
+
+            let current_rect = deferred_expanse_query.get(territory_entity).map_or(territory.expanse(), DeferredExpanse::expanse).screenspace();
+            let new_move_request = MoveRequest {
+                proposed_expanse: RectKit::from_screenspace(
+                    Rect::from_center_size(
+                        current_rect.center() + drag_delta, 
+                        current_rect.size()
+                    ),
+                    window.width(), 
+                    window.height()
+                ),
+                move_type: MoveRequestType::Drag
+            };
+
+            commands.entity(territory_entity).insert(new_move_request);
+        }
+    }
+}
+
+/// Follows the cursor while a resize button's [`Interaction`] is pressed, and creates a [`MoveRequest`] for the [`Territory`].  
+/// \
+/// The bevy_ui counterpart to the sickle resize system, for [`Territory`]s with [`DisplayLibrary::BevyUi`]. 
+/// Buttons for sides the [`Territory`]'s [`ResizableEdges`] disable are ignored, 
+/// and a [`DeferredExpanse`] is resized instead of the [`Territory`]'s expanse when there is one.
+#[allow(clippy::too_many_arguments)]
+pub fn territory_resize_move_request (
+    mut commands: Commands,
+    mut last_cursor_positions: Local<HashMap<Entity, (Option<u64>, Vec2)>>,
+    window_touches: Option<Res<WindowTouches>>,
+    window_query: Query<
+        (Entity, &Window, &Children),
+        With<TerritoryTabs>
+    >,
+    territory_resize_query: Query<
+        (Entity, &Territory, &DisplayLibrary)
+    >,
+    resizable_edges_query: Query<&ResizableEdges>,
+    deferred_expanse_query: Query<&DeferredExpanse>,
+    resize_grid_children_query: Query<
+        &Children,
+        With<TerritoryResizeGridNode>
+    >,
+    resize_button_query: Query<
+        (&Interaction, &ResizeDirection),
+        With<TerritoryResizeButtonNode>
+    >
+) {
+    for (window_entity, window, window_children) in & window_query {
+
+        let Some(drag_delta) = cursor_delta(&mut last_cursor_positions, window_touches.as_deref(), window_entity, window) else {
+            continue;
+        };
+
+        for (territory_entity, territory, display_library) in territory_resize_query.iter_many(window_children) {
+
+            // This system will only process a Territory that is being represented by bevy_ui.
+            if !matches!(display_library, DisplayLibrary::BevyUi) {
+                continue;
+            }
+
+            let Some(resize_grid_node) = territory.resize_node() else {
+                warn!("Found a Territory without a resize grid node!");
+                continue;
+            };
+
+            let Ok(resize_grid_children) = resize_grid_children_query.get(resize_grid_node) else {
+                warn!("Territory's resize grid node has no children!");
+                continue;
+            };
+
+            let resizable_edges = resizable_edges_query.get(territory_entity).copied().unwrap_or_default();
+            for (interaction, resize_direction) in resize_button_query.iter_many(resize_grid_children) {
+
+                if *interaction != Interaction::Pressed || !resizable_edges.allows(resize_direction) {
+                    continue;
+                }
+
+                // Everything is screenspace!
+                let current_rect = deferred_expanse_query.get(territory_entity).map_or(territory.expanse(), DeferredExpanse::expanse).screenspace();
+                let new_rect = resize_direction.add_delta_to_rect(current_rect, drag_delta);
+
+                let new_move_request = MoveRequest {
+                    proposed_expanse: RectKit::from_screenspace(
+                        new_rect,
+                        window.width(),
+                        window.height()
+                    ),
+                    move_type: MoveRequestType::Resize(*resize_direction)
+                };
+
+                commands.entity(territory_entity).insert(new_move_request);
+            }
+        }
+    }
+}
+
+/// Sends an [`AddTabRequest`] when a [`TerritoryAddTabButton`] is pressed.
+pub fn add_tab_button_interaction (
+    button_query: Query<(&Interaction, &TerritoryAddTabButton), Changed<Interaction>>,
+    mut add_tab_request: EventWriter<AddTabRequest>
+) {
+    for (interaction, add_tab_button) in & button_query {
+        if *interaction == Interaction::Pressed {
+            add_tab_request.send(AddTabRequest { 
+                territory: add_tab_button.territory, 
+                tab: Tab::build_from_type(add_tab_button.tab_type)
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems_common::{despawn_all_entities_with, TerritoryTabsState};
+
+    #[test]
+    fn territory_content_scrolls_and_clamps_at_bottom() {
+        let mut app = App::new();
+        app
+            .add_event::<MouseWheel>()
+            .add_systems(Update, (scroll_territory_content, update_territory_scroll_node).chain());
+
+        let scroll_node = app.world_mut().spawn((Style::default(), TerritoryScrollNode)).id();
+        let content_node = app.world_mut().spawn((
+            RelativeCursorPosition {
+                normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+                normalized: Some(Vec2::splat(0.5))
+            },
+            ContentScroll { offset: 0.0, content_height: 500.0, viewport_height: 100.0 },
+            TerritoryContentNode
+        )).id();
+        app.world_mut().entity_mut(content_node).add_child(scroll_node);
+
+        let scroll_down = |app: &mut App, pixels: f32| {
+            app.world_mut().send_event(MouseWheel { 
+                unit: MouseScrollUnit::Pixel, 
+                x: 0.0, 
+                y: -pixels, 
+                window: Entity::PLACEHOLDER 
+            });
+            app.update();
+            app.world().get::<Style>(scroll_node).unwrap().top
+        };
+
+        assert_eq!(scroll_down(&mut app, 50.0), Val::Px(-50.0), "Content failed to scroll down.");
+        assert_eq!(scroll_down(&mut app, 1000.0), Val::Px(-400.0), "Content failed to clamp at the bottom.");
+    }
+
+    #[test]
+    fn registered_renderer_draws_the_active_tab_and_swaps_with_it() {
+        let mut app = App::new();
+        app
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<TabRenderers>()
+            .add_systems(Update, render_active_tab_content);
+        app.world_mut().resource_mut::<TabRenderers>().register(TabType::Glossary, |commands, context| {
+            commands.entity(context.node).with_children(|parent| {
+                parent.spawn(TextBundle::from_section("Glossary", TextStyle::default()));
+            });
+        });
+
+        let glossary_tab = app.world_mut().spawn(Tab { active: true, ..Tab::build_from_type(TabType::Glossary) }).id();
+        let file_tab = app.world_mut().spawn(Tab::build_from_type(TabType::FileSystem)).id();
+        let scroll_node = app.world_mut().spawn((NodeBundle::default(), TerritoryScrollNode)).id();
+        let content_node = app.world_mut().spawn((NodeBundle::default(), TerritoryContentNode)).id();
+        app.world_mut().entity_mut(content_node).add_child(scroll_node);
+        let mut territory = Territory::empty();
+        territory.content_node = Some(content_node);
+        territory.tabs = vec![glossary_tab, file_tab];
+        app.world_mut().spawn(territory);
+
+        let drawn = |app: &mut App| -> Vec<(Entity, usize)> {
+            let mut tab_content_query = app.world_mut().query::<(&TabContentNode, &Parent, Option<&Children>)>();
+            tab_content_query.iter(app.world())
+                .inspect(|(_, parent, _)| assert_eq!(parent.get(), scroll_node, "Tab content was not put in the scroll node."))
+                .map(|(tab_content, _, children)| (tab_content.tab, children.map_or(0, |children| children.len())))
+                .collect()
+        };
+
+        app.update();
+        assert_eq!(drawn(&mut app), vec![(glossary_tab, 1)], "Glossary renderer did not draw the active tab.");
+        app.update();
+        assert_eq!(drawn(&mut app), vec![(glossary_tab, 1)], "Unchanged tab content was drawn again.");
+
+        app.world_mut().get_mut::<Tab>(glossary_tab).unwrap().active = false;
+        app.world_mut().get_mut::<Tab>(file_tab).unwrap().active = true;
+        app.update();
+        assert_eq!(drawn(&mut app), vec![(file_tab, 0)], "Old content was kept, or a tab without a renderer got some.");
+    }
+
+    #[test]
+    fn add_tab_button_requests_tab_for_its_territory() {
+        let mut app = App::new();
+        app
+            .add_event::<AddTabRequest>()
+            .add_systems(Update, add_tab_button_interaction);
+
+        let territory = app.world_mut().spawn(Territory::empty()).id();
+        let other_territory = app.world_mut().spawn(Territory::empty()).id();
+        let add_tab_button = app.world_mut().spawn((
+            Interaction::None, 
+            TerritoryAddTabButton { territory, tab_type: TabType::Glossary }
+        )).id();
+        app.world_mut().spawn((
+            Interaction::None, 
+            TerritoryAddTabButton { territory: other_territory, tab_type: TabType::Glossary }
+        ));
+        app.update();
+
+        // Click.
+        *app.world_mut().get_mut::<Interaction>(add_tab_button).unwrap() = Interaction::Pressed;
+        app.update();
+
+        let add_tab_events = app.world().resource::<Events<AddTabRequest>>();
+        let mut add_tab_reader = add_tab_events.get_reader();
+        let requests: Vec<&AddTabRequest> = add_tab_reader.read(add_tab_events).collect();
+        assert_eq!(requests.len(), 1, "Expected exactly one AddTabRequest from a single click.");
+        assert_eq!(requests[0].territory, territory, "AddTabRequest was sent for the wrong Territory.");
+        assert_eq!(requests[0].tab.tab_type, TabType::Glossary, "AddTabRequest has the wrong TabType.");
+    }
+
+    #[test]
+    fn pressed_bevy_ui_nodes_follow_the_cursor() {
+        let mut app = App::new();
+        app.add_systems(Update, (track_motion_origin, territory_drag_move_request, territory_resize_move_request).chain());
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let drag_node = app.world_mut().spawn((Interaction::None, TerritoryDragNode)).id();
+        let resize_button = app.world_mut().spawn((
+            Interaction::None, 
+            TerritoryResizeButtonNode, 
+            ResizeDirection::East { eastward_magnitude: ResizeMagnitude::None }
+        )).id();
+        let resize_node = app.world_mut().spawn(TerritoryResizeGridNode).add_child(resize_button).id();
+        let start_rect = Rect::new(100.0, 100.0, 300.0, 300.0);
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(start_rect, 1000.0, 1000.0);
+        territory.drag_node = Some(drag_node);
+        territory.resize_node = Some(resize_node);
+        let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevyUi)).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+
+        let move_cursor = |app: &mut App, cursor_position: Vec2| {
+            app.world_mut().get_mut::<Window>(window_entity).unwrap().set_cursor_position(Some(cursor_position));
+            app.update();
+            app.world_mut().entity_mut(territory_entity).take::<MoveRequest>()
+        };
+
+        move_cursor(&mut app, Vec2::new(200.0, 200.0));
+        assert!(move_cursor(&mut app, Vec2::new(220.0, 200.0)).is_none(), "Unpressed drag node created a MoveRequest.");
+
+        *app.world_mut().get_mut::<Interaction>(drag_node).unwrap() = Interaction::Pressed;
+        let drag_request = move_cursor(&mut app, Vec2::new(250.0, 210.0)).expect("Pressed drag node did not create a MoveRequest.");
+        assert!(app.world().get::<MotionOrigin>(territory_entity).is_some(), "Pressing the drag node did not start a motion.");
+        assert!(matches!(drag_request.move_type, MoveRequestType::Drag));
+        assert_eq!(drag_request.proposed_expanse.screenspace(), Rect::new(130.0, 110.0, 330.0, 310.0), "Drag did not follow the cursor.");
+
+        *app.world_mut().get_mut::<Interaction>(drag_node).unwrap() = Interaction::None;
+        *app.world_mut().get_mut::<Interaction>(resize_button).unwrap() = Interaction::Pressed;
+        let resize_request = move_cursor(&mut app, Vec2::new(290.0, 210.0)).expect("Pressed resize button did not create a MoveRequest.");
+        assert!(matches!(resize_request.move_type, MoveRequestType::Resize(ResizeDirection::East { .. })));
+        assert_eq!(resize_request.proposed_expanse.screenspace(), Rect::new(100.0, 100.0, 340.0, 300.0), "East resize did not follow the cursor.");
+        assert!(app.world().get::<MotionOrigin>(territory_entity).is_some(), "Handing the press to a resize button ended the motion.");
+
+        *app.world_mut().get_mut::<Interaction>(resize_button).unwrap() = Interaction::None;
+        move_cursor(&mut app, Vec2::new(300.0, 210.0));
+        assert!(app.world().get::<MotionOrigin>(territory_entity).is_none(), "Releasing every node did not end the motion.");
+    }
+
+    #[test]
+    fn oldest_touch_drags_when_there_is_no_cursor() {
+        use bevy::input::touch::{TouchInput, TouchPhase};
+
+        let mut app = App::new();
+        app
+            .init_resource::<WindowTouches>()
+            .add_systems(Update, territory_drag_move_request);
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let drag_node = app.world_mut().spawn((Interaction::Pressed, TerritoryDragNode)).id();
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(Rect::new(100.0, 100.0, 300.0, 300.0), 1000.0, 1000.0);
+        territory.drag_node = Some(drag_node);
+        let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevyUi)).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+
+        let touch = |app: &mut App, id: u64, phase: TouchPhase, position: Vec2| {
+            app.world_mut().resource_mut::<WindowTouches>().update(&TouchInput { phase, position, window: window_entity, force: None, id });
+        };
+        let drag_delta = |app: &mut App| {
+            app.update();
+            app.world_mut().entity_mut(territory_entity).take::<MoveRequest>()
+                .map(|move_request| move_request.proposed_expanse.screenspace().min - Vec2::new(100.0, 100.0))
+        };
+
+        touch(&mut app, 1, TouchPhase::Started, Vec2::new(200.0, 200.0));
+        assert_eq!(drag_delta(&mut app), None, "First touch frame has nothing to follow yet.");
+
+        // A second finger lands and moves around, but only the first one drags.
+        touch(&mut app, 2, TouchPhase::Started, Vec2::new(600.0, 600.0));
+        touch(&mut app, 2, TouchPhase::Moved, Vec2::new(700.0, 650.0));
+        touch(&mut app, 1, TouchPhase::Moved, Vec2::new(230.0, 210.0));
+        assert_eq!(drag_delta(&mut app), Some(Vec2::new(30.0, 10.0)), "Drag did not follow the oldest touch.");
+
+        // With the first finger gone, the second one takes over from where it is, without a jump.
+        touch(&mut app, 1, TouchPhase::Ended, Vec2::new(230.0, 210.0));
+        assert_eq!(drag_delta(&mut app), None, "Switching touches jumped the Territory.");
+        touch(&mut app, 2, TouchPhase::Moved, Vec2::new(690.0, 660.0));
+        assert_eq!(drag_delta(&mut app), Some(Vec2::new(-10.0, 10.0)), "Drag did not switch to the remaining touch.");
+        touch(&mut app, 2, TouchPhase::Canceled, Vec2::new(700.0, 650.0));
+        assert_eq!(drag_delta(&mut app), None, "Drag kept going without any touch.");
+    }
+
+    #[test]
+    fn animated_territory_glides_while_expanse_jumps() {
+        let mut app = App::new();
+        app
+            .init_resource::<Time>()
+            .add_systems(Update, (update_territory_base_node, animate_territory_base_node).chain());
+
+        let start_rect = Rect::new(0.0, 0.0, 200.0, 200.0);
+        let end_rect = Rect::new(100.0, 0.0, 300.0, 200.0);
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(start_rect, 1000.0, 1000.0);
+        let base_node = app.world_mut().spawn((territory.expanse.to_absolute_style(), TerritoryBaseNode)).id();
+        territory.base_node = Some(base_node);
+        let territory_entity = app.world_mut().spawn((
+            territory, 
+            AnimateMove { duration: Duration::from_millis(100), curve: EaseFunction::Linear }
+        )).id();
+        app.update();
+        assert!(app.world().get::<MoveAnimation>(territory_entity).is_none(), "Territory animated without moving.");
+
+        // Pushed by a neighbor.
+        app.world_mut().get_mut::<Territory>(territory_entity).unwrap().expanse = RectKit::from_screenspace(end_rect, 1000.0, 1000.0);
+        let advance = |app: &mut App, millis: u64| {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(millis));
+            app.update();
+            app.world().get::<Style>(base_node).unwrap().left
+        };
+
+        assert_eq!(advance(&mut app, 50), Val::Percent(5.0), "Base node is not halfway there.");
+        assert_eq!(
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace(), 
+            end_rect, 
+            "Territory expanse did not jump straight to the new rect."
+        );
+        assert_eq!(advance(&mut app, 60), Val::Percent(10.0), "Base node did not arrive.");
+        assert!(app.world().get::<MoveAnimation>(territory_entity).is_none(), "MoveAnimation was not removed once complete.");
+    }
+
+    #[test]
+    fn territory_with_despawned_base_node_is_cleaned_up() {
+        let mut app = App::new();
+        app
+            .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_systems(Update, (despawn_territories_with_dangling_nodes, despawn_territory).chain());
+
+        let base_node = app.world_mut().spawn(NodeBundle::default()).id();
+        let drag_node = app.world_mut().spawn(NodeBundle::default()).id();
+        app.world_mut().entity_mut(base_node).add_child(drag_node);
+        let mut territory = Territory::empty();
+        territory.base_node = Some(base_node);
+        territory.drag_node = Some(drag_node);
+        let territory_entity = app.world_mut().spawn(territory).id();
+        let healthy_base_node = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut healthy_territory = Territory::empty();
+        healthy_territory.base_node = Some(healthy_base_node);
+        let healthy_territory_entity = app.world_mut().spawn(healthy_territory).id();
+
+        app.update();
+        assert!(app.world().get_entity(territory_entity).is_some(), "Territory with all of its nodes was despawned.");
+
+        // Something outside of Territory Tabs cleans up the node tree.
+        app.world_mut().entity_mut(base_node).despawn_recursive();
+        app.update();
+
+        assert!(app.world().get_entity(territory_entity).is_none(), "Territory with a dangling base node was left alive.");
+        assert!(app.world().get_entity(healthy_territory_entity).is_some(), "Unrelated Territory was despawned.");
+    }
+
+    #[test]
+    fn changed_settings_restyle_content_and_grow_small_territories() {
+        let mut app = App::new();
+        app
+            .init_resource::<GlobalTerritorySettings>()
+            .add_systems(Update, apply_territory_settings);
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let content_node = app.world_mut().spawn((NodeBundle::default(), TerritoryContentNode)).id();
+        let territory_entity = app.world_mut().spawn(Territory { 
+            expanse: RectKit::from_screenspace(Rect::new(100.0, 100.0, 300.0, 200.0), 1000.0, 1000.0),
+            content_node: Some(content_node),
+            ..Territory::empty()
+        }).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+        app.update();
+
+        let padding_of = |app: &App| app.world().get::<Style>(content_node).unwrap().padding;
+        assert_eq!(padding_of(&app), UiRect::axes(Val::Px(3.0), Val::Px(3.0)), "New Territory's content was not padded by the inner margins.");
+        assert!(app.world().get::<MoveRequest>(territory_entity).is_none(), "Territory big enough already was asked to grow.");
+
+        {
+            let mut territory_settings = app.world_mut().resource_mut::<GlobalTerritorySettings>();
+            territory_settings.inner_margins = Vec2::new(10.0, 6.0);
+            territory_settings.min_size = Vec2::new(250.0, 50.0);
+        }
+        app.update();
+        assert_eq!(padding_of(&app), UiRect::axes(Val::Px(10.0), Val::Px(6.0)), "Content padding did not follow the new inner margins.");
+        let move_request = app.world().get::<MoveRequest>(territory_entity).expect("Territory below the new minimum was not grown.");
+        assert_eq!(move_request.proposed_expanse.screenspace(), Rect::new(100.0, 100.0, 350.0, 200.0));
+    }
+
+    #[test]
+    fn changed_resize_handle_size_regrids_resize_nodes() {
+        let mut app = App::new();
+        app
+            .init_resource::<GlobalTerritorySettings>()
+            .add_systems(Update, apply_territory_settings);
+        let window_entity = app.world_mut().spawn((Window::default(), TerritoryTabs)).id();
+        let territory = Territory::empty();
+        let resize_node = app.world_mut().spawn(territory.resize_node_template(ResizeDirection::SIZE)).id();
+        let territory_entity = app.world_mut().spawn(Territory { resize_node: Some(resize_node), ..territory }).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+        app.update();
+
+        let columns_of = |app: &App| app.world().get::<Style>(resize_node).unwrap().grid_template_columns.clone();
+        assert_eq!(columns_of(&app), resize_grid_tracks(ResizeDirection::SIZE), "Resize grid was changed without a settings change.");
+
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().resize_handle_size = 12.0;
+        app.update();
+        assert_eq!(columns_of(&app), resize_grid_tracks(12.0), "Resize grid did not follow the new resize handle size.");
+        assert_eq!(app.world().get::<Style>(resize_node).unwrap().grid_template_rows, resize_grid_tracks(12.0));
+    }
+
+    #[test]
+    fn unfocused_territories_dim_only_when_asked() {
+        let mut app = App::new();
+        app
+            .init_resource::<TerritoryStyle>()
+            .add_systems(Update, update_territory_base_node_alpha);
+        let base_color = Color::srgb_u8(60, 91, 111);
+        let spawn_base_node = |app: &mut App| app.world_mut().spawn((
+            NodeBundle { background_color: BackgroundColor(base_color), ..default() }, 
+            TerritoryBaseNode
+        )).id();
+        let focused_base_node = spawn_base_node(&mut app);
+        let unfocused_base_node = spawn_base_node(&mut app);
+        app.world_mut().spawn((Territory { base_node: Some(focused_base_node), ..Territory::empty() }, Focused));
+        app.world_mut().spawn(Territory { base_node: Some(unfocused_base_node), ..Territory::empty() });
+        app.update();
+
+        let color_of = |app: &App, base_node: Entity| app.world().get::<BackgroundColor>(base_node).unwrap().0;
+        assert_eq!(color_of(&app, unfocused_base_node), base_color, "Default TerritoryStyle dimmed an unfocused Territory.");
+
+        *app.world_mut().resource_mut::<TerritoryStyle>() = TerritoryStyle { focused_alpha: 1.0, unfocused_alpha: 0.6 };
+        app.update();
+        assert_eq!(color_of(&app, focused_base_node), base_color, "Focused Territory was dimmed.");
+        assert_eq!(color_of(&app, unfocused_base_node), base_color.with_alpha(0.6), "Unfocused Territory was not dimmed.");
+    }
+
+    #[test]
+    fn title_bar_drag_zone_shrinks_drag_nodes_to_the_top_strip() {
+        let mut app = App::new();
+        app
+            .init_resource::<GlobalTerritorySettings>()
+            .add_systems(Update, apply_territory_settings);
+        let window_entity = app.world_mut().spawn((Window::default(), TerritoryTabs)).id();
+        let territory = Territory::empty();
+        let drag_node = app.world_mut().spawn(territory.drag_node_template(DragZone::default())).id();
+        let territory_entity = app.world_mut().spawn(Territory { drag_node: Some(drag_node), ..territory }).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+        app.update();
+
+        let height_of = |app: &App| app.world().get::<Style>(drag_node).unwrap().height;
+        assert_eq!(height_of(&app), Val::Percent(100.0), "Default drag zone does not cover the whole Territory.");
+
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().drag_zone = DragZone { title_bar_only: true, title_bar_height: 24.0 };
+        app.update();
+        assert_eq!(height_of(&app), Val::Px(24.0), "Drag node was not shrunk to the title bar.");
+
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().drag_zone = DragZone::default();
+        app.update();
+        assert_eq!(height_of(&app), Val::Percent(100.0), "Drag node did not grow back to the whole Territory.");
+    }
+
+    #[test]
+    fn empty_window_button_spawns_a_centered_territory_and_leaves_with_empty() {
+        let mut app = App::new();
+        app
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(TerritoryTabsState::Empty)
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<TabTextConfig>()
+            .init_resource::<WindowRootIndex>()
+            .insert_resource(DefaultDisplayLibrary(DisplayLibrary::BevyEgui))
+            .add_event::<TerritorySpawnRequest>()
+            .add_systems(OnEnter(TerritoryTabsState::Empty), spawn_empty_window_spawn_buttons)
+            .add_systems(OnExit(TerritoryTabsState::Empty), despawn_all_entities_with::<EmptyWindowSpawnNode>)
+            .add_systems(Update, empty_window_spawn_button_interaction);
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        app.world_mut().resource_mut::<WindowRootIndex>().insert(window_entity, root_node_entity);
+        app.update();
+
+        let mut button_query = app.world_mut().query_filtered::<Entity, With<EmptyWindowSpawnButton>>();
+        let button_entity = button_query.single(app.world());
+        app.world_mut().entity_mut(button_entity).insert(Interaction::Pressed);
+        app.update();
+
+        let spawn_requests: Vec<TerritorySpawnRequest> = app.world_mut().resource_mut::<Events<TerritorySpawnRequest>>().drain().collect();
+        assert_eq!(spawn_requests.len(), 1, "Pressing New Territory did not request exactly one Territory.");
+        assert_eq!(spawn_requests[0].window_entity, window_entity);
+        assert_eq!(spawn_requests[0].expanse.screenspace(), Rect::new(200.0, 400.0, 800.0, 600.0), "New Territory was not default sized and centered.");
+        assert_eq!(spawn_requests[0].display_library, DisplayLibrary::BevyEgui, "Window without a DisplayLibrary did not use the default one.");
+
+        app.world_mut().resource_mut::<NextState<TerritoryTabsState>>().set(TerritoryTabsState::Natural);
+        app.update();
+        let mut spawn_node_query = app.world_mut().query::<&EmptyWindowSpawnNode>();
+        assert_eq!(spawn_node_query.iter(app.world()).count(), 0, "New Territory button stayed after leaving Empty.");
+        assert!(app.world().get_entity(button_entity).is_none());
+    }
+
+    #[test]
+    fn guarded_despawn_waits_for_approval() {
+        let mut app = App::new();
+        app
+            .init_resource::<TerritoryDespawnGuard>()
+            .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_event::<TerritoryDespawnApproved>()
+            .add_systems(Update, (
+                despawn_territory.run_if(on_event::<TerritoryDespawnRequest>()), 
+                despawn_approved_territories.run_if(on_event::<TerritoryDespawnApproved>())
+            ).chain());
+
+        let base_node = app.world_mut().spawn(NodeBundle::default()).id();
+        let territory_entity = app.world_mut().spawn(Territory { base_node: Some(base_node), ..Territory::empty() }).id();
+        app.world_mut().send_event(TerritoryDespawnRequest { despawned_territory: territory_entity });
+        app.update();
+
+        let requested: Vec<TerritoryDespawnRequested> = app.world_mut().resource_mut::<Events<TerritoryDespawnRequested>>().drain().collect();
+        assert_eq!(requested, vec![TerritoryDespawnRequested { territory: territory_entity }], "Despawn was not announced.");
+        app.update();
+        assert!(app.world().get_entity(territory_entity).is_some(), "Guarded Territory despawned without approval.");
+
+        app.world_mut().send_event(TerritoryDespawnApproved { territory: territory_entity });
+        app.update();
+        assert!(app.world().get_entity(territory_entity).is_none(), "Approved Territory was not despawned.");
+        assert!(app.world().get_entity(base_node).is_none(), "Approved Territory's base node was not despawned.");
+    }
+
+    #[test]
+    fn resize_buttons_follow_changed_resizable_edges() {
+        let mut app = App::new();
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut window_root_index = WindowRootIndex::default();
+        window_root_index.insert(window_entity, root_node_entity);
+        app
+            .insert_resource(window_root_index)
+            .init_resource::<TabTextConfig>()
+            .init_resource::<GlobalTerritorySettings>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_systems(Update, (spawn_territory, sync_resize_buttons_to_edges).chain());
+
+        app.world_mut().send_event(TerritorySpawnRequest::from_relative(window_entity, Rect::new(0.1, 0.1, 0.5, 0.5), DisplayLibrary::BevyUi));
+        app.update();
+        let resize_directions = |app: &mut App| -> Vec<ResizeDirection> {
+            let mut resize_button_query = app.world_mut().query_filtered::<&ResizeDirection, With<TerritoryResizeButtonNode>>();
+            let mut directions: Vec<ResizeDirection> = resize_button_query.iter(app.world()).copied().collect();
+            directions.sort_by_key(|direction| ResizeDirection::ORDINAL.iter()
+                .position(|ordinal| std::mem::discriminant(ordinal) == std::mem::discriminant(direction)));
+            directions
+        };
+        assert_eq!(resize_directions(&mut app).len(), 8, "Territory did not spawn with every resize button.");
+
+        let mut territory_query = app.world_mut().query_filtered::<Entity, With<Territory>>();
+        let territory_entity = territory_query.single(app.world());
+        app.world_mut().entity_mut(territory_entity).insert(ResizableEdges::NORTH | ResizableEdges::EAST);
+        app.update();
+        assert_eq!(resize_directions(&mut app), ResizeDirection::ORDINAL[0..3].to_vec(), "Only north, east, and their corner should be left.");
+
+        app.world_mut().entity_mut(territory_entity).insert(ResizableEdges::ALL);
+        app.update();
+        assert_eq!(resize_directions(&mut app), ResizeDirection::ORDINAL.to_vec(), "Re-enabled sides did not get their buttons back.");
+    }
+
+    #[test]
+    fn relative_spawn_request_resolves_against_window_size() {
+        let mut app = App::new();
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 500.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut window_root_index = WindowRootIndex::default();
+        window_root_index.insert(window_entity, root_node_entity);
+        app
+            .insert_resource(window_root_index)
+            .init_resource::<TabTextConfig>()
+            .add_event::<TerritorySpawnRequest>()
+            .init_resource::<GlobalTerritorySettings>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_systems(Update, spawn_territory);
+
+        app.world_mut().send_event(TerritorySpawnRequest::from_relative(window_entity, Rect::new(0.25, 0.0, 0.75, 0.5), DisplayLibrary::BevyEgui));
+        app.world_mut().send_event(TerritorySpawnRequest::from_relative(window_entity, Rect::new(0.5, 0.5, 1.5, 1.0), DisplayLibrary::BevyEgui));
+        app.update();
+
+        let mut territory_query = app.world_mut().query::<&Territory>();
+        let mut spawned_rects: Vec<Rect> = territory_query.iter(app.world()).map(|territory| territory.expanse().screenspace()).collect();
+        spawned_rects.sort_by(|a, b| a.min.x.total_cmp(&b.min.x));
+        assert_eq!(spawned_rects, vec![
+            Rect::new(250.0, 0.0, 750.0, 250.0),
+            Rect::new(500.0, 250.0, 1000.0, 500.0)
+        ], "Relative spawn rects were not resolved, or not clamped to the window.");
+    }
+
+    #[test]
+    fn territory_spawn_into_plain_window_is_rejected() {
+        let mut app = App::new();
+        app
+            .init_resource::<WindowRootIndex>()
+            .init_resource::<TabTextConfig>()
+            .add_event::<TerritorySpawnRequest>()
+            .init_resource::<GlobalTerritorySettings>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_systems(Update, spawn_territory);
+
+        let plain_window = app.world_mut().spawn(Window::default()).id();
+        app.world_mut().send_event(TerritorySpawnRequest { 
+            window_entity: plain_window, 
+            expanse: RectKit::from_screenspace(Rect::new(0.0, 0.0, 200.0, 100.0), 1280.0, 720.0),
+            relative_screenspace: None,
+            display_library: DisplayLibrary::BevyUi,
+            resizable_edges: ResizableEdges::default()
+        });
+        app.update();
+
+        let rejected_events = app.world().resource::<Events<TerritorySpawnRejected>>();
+        let mut rejected_reader = rejected_events.get_reader();
+        let rejections: Vec<&TerritorySpawnRejected> = rejected_reader.read(rejected_events).collect();
+        assert_eq!(rejections.len(), 1, "Spawn into a plain window was not rejected.");
+        assert_eq!(rejections[0].window_entity, plain_window);
+        assert_eq!(rejections[0].reason, TerritorySpawnRejection::NotTerritoryTabsWindow);
+
+        let mut territory_query = app.world_mut().query::<&Territory>();
+        assert_eq!(territory_query.iter(app.world()).count(), 0, "An orphaned Territory was spawned.");
+    }
+}
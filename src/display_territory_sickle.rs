@@ -1,14 +1,49 @@
 //! UI display logic for representing [`Territory`] functions using the sickle_ui library.
 //! In addition, some of the code design in this file is loosely copied from sickle_ui.
 
-use bevy::{prelude::*, ui::RelativeCursorPosition};
-use sickle_ui::{animated_interaction::AnimatedInteraction, drag_interaction::Draggable, interactions::InteractiveBackground, flux_interaction::TrackedInteraction};
+use bevy::{prelude::*, ui::RelativeCursorPosition, utils::HashMap};
+use std::time::Duration;
+use sickle_ui::{animated_interaction::AnimatedInteraction, drag_interaction::{DragState, Draggable}, interactions::InteractiveBackground, flux_interaction::TrackedInteraction};
+
+use leafwing_input_manager::prelude::ActionState;
 
 use crate::components_territory::*;
+use crate::components_ui::Tab;
+use crate::input_manager::DevControls;
+use crate::resources_ui::TabTextConfig;
+use crate::systems_territory::{SelectTab, TabSelector};
 
 /// Extension trait for adding sickle_ui related functionality to Territory Tabs types.
 pub trait SickleInterface {
+    fn tab_button_template(&self, tab: &Tab) -> impl Bundle;
+}
 
+impl SickleInterface for Territory {
+    /// Returns a [`Bundle`] of a template, named, tab button [`Node`].  
+    /// \
+    /// One signet tall, as wide as its label. The active tab is highlighted. 
+    /// The [`TerritoryTabButton`] is inserted by [`sync_territory_tab_buttons_sickle`].
+    fn tab_button_template(&self, tab: &Tab) -> impl Bundle {
+        (
+            Name::new(format!("[NODE] Territory Tab Button Node ({})", tab.name)),
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    height: Val::Px(SIGNET_SIZE.y),
+                    padding: UiRect::horizontal(Val::Px(6.0)),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                background_color: if tab.active { 
+                    BackgroundColor(Color::srgb_u8(70, 110, 130)) 
+                } else { 
+                    BackgroundColor(Color::NONE) 
+                },
+                ..default()
+            }
+        )
+    }
 }
 
 /// Follow-up config for any [`Territory`] with [`DisplayLibrary::BevySickle`].
@@ -50,72 +85,162 @@ pub fn spawn_territory_sickle (
 
             // Resize buttons are just drag areas that change the size.
             for resize_button_entity in resize_button_query.iter_many(resize_grid_children) {
-                commands.entity(resize_button_entity).insert((
-                    TrackedInteraction::default(),
-                    Draggable::default(),
-                    RelativeCursorPosition::default(),
-                    InteractiveBackground {
-                        highlight: Color::srgb_u8(115, 235, 235).into(),
-                        pressed: Color::srgb_u8(50, 245, 245).into(),
-                        cancel: Color::NONE.into()
-                    },
-                    AnimatedInteraction::<InteractiveBackground>::default()
-                ));
+                commands.entity(resize_button_entity).insert(resize_button_sickle_components());
             }
         }
     }
 }
 
+/// Components sickle needs on a resize button to track dragging it, and to highlight it.
+fn resize_button_sickle_components() -> impl Bundle {
+    (
+        TrackedInteraction::default(),
+        Draggable::default(),
+        RelativeCursorPosition::default(),
+        InteractiveBackground {
+            highlight: Color::srgb_u8(115, 235, 235).into(),
+            pressed: Color::srgb_u8(50, 245, 245).into(),
+            cancel: Color::NONE.into()
+        },
+        AnimatedInteraction::<InteractiveBackground>::default()
+    )
+}
+
+/// Gives resize buttons spawned after their [`Territory`], by [`crate::display_territory::sync_resize_buttons_to_edges`], 
+/// the same sickle components [`spawn_territory_sickle`] gives the original ones.
+pub fn sync_resize_buttons_sickle (
+    mut commands: Commands,
+    territory_query: Query<(&Territory, &DisplayLibrary), Changed<ResizableEdges>>,
+    resize_grid_query: Query<&Children, With<TerritoryResizeGridNode>>,
+    new_resize_button_query: Query<Entity, (With<TerritoryResizeButtonNode>, Without<Draggable>)>
+) {
+    for (territory, display_library) in & territory_query {
+        if !matches!(display_library, DisplayLibrary::BevySickle) {
+            continue;
+        }
+        let Some(resize_grid_children) = territory.resize_node().and_then(|resize_node| resize_grid_query.get(resize_node).ok()) else {
+            continue;
+        };
+        for resize_button_entity in new_resize_button_query.iter_many(resize_grid_children) {
+            commands.entity(resize_button_entity).insert(resize_button_sickle_components());
+        }
+    }
+}
+
+/// Sets the cursor of each [`TerritoryTabs`] `Window` to the resize arrow of the sickle resize button it's over, 
+/// from [`ResizeDirection::cursor_icon`].  
+/// \
+/// Once no resize button is hovered or pressed, a resize arrow is swapped back to [`CursorIcon::Default`]. 
+/// Any other cursor is left alone, so this doesn't fight whatever else set it.
+pub fn resize_cursor_icon_sickle (
+    mut window_query: Query<(&mut Window, &Children), With<TerritoryTabs>>,
+    territory_query: Query<(&Territory, &DisplayLibrary)>,
+    resize_grid_children_query: Query<&Children, With<TerritoryResizeGridNode>>,
+    resize_button_query: Query<(&Interaction, &ResizeDirection), With<Draggable>>
+) {
+    let resize_icons = ResizeDirection::ORDINAL.map(|resize_direction| resize_direction.cursor_icon());
+    for (mut window, window_children) in &mut window_query {
+        let hovered_icon = territory_query.iter_many(window_children)
+            .filter(|(_, display_library)| matches!(display_library, DisplayLibrary::BevySickle))
+            .filter_map(|(territory, _)| territory.resize_node().and_then(|resize_node| resize_grid_children_query.get(resize_node).ok()))
+            .flat_map(|resize_grid_children| resize_button_query.iter_many(resize_grid_children))
+            .find(|(interaction, _)| **interaction != Interaction::None)
+            .map(|(_, resize_direction)| resize_direction.cursor_icon());
+
+        let cursor_icon = match hovered_icon {
+            Some(cursor_icon) => cursor_icon,
+            None if resize_icons.contains(&window.cursor.icon) => CursorIcon::Default,
+            None => continue
+        };
+        if window.cursor.icon != cursor_icon {
+            window.cursor.icon = cursor_icon;
+        }
+    }
+}
+
 /// Reads sickle_ui's [`Draggable`] component on the drag node for a difference and creates a [`MoveRequest`] for the [`Territory`].  
+/// \
+/// Differences are added up until [`MoveRequestThrottle::interval`] has passed since the last [`MoveRequest`] or the drag ends, 
+/// so collisions are resolved once for all of them instead of once per frame. 
+/// A [`DeferredExpanse`] is moved instead of the [`Territory`]'s expanse when there is one.
+#[allow(clippy::too_many_arguments)]
 pub fn territory_drag_move_request_sickle (
     mut commands: Commands,
+    mut pending_drags: Local<HashMap<Entity, (Vec2, Duration)>>,
+    time: Res<Time>,
+    move_request_throttle: Res<MoveRequestThrottle>,
     window_query: Query<
         (&Window, &Children),
         With<TerritoryTabs>
     >,
     territory_drag_query: Query<
-        (Entity, &Territory, &DisplayLibrary)
+        (Entity, &Territory, &DisplayLibrary, Option<&MotionOrigin>)
     >,
+    deferred_expanse_query: Query<&DeferredExpanse>,
     drag_node_query: Query<
-        &Draggable,
-        (Changed<Draggable>, With<TerritoryDragNode>)
+        Ref<Draggable>,
+        With<TerritoryDragNode>
     >
 ) {
     for (window, window_children) in & window_query {
 
-        for (territory_entity, territory, display_library) in territory_drag_query.iter_many(window_children) {
+        for (territory_entity, territory, display_library, motion_origin) in territory_drag_query.iter_many(window_children) {
 
             // This system will only process a Territory that is being represented by sickle.
             if !matches!(display_library, DisplayLibrary::BevySickle) {
                 continue;
             }
 
+            // The rest of a canceled drag is ignored.
+            if motion_origin.is_some_and(MotionOrigin::canceled) {
+                pending_drags.remove(&territory_entity);
+                continue;
+            }
+
             // Did someone forget to associate a drag node with this Territory?
             let Some(drag_node_entity) = territory.drag_node() else {
                 warn!("Found a Territory without a drag node!");
                 continue;
             };
 
-            // Does this Territory have a Draggable drag node that was changed recently?
             let Ok(drag_data) = drag_node_query.get(drag_node_entity) else {
                 continue;
             };
 
-            // Is there a diff in the drag node's Draggable component? 
-            let Some(drag_delta) = drag_data.diff else {
+            // Add a recent, non-zero diff to whatever is still pending. Zero-size diffs can sneak in at drag end.
+            if drag_data.is_changed() {
+                if let Some(drag_delta) = drag_data.diff.filter(|drag_delta| *drag_delta != Vec2::ZERO) {
+                    pending_drags.entry(territory_entity)
+                        .or_insert((Vec2::ZERO, move_request_throttle.interval))
+                        .0 += drag_delta;
+                }
+            }
+
+            let Some((pending_delta, since_last_request)) = pending_drags.get_mut(&territory_entity) else {
                 continue;
             };
+            *since_last_request += time.delta();
 
-            // Is the diff greater than zero? Zero-size diffs can sneak in at drag end.
-            if drag_delta == Vec2::ZERO { 
-                continue; 
+            // Wait out the throttle, unless the drag is over.
+            let drag_ended = !matches!(drag_data.state, DragState::DragStart | DragState::Dragging);
+            if !drag_ended && (*pending_delta == Vec2::ZERO || *since_last_request < move_request_throttle.interval) {
+                continue;
+            }
+            let drag_delta = std::mem::take(pending_delta);
+            *since_last_request = Duration::ZERO;
+            if drag_ended {
+                pending_drags.remove(&territory_entity);
+            }
+            if drag_delta == Vec2::ZERO {
+                continue;
             }
 
+            let current_rect = deferred_expanse_query.get(territory_entity).map_or(territory.expanse(), DeferredExpanse::expanse).screenspace();
             let new_move_request = MoveRequest {
                 proposed_expanse: RectKit::from_screenspace(
                     Rect::from_center_size(
-                        territory.expanse().screenspace().center() + drag_delta, 
-                        territory.expanse().screenspace().size()
+                        current_rect.center() + drag_delta, 
+                        current_rect.size()
                     ),
                     window.width(), 
                     window.height()
@@ -132,33 +257,51 @@ pub fn territory_drag_move_request_sickle (
 
 
 /// Reads sickle_ui's [`Draggable`] component on the resize node buttons for a difference and creates a [`MoveRequest`] for the [`Territory`].  
+/// \
+/// Differences are throttled by [`MoveRequestThrottle`] the same way [`territory_drag_move_request_sickle`] throttles drags. 
+/// While [`DevControls::KeepAspectRatio`] is held, corner resizes keep the aspect ratio the [`Territory`] had when the resize started. 
+/// A [`DeferredExpanse`] is resized instead of the [`Territory`]'s expanse when there is one. 
+/// Drags on buttons for sides the [`Territory`]'s [`ResizableEdges`] disable are ignored.
+#[allow(clippy::too_many_arguments)]
 pub fn territory_resize_move_request_sickle (
     mut commands: Commands,
+    mut pending_resizes: Local<HashMap<Entity, (ResizeDirection, Vec2, Duration)>>,
+    time: Res<Time>,
+    move_request_throttle: Res<MoveRequestThrottle>,
+    dev_controls: Option<Res<ActionState<DevControls>>>,
     window_query: Query<
         (&Window, &Children),
         With<TerritoryTabs>
     >,
     territory_resize_query: Query<
-        (Entity, &Territory, &DisplayLibrary)
+        (Entity, &Territory, &DisplayLibrary, Option<&MotionOrigin>)
     >,
+    deferred_expanse_query: Query<&DeferredExpanse>,
+    resizable_edges_query: Query<&ResizableEdges>,
     resize_grid_children_query: Query<
         &Children,
         With<TerritoryResizeGridNode>
     >,
     resize_button_query: Query<
-        (&Draggable, &ResizeDirection),
-        (Changed<Draggable>, With<TerritoryResizeButtonNode>)
+        (Ref<Draggable>, &ResizeDirection),
+        With<TerritoryResizeButtonNode>
     >
 ) {
     for (window, window_children) in & window_query {
 
-        for (territory_entity, territory, display_library) in territory_resize_query.iter_many(window_children) {
+        for (territory_entity, territory, display_library, motion_origin) in territory_resize_query.iter_many(window_children) {
 
             // This system will only process a Territory that is being represented by sickle.
             if !matches!(display_library, DisplayLibrary::BevySickle) {
                 continue;
             }
 
+            // The rest of a canceled resize is ignored.
+            if motion_origin.is_some_and(MotionOrigin::canceled) {
+                pending_resizes.remove(&territory_entity);
+                continue;
+            }
+
             // Is there a resize grid node addociated with this Territory?
             let Some(resize_grid_node) = territory.resize_node() else {
                 warn!("Found a Territory without a resize grid node!");
@@ -171,34 +314,402 @@ pub fn territory_resize_move_request_sickle (
                 continue;
             };
 
+            let resizable_edges = resizable_edges_query.get(territory_entity).copied().unwrap_or_default();
+            let mut resize_ended = true;
             for (resize_button_draggable, resize_direction) in resize_button_query.iter_many(resize_grid_children) {
 
-                // Is there a diff in the drag node's Draggable component? 
-                let Some(drag_delta) = resize_button_draggable.diff else {
+                // A button can outlive a change to ResizableEdges until it is synced away.
+                if !resizable_edges.allows(resize_direction) {
+                    continue;
+                }
+
+                if matches!(resize_button_draggable.state, DragState::DragStart | DragState::Dragging) {
+                    resize_ended = false;
+                }
+
+                // Add a recent, non-zero diff to whatever is still pending. Zero-size diffs can sneak in at drag end.
+                if !resize_button_draggable.is_changed() {
+                    continue;
+                }
+                let Some(drag_delta) = resize_button_draggable.diff.filter(|drag_delta| *drag_delta != Vec2::ZERO) else {
                     continue;
                 };
+                let pending_resize = pending_resizes.entry(territory_entity)
+                    .or_insert((*resize_direction, Vec2::ZERO, move_request_throttle.interval));
 
-                // Is the diff greater than zero? Zero-size diffs can sneak in at drag end.
-                if drag_delta == Vec2::ZERO { 
-                    continue; 
+                // Only one button is dragged at a time, but a new one starts over.
+                if pending_resize.0 != *resize_direction {
+                    *pending_resize = (*resize_direction, Vec2::ZERO, move_request_throttle.interval);
                 }
+                pending_resize.1 += drag_delta;
+            }
 
-                // Mod a new screenspace rect, depending on ResizeDirection. Everything is screenspace!
-                let new_rect = resize_direction.add_delta_to_rect(territory.expanse().screenspace(), drag_delta);
+            let Some((resize_direction, pending_delta, since_last_request)) = pending_resizes.get_mut(&territory_entity) else {
+                continue;
+            };
+            *since_last_request += time.delta();
 
-                let new_move_request = MoveRequest {
-                    proposed_expanse: RectKit::from_screenspace(
-                        new_rect,
-                        window.width(),
-                        window.height()
-                    ),
-                    move_type: MoveRequestType::Resize(resize_direction.clone())
-                };
+            // Wait out the throttle, unless the resize is over.
+            if !resize_ended && (*pending_delta == Vec2::ZERO || *since_last_request < move_request_throttle.interval) {
+                continue;
+            }
+            let resize_direction = *resize_direction;
+            let drag_delta = std::mem::take(pending_delta);
+            *since_last_request = Duration::ZERO;
+            if resize_ended {
+                pending_resizes.remove(&territory_entity);
+            }
+            if drag_delta == Vec2::ZERO {
+                continue;
+            }
+
+            // Mod a new screenspace rect, depending on ResizeDirection. Everything is screenspace!
+            let current_rect = deferred_expanse_query.get(territory_entity).map_or(territory.expanse(), DeferredExpanse::expanse).screenspace();
+            let mut new_rect = resize_direction.add_delta_to_rect(current_rect, drag_delta);
 
-                commands.entity(territory_entity).insert(new_move_request);
+            if dev_controls.as_ref().is_some_and(|dev_controls| dev_controls.pressed(&DevControls::KeepAspectRatio)) {
+                let pre_resize_rect = motion_origin
+                    .and_then(|motion_origin| motion_origin.expanses.iter().find(|(entity, _)| *entity == territory_entity))
+                    .map_or(territory.expanse().screenspace(), |(_, expanse)| expanse.screenspace());
+                new_rect = resize_direction.keep_aspect_ratio(new_rect, pre_resize_rect.width() / pre_resize_rect.height());
             }
 
+            let new_move_request = MoveRequest {
+                proposed_expanse: RectKit::from_screenspace(
+                    new_rect,
+                    window.width(),
+                    window.height()
+                ),
+                move_type: MoveRequestType::Resize(resize_direction)
+            };
+
+            commands.entity(territory_entity).insert(new_move_request);
+
+        }
+
+    }
+}
+
+/// Inserts a [`MotionOrigin`] on a [`Territory`] with [`DisplayLibrary::BevySickle`] when a drag or resize of it starts, 
+/// and removes it once the drag ends.  
+/// \
+/// Runs before the sickle move request systems, so the snapshot is from before the first move.
+pub fn track_motion_origin_sickle (
+    mut commands: Commands,
+    window_query: Query<&Children, With<TerritoryTabs>>,
+    territory_query: Query<(Entity, &Territory, &DisplayLibrary, Has<MotionOrigin>)>,
+    resize_grid_children_query: Query<&Children, With<TerritoryResizeGridNode>>,
+    draggable_query: Query<&Draggable>
+) {
+    for window_children in & window_query {
+        for (territory_entity, territory, display_library, has_motion_origin) in territory_query.iter_many(window_children) {
+
+            if !matches!(display_library, DisplayLibrary::BevySickle) {
+                continue;
+            }
+
+            let resize_buttons = territory.resize_node()
+                .and_then(|resize_node_entity| resize_grid_children_query.get(resize_node_entity).ok())
+                .map(|resize_grid_children| resize_grid_children.to_vec())
+                .unwrap_or_default();
+            let drag_states: Vec<DragState> = draggable_query
+                .iter_many(territory.drag_node().iter().chain(resize_buttons.iter()))
+                .map(|draggable| draggable.state)
+                .collect();
+
+            if !has_motion_origin && drag_states.contains(&DragState::DragStart) {
+                let expanses = territory_query.iter_many(window_children)
+                    .map(|(other_entity, other_territory, _, _)| (other_entity, other_territory.expanse))
+                    .collect();
+                let resizing = draggable_query.iter_many(&resize_buttons).any(|draggable| draggable.state == DragState::DragStart);
+                commands.entity(territory_entity).insert(MotionOrigin { expanses, resizing, canceled: false });
+            }
+            else if has_motion_origin && !drag_states.iter().any(|drag_state| matches!(drag_state, DragState::DragStart | DragState::Dragging)) {
+                commands.entity(territory_entity).remove::<MotionOrigin>();
+            }
+        }
+    }
+}
+
+/// Keeps the tab bar of each [`Territory`] with [`DisplayLibrary::BevySickle`] showing one [`TerritoryTabButton`] per [`Tab`].  
+/// \
+/// Buttons are rebuilt, in tab list order and ahead of the add tab button, 
+/// whenever the tab list changes or any of its [`Tab`]s do. 
+/// The tab bar sits under the [`TerritoryBaseNode`], so it moves and resizes with the [`Territory`].
+#[allow(clippy::too_many_arguments)]
+pub fn sync_territory_tab_buttons_sickle (
+    mut commands: Commands,
+    tab_text_config: Res<TabTextConfig>,
+    territory_query: Query<(Entity, &Territory, &DisplayLibrary)>,
+    tab_query: Query<Ref<Tab>>,
+    parent_query: Query<&Parent>,
+    children_query: Query<&Children>,
+    tab_bar_query: Query<(), With<TerritoryTabBarNode>>,
+    tab_button_query: Query<(Entity, &TerritoryTabButton)>
+) {
+    for (territory_entity, territory, display_library) in & territory_query {
+
+        // This system will only process a Territory that is being represented by sickle.
+        if !matches!(display_library, DisplayLibrary::BevySickle) {
+            continue;
+        }
+
+        // The tab bar shares the panel node with the content node.
+        let Some(tab_bar_entity) = territory.content_node()
+            .and_then(|content_node_entity| parent_query.get(content_node_entity).ok())
+            .and_then(|panel_node| children_query.get(panel_node.get()).ok())
+            .and_then(|panel_children| panel_children.iter().copied().find(|child| tab_bar_query.contains(*child))) else {
+            continue;
+        };
+
+        let shown_buttons: Vec<(Entity, Entity)> = children_query.get(tab_bar_entity)
+            .map(|tab_bar_children| tab_button_query.iter_many(tab_bar_children)
+                .map(|(button_entity, tab_button)| (button_entity, tab_button.tab))
+                .collect())
+            .unwrap_or_default();
+
+        let tabs_unchanged = shown_buttons.iter().map(|(_, tab_entity)| *tab_entity).eq(territory.tabs().iter().copied());
+        let any_tab_changed = tab_query.iter_many(territory.tabs()).any(|tab| tab.is_changed());
+        if tabs_unchanged && !any_tab_changed {
+            continue;
+        }
+
+        for (button_entity, _) in shown_buttons {
+            commands.entity(button_entity).despawn_recursive();
         }
 
+        let mut new_buttons = Vec::new();
+        for tab_entity in territory.tabs() {
+            let Ok(tab) = tab_query.get(*tab_entity) else {
+                warn!("Territory {:?} lists {:?} as a tab, but it has no Tab component. No button shown.", territory_entity, tab_entity);
+                continue;
+            };
+            let label_color = if tab.active { Color::srgb_u8(93, 235, 215) } else { Color::srgb_u8(140, 170, 185) };
+            let new_button = commands.spawn((
+                    territory.tab_button_template(&tab),
+                    TerritoryTabButton { territory: territory_entity, tab: *tab_entity }
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_sections(tab_text_config.sections(
+                        &format!("{} {}", tab.icon, tab.name), 
+                        TextStyle { font_size: 14.0, color: label_color, ..default() }
+                    )));
+                })
+                .id();
+            new_buttons.push(new_button);
+        }
+        commands.entity(tab_bar_entity).insert_children(0, &new_buttons);
     }
-}
\ No newline at end of file
+}
+
+/// Sends a [`SelectTab`] when a [`TerritoryTabButton`] is pressed, making its [`Tab`] the only active one.
+pub fn tab_button_interaction_sickle (
+    button_query: Query<(&Interaction, &TerritoryTabButton), Changed<Interaction>>,
+    mut select_tab: EventWriter<SelectTab>
+) {
+    for (interaction, tab_button) in & button_query {
+        if *interaction == Interaction::Pressed {
+            select_tab.send(SelectTab { 
+                territory: tab_button.territory, 
+                selector: TabSelector::Entity(tab_button.tab)
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components_ui::TabType;
+    use crate::systems_territory::{cancel_territory_motion, select_requested_tabs};
+
+    #[test]
+    fn tab_buttons_follow_tabs_and_select_on_click() {
+        let mut app = App::new();
+        app
+            .add_event::<SelectTab>()
+            .init_resource::<TabTextConfig>()
+            .add_systems(Update, (
+                tab_button_interaction_sickle,
+                select_requested_tabs,
+                sync_territory_tab_buttons_sickle
+            ).chain());
+
+        // Panel holding the tab bar, with the add tab button, above the content node.
+        let add_tab_button = app.world_mut().spawn(Node::default()).id();
+        let tab_bar = app.world_mut().spawn(TerritoryTabBarNode).id();
+        let content_node = app.world_mut().spawn(TerritoryContentNode).id();
+        let panel_node = app.world_mut().spawn(Node::default()).id();
+        app.world_mut().entity_mut(tab_bar).add_child(add_tab_button);
+        app.world_mut().entity_mut(panel_node).push_children(&[tab_bar, content_node]);
+
+        let first_tab = app.world_mut().spawn(Tab { active: true, ..Tab::build_from_type(TabType::ECS) }).id();
+        let second_tab = app.world_mut().spawn(Tab::build_from_type(TabType::Glossary)).id();
+        let mut territory = Territory::empty();
+        territory.content_node = Some(content_node);
+        territory.tabs = vec![first_tab, second_tab];
+        app.world_mut().spawn((territory, DisplayLibrary::BevySickle));
+        app.update();
+
+        let tab_buttons = |app: &App| -> Vec<(Entity, Entity)> {
+            let tab_bar_children: Vec<Entity> = app.world().get::<Children>(tab_bar).unwrap().to_vec();
+            tab_bar_children.iter()
+                .filter_map(|child| app.world().get::<TerritoryTabButton>(*child).map(|tab_button| (*child, tab_button.tab)))
+                .collect()
+        };
+        let buttons = tab_buttons(&app);
+        assert_eq!(buttons.iter().map(|(_, tab)| *tab).collect::<Vec<_>>(), vec![first_tab, second_tab], "Tab buttons don't match the tab list.");
+        assert_eq!(app.world().get::<Children>(tab_bar).unwrap().last(), Some(&add_tab_button), "Add tab button should stay at the end.");
+
+        // Nothing changed, so the same buttons stay.
+        app.update();
+        assert_eq!(tab_buttons(&app), buttons, "Tab buttons were rebuilt without any change.");
+
+        app.world_mut().entity_mut(buttons[1].0).insert(Interaction::Pressed);
+        app.update();
+        assert!(!app.world().get::<Tab>(first_tab).unwrap().active, "Clicked away tab is still active.");
+        assert!(app.world().get::<Tab>(second_tab).unwrap().active, "Clicked tab did not become active.");
+
+        let rebuilt_buttons = tab_buttons(&app);
+        assert_eq!(rebuilt_buttons.len(), 2);
+        assert_eq!(
+            app.world().get::<BackgroundColor>(rebuilt_buttons[1].0).unwrap().0, 
+            Color::srgb_u8(70, 110, 130), 
+            "Newly active tab button is not highlighted."
+        );
+    }
+
+    #[test]
+    fn escape_cancels_drag_and_reverts_pushed_neighbors() {
+        let mut app = App::new();
+        app
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Time>()
+            .insert_resource(MoveRequestThrottle { interval: Duration::ZERO })
+            .add_systems(Update, (
+                track_motion_origin_sickle,
+                territory_drag_move_request_sickle,
+                cancel_territory_motion
+                    .run_if(bevy::input::common_conditions::input_just_pressed(KeyCode::Escape))
+            ).chain());
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let drag_node = app.world_mut().spawn((Draggable::default(), TerritoryDragNode)).id();
+        let dragged_rect = Rect::new(100.0, 100.0, 300.0, 300.0);
+        let neighbor_rect = Rect::new(300.0, 100.0, 500.0, 300.0);
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(dragged_rect, 1000.0, 1000.0);
+        territory.drag_node = Some(drag_node);
+        let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevySickle)).id();
+        let mut neighbor = Territory::empty();
+        neighbor.expanse = RectKit::from_screenspace(neighbor_rect, 1000.0, 1000.0);
+        let neighbor_entity = app.world_mut().spawn((neighbor, DisplayLibrary::BevySickle)).id();
+        app.world_mut().entity_mut(window_entity).push_children(&[territory_entity, neighbor_entity]);
+
+        let drag = |app: &mut App, state: DragState, diff: Vec2| {
+            *app.world_mut().get_mut::<Draggable>(drag_node).unwrap() = Draggable { state, diff: Some(diff), ..default() };
+            app.update();
+        };
+        let screenspace = |app: &App, entity: Entity| app.world().get::<Territory>(entity).unwrap().expanse().screenspace();
+
+        drag(&mut app, DragState::DragStart, Vec2::ZERO);
+        assert!(app.world().get::<MotionOrigin>(territory_entity).is_some(), "Drag start did not record a MotionOrigin.");
+
+        // Half-applied drag, with the neighbor pushed along.
+        drag(&mut app, DragState::Dragging, Vec2::new(40.0, 0.0));
+        assert!(app.world().get::<MoveRequest>(territory_entity).is_some(), "Drag did not create a MoveRequest.");
+        app.world_mut().entity_mut(territory_entity).remove::<MoveRequest>();
+        app.world_mut().get_mut::<Territory>(territory_entity).unwrap().expanse = RectKit::from_screenspace(Rect::new(140.0, 100.0, 340.0, 300.0), 1000.0, 1000.0);
+        app.world_mut().get_mut::<Territory>(neighbor_entity).unwrap().expanse = RectKit::from_screenspace(Rect::new(340.0, 100.0, 540.0, 300.0), 1000.0, 1000.0);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Escape);
+        drag(&mut app, DragState::Dragging, Vec2::new(40.0, 0.0));
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+        assert!(app.world().get::<MoveRequest>(territory_entity).is_none(), "Escape left the MoveRequest in place.");
+        assert_eq!(screenspace(&app, territory_entity), dragged_rect, "Escape did not revert the dragged Territory.");
+        assert_eq!(screenspace(&app, neighbor_entity), neighbor_rect, "Escape did not revert the pushed neighbor.");
+
+        // The rest of the drag is ignored, until it ends.
+        drag(&mut app, DragState::Dragging, Vec2::new(40.0, 0.0));
+        assert!(app.world().get::<MoveRequest>(territory_entity).is_none(), "Canceled drag kept moving the Territory.");
+        drag(&mut app, DragState::DragEnd, Vec2::ZERO);
+        assert!(app.world().get::<MotionOrigin>(territory_entity).is_none(), "MotionOrigin outlived the drag.");
+    }
+
+    #[test]
+    fn hovering_a_resize_button_shows_its_resize_arrow() {
+        let mut app = App::new();
+        app.add_systems(Update, resize_cursor_icon_sickle);
+
+        let window_entity = app.world_mut().spawn((Window::default(), TerritoryTabs)).id();
+        let east_button = app.world_mut().spawn((
+            Interaction::None, 
+            ResizeDirection::East { eastward_magnitude: ResizeMagnitude::None }, 
+            TerritoryResizeButtonNode, 
+            Draggable::default()
+        )).id();
+        let resize_node = app.world_mut().spawn((NodeBundle::default(), TerritoryResizeGridNode)).id();
+        app.world_mut().entity_mut(resize_node).add_child(east_button);
+        let mut territory = Territory::empty();
+        territory.resize_node = Some(resize_node);
+        let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevySickle)).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+
+        let hover = |app: &mut App, interaction: Interaction| -> CursorIcon {
+            *app.world_mut().get_mut::<Interaction>(east_button).unwrap() = interaction;
+            app.update();
+            app.world().get::<Window>(window_entity).unwrap().cursor.icon
+        };
+        assert_eq!(hover(&mut app, Interaction::Hovered), CursorIcon::EwResize, "Hovered East button did not show a horizontal resize arrow.");
+        assert_eq!(hover(&mut app, Interaction::Pressed), CursorIcon::EwResize, "Resize arrow did not stay while pressed.");
+        assert_eq!(hover(&mut app, Interaction::None), CursorIcon::Default, "Cursor was not restored after leaving the button.");
+
+        // Somebody else's cursor is left alone.
+        app.world_mut().get_mut::<Window>(window_entity).unwrap().cursor.icon = CursorIcon::Pointer;
+        assert_eq!(hover(&mut app, Interaction::None), CursorIcon::Pointer, "Unrelated cursor was reset.");
+    }
+
+    #[test]
+    fn throttled_drag_integrates_pending_differences() {
+        let mut app = App::new();
+        app
+            .init_resource::<Time>()
+            .insert_resource(MoveRequestThrottle { interval: Duration::from_millis(16) })
+            .add_systems(Update, territory_drag_move_request_sickle);
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let drag_node = app.world_mut().spawn((Draggable::default(), TerritoryDragNode)).id();
+        let start_rect = Rect::new(100.0, 100.0, 300.0, 300.0);
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(start_rect, 1000.0, 1000.0);
+        territory.drag_node = Some(drag_node);
+        let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevySickle)).id();
+        app.world_mut().entity_mut(window_entity).push_children(&[territory_entity]);
+
+        // Each frame takes 5ms. A drag difference of `None` leaves the Draggable untouched.
+        let frame = |app: &mut App, drag: Option<(DragState, Vec2)>| -> Option<Vec2> {
+            if let Some((state, diff)) = drag {
+                *app.world_mut().get_mut::<Draggable>(drag_node).unwrap() = Draggable { state, diff: Some(diff), ..default() };
+            }
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(5));
+            app.update();
+            app.world_mut().entity_mut(territory_entity).take::<MoveRequest>()
+                .map(|move_request| move_request.proposed_expanse.screenspace().min - start_rect.min)
+        };
+
+        assert_eq!(frame(&mut app, Some((DragState::Dragging, Vec2::new(10.0, 0.0)))), Some(Vec2::new(10.0, 0.0)), "First difference should move right away.");
+        assert_eq!(frame(&mut app, Some((DragState::Dragging, Vec2::new(10.0, 0.0)))), None, "Throttled difference was sent.");
+        assert_eq!(frame(&mut app, Some((DragState::Dragging, Vec2::new(5.0, 2.0)))), None, "Throttled difference was sent.");
+        assert_eq!(frame(&mut app, None), None, "Throttled difference was sent.");
+        assert_eq!(frame(&mut app, None), Some(Vec2::new(15.0, 2.0)), "Pending differences were not sent together.");
+
+        // Leftovers are sent as soon as the drag ends.
+        assert_eq!(frame(&mut app, Some((DragState::Dragging, Vec2::new(7.0, 0.0)))), None, "Throttled difference was sent.");
+        assert_eq!(frame(&mut app, Some((DragState::DragEnd, Vec2::ZERO))), Some(Vec2::new(7.0, 0.0)), "Drag end did not flush the pending difference.");
+        assert_eq!(frame(&mut app, None), None, "Ended drag kept sending MoveRequests.");
+    }
+}
@@ -1,34 +1,83 @@
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
+use crate::components_territory::{ResizeDirection, ResizeMagnitude};
+
+/// Keyboard actions the Territory Tabs UI listens for.  
+/// \
+/// Bound by [`DevControls::default_input_map`] unless the plugin is given its own [`InputMap`].
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum DevControls {
-    TestChord,
-    TestSpawnNewWindow,
-    TestRemoveAllTerritories
+    /// Held to move Tabs between Territories. Defaults to `Ctrl + Shift`.
+    MoveTabs,
+    /// Spawns a new OS window. Defaults to `N`.
+    SpawnNewWindow,
+    /// Removes every Territory. Defaults to `Shift + X`.
+    RemoveAllTerritories,
+    /// Held during a corner resize to keep the Territory's aspect ratio from before the resize. Defaults to `Shift`.
+    KeepAspectRatio,
+    /// Held while moving a Territory to place it without snapping to neighbors or the Window edge. Defaults to `Alt`.
+    IgnoreSnap,
+    /// Moves focus to the Territory above the focused one. Defaults to `Ctrl + Up`.
+    FocusNorth,
+    /// Moves focus to the Territory right of the focused one. Defaults to `Ctrl + Right`.
+    FocusEast,
+    /// Moves focus to the Territory below the focused one. Defaults to `Ctrl + Down`.
+    FocusSouth,
+    /// Moves focus to the Territory left of the focused one. Defaults to `Ctrl + Left`.
+    FocusWest,
+    /// Spawns a default sized Territory at the cursor, skipping the placeholders. Defaults to `T`.
+    SpawnTerritoryAtCursor,
+    /// Held while scrolling over a Territory to grow or shrink it from its center. Defaults to `Ctrl`.
+    ScrollResize,
+    /// Docks the focused Territory to its nearest Window edge, or undocks it if it's already docked. Defaults to `Ctrl + D`.
+    ToggleDock
 }
 impl DevControls {
+    /// The focus moving actions, with the side of the focused Territory each one looks past.
+    pub const FOCUS_DIRECTIONS: [(Self, ResizeDirection); 4] = [
+        (Self::FocusNorth, ResizeDirection::North { northward_magnitude: ResizeMagnitude::None }),
+        (Self::FocusEast, ResizeDirection::East { eastward_magnitude: ResizeMagnitude::None }),
+        (Self::FocusSouth, ResizeDirection::South { southward_magnitude: ResizeMagnitude::None }),
+        (Self::FocusWest, ResizeDirection::West { westward_magnitude: ResizeMagnitude::None })
+    ];
+
+    /// Bindings used when the plugin isn't given its own [`InputMap`].
     pub fn default_input_map() -> InputMap<DevControls> {
         use KeyCode::*;
         InputMap::new([
-            (Self::TestChord, UserInput::Chord(
+            (Self::MoveTabs, UserInput::Chord(
                 vec!(InputKind::PhysicalKey(ControlLeft), InputKind::PhysicalKey(ShiftLeft) ))),
-            (Self::TestSpawnNewWindow, UserInput::Single(InputKind::PhysicalKey(KeyN))),
-            (Self::TestRemoveAllTerritories, UserInput::Chord(
-                vec!(InputKind::PhysicalKey(ShiftLeft), InputKind::PhysicalKey(KeyX) )))
+            (Self::SpawnNewWindow, UserInput::Single(InputKind::PhysicalKey(KeyN))),
+            (Self::RemoveAllTerritories, UserInput::Chord(
+                vec!(InputKind::PhysicalKey(ShiftLeft), InputKind::PhysicalKey(KeyX) ))),
+            (Self::KeepAspectRatio, UserInput::Single(InputKind::PhysicalKey(ShiftLeft))),
+            (Self::IgnoreSnap, UserInput::Single(InputKind::PhysicalKey(AltLeft))),
+            (Self::FocusNorth, UserInput::Chord(
+                vec!(InputKind::PhysicalKey(ControlLeft), InputKind::PhysicalKey(ArrowUp) ))),
+            (Self::FocusEast, UserInput::Chord(
+                vec!(InputKind::PhysicalKey(ControlLeft), InputKind::PhysicalKey(ArrowRight) ))),
+            (Self::FocusSouth, UserInput::Chord(
+                vec!(InputKind::PhysicalKey(ControlLeft), InputKind::PhysicalKey(ArrowDown) ))),
+            (Self::FocusWest, UserInput::Chord(
+                vec!(InputKind::PhysicalKey(ControlLeft), InputKind::PhysicalKey(ArrowLeft) ))),
+            (Self::SpawnTerritoryAtCursor, UserInput::Single(InputKind::PhysicalKey(KeyT))),
+            (Self::ScrollResize, UserInput::Single(InputKind::PhysicalKey(ControlLeft))),
+            (Self::ToggleDock, UserInput::Chord(
+                vec!(InputKind::PhysicalKey(ControlLeft), InputKind::PhysicalKey(KeyD) )))
         ])
     }
 }
 
 // For now, broadcast the dev chord actions as events.
 #[derive(Event)]
-pub struct TestChordJustPressed(pub Entity);
+pub struct MoveTabsJustPressed(pub Entity);
 
 #[derive(Event)]
-pub struct TestChordPressed(pub Entity);
+pub struct MoveTabsPressed(pub Entity);
 
 #[derive(Event)]
-pub struct TestChordJustReleased(pub Entity);
+pub struct MoveTabsJustReleased(pub Entity);
 
 #[derive(Event)]
 pub struct SpawnWindowKeyJustPressed;
@@ -37,32 +86,32 @@ pub struct SpawnWindowKeyJustPressed;
 pub struct RemoveTerritoriesKeyPressed;
 
 // Send event when key pressed.
-pub fn test_delete_all_territories_just_pressed (
+pub fn send_remove_territories_key_pressed (
     dev_controls: Res<ActionState<DevControls>>,
     mut remove_territories_key_pressed: EventWriter<RemoveTerritoriesKeyPressed>
 ) {
-    if dev_controls.pressed(&DevControls::TestRemoveAllTerritories) {
+    if dev_controls.pressed(&DevControls::RemoveAllTerritories) {
         remove_territories_key_pressed.send(RemoveTerritoriesKeyPressed);
     }
 }
 
 // Send window spawn event for testing.
-pub fn test_spawn_window (
+pub fn send_spawn_window_key_just_pressed (
     dev_controls: Res<ActionState<DevControls>>,
     mut spawn_window_key_just_pressed: EventWriter<SpawnWindowKeyJustPressed>
 ) {
-    if dev_controls.just_pressed(&DevControls::TestSpawnNewWindow) {
+    if dev_controls.just_pressed(&DevControls::SpawnNewWindow) {
         spawn_window_key_just_pressed.send(SpawnWindowKeyJustPressed);
     }
 }
 
 // TODO: Find way to gatekeep this with a run condition.
-pub fn test_chord_pressed(
+pub fn send_move_tabs_chord_events(
     dev_controls: Res<ActionState<DevControls>>,
     window_query: Query<(Entity, &Window)>,
-    mut test_chord_just_pressed: EventWriter<TestChordJustPressed>,
-    mut test_chord_pressed: EventWriter<TestChordPressed>,
-    mut test_chord_just_released: EventWriter<TestChordJustReleased>
+    mut move_tabs_just_pressed: EventWriter<MoveTabsJustPressed>,
+    mut move_tabs_pressed: EventWriter<MoveTabsPressed>,
+    mut move_tabs_just_released: EventWriter<MoveTabsJustReleased>
 ) {
     // TODO: Get Tab's parent Territory's parent Window entity id.
     // Until then, we get Window entity id of wherever we set off this stand-in chord.
@@ -71,14 +120,14 @@ pub fn test_chord_pressed(
     for (entity, window) in &window_query {
 
         if window.cursor_position().is_none() {continue}
-        if dev_controls.just_pressed(&DevControls::TestChord) {
-            test_chord_just_pressed.send(TestChordJustPressed(entity));
+        if dev_controls.just_pressed(&DevControls::MoveTabs) {
+            move_tabs_just_pressed.send(MoveTabsJustPressed(entity));
         }
-        if dev_controls.pressed(&DevControls::TestChord) {
-            test_chord_pressed.send(TestChordPressed(entity));
+        if dev_controls.pressed(&DevControls::MoveTabs) {
+            move_tabs_pressed.send(MoveTabsPressed(entity));
         }
-        if dev_controls.just_released(&DevControls::TestChord) {
-            test_chord_just_released.send(TestChordJustReleased(entity));
+        if dev_controls.just_released(&DevControls::MoveTabs) {
+            move_tabs_just_released.send(MoveTabsJustReleased(entity));
         }
     }
 }
@@ -12,10 +12,13 @@ pub mod display_territory;
 pub mod display_territory_sickle;
 
 pub mod ui {
+    use bevy::input::mouse::MouseWheel;
     use bevy::prelude::*;
     use leafwing_input_manager::prelude::*;
 
+    use crate::components_territory::{DisplayLibrary, MotionOrigin};
     use crate::input_manager::*;
+    use crate::resources_ui::PendingTabMove;
     use crate::systems_common::*;
     use crate::systems_egui::*;
     use crate::systems_ui::*;
@@ -47,37 +50,108 @@ pub mod ui {
     #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
     pub struct UpdateUIDebug;
 
-    // Plugin for the Territory Tabs UI, handling all initialization and updating.
-    pub struct TerritoryTabsPlugin;
+    /// Plugin for the Territory Tabs UI, handling all initialization and updating.  
+    /// \
+    /// The [`TerritoryTabsState`] starts out [`TerritoryTabsState::Empty`] by default. 
+    /// Use [`TerritoryTabsPlugin::with_initial_state`] to start somewhere else.  
+    /// \
+    /// The [`DevControls`] are always bound, since focus, snapping, and resizing keys are part of the UI. 
+    /// Only the dev systems behind them, for spawning `Window`s, removing every Territory, and the tab moving chord, 
+    /// are off by default, since they'd get in the way of a real app. 
+    /// Turn those on with [`TerritoryTabsPlugin::with_dev_controls`], and rebind everything with [`TerritoryTabsPlugin::with_input_map`].  
+    /// \
+    /// Debug gizmos are drawn in debug builds only, unless set otherwise with [`TerritoryTabsPlugin::with_debug_gizmos`]. 
+    /// The egui debug overlay is shown by default, and can be left out with [`TerritoryTabsPlugin::with_debug_overlay`].
+    pub struct TerritoryTabsPlugin {
+        /// [`TerritoryTabsState`] the app starts in.
+        pub initial_state: TerritoryTabsState,
+        /// Whether the dev systems behind the [`DevControls`] are added.
+        pub dev_controls: bool,
+        /// Bindings for the [`DevControls`]. [`DevControls::default_input_map`] is used if `None`.
+        pub input_map: Option<InputMap<DevControls>>,
+        /// Whether each `Window`'s Territories are drawn with debug gizmos.
+        pub debug_gizmos: bool,
+        /// Whether the egui "Debug Window" and placeholder overlays are shown.
+        pub show_debug_overlay: bool,
+        /// [`DisplayLibrary`] new `Window`s start with. A `Window`, Territory, or Tab with its own still overrides it.
+        pub default_display_library: DisplayLibrary
+    }
+    impl Default for TerritoryTabsPlugin {
+        fn default() -> Self {
+            TerritoryTabsPlugin {
+                initial_state: TerritoryTabsState::Empty,
+                dev_controls: false,
+                input_map: None,
+                debug_gizmos: cfg!(debug_assertions),
+                show_debug_overlay: true,
+                default_display_library: DisplayLibrary::default()
+            }
+        }
+    }
+    impl TerritoryTabsPlugin {
+        /// Sets the [`TerritoryTabsState`] the app starts in.
+        pub fn with_initial_state(mut self, initial_state: TerritoryTabsState) -> Self {
+            self.initial_state = initial_state;
+            self
+        }
+
+        /// Sets whether the dev systems behind the [`DevControls`] are added.
+        pub fn with_dev_controls(mut self, dev_controls: bool) -> Self {
+            self.dev_controls = dev_controls;
+            self
+        }
+
+        /// Sets whether each `Window`'s Territories are drawn with debug gizmos.
+        pub fn with_debug_gizmos(mut self, debug_gizmos: bool) -> Self {
+            self.debug_gizmos = debug_gizmos;
+            self
+        }
+
+        /// Sets whether the egui "Debug Window" and placeholder overlays are shown. 
+        /// Everything else works the same without them.
+        pub fn with_debug_overlay(mut self, show_debug_overlay: bool) -> Self {
+            self.show_debug_overlay = show_debug_overlay;
+            self
+        }
+
+        /// Sets the [`DisplayLibrary`] new `Window`s start with, instead of [`DisplayLibrary::BevySickle`].
+        pub fn with_default_display_library(mut self, default_display_library: DisplayLibrary) -> Self {
+            self.default_display_library = default_display_library;
+            self
+        }
+
+        /// Binds the [`DevControls`] with the given [`InputMap`] instead of [`DevControls::default_input_map`]. 
+        /// The dev systems still need [`TerritoryTabsPlugin::with_dev_controls`].
+        pub fn with_input_map(mut self, input_map: InputMap<DevControls>) -> Self {
+            self.input_map = Some(input_map);
+            self
+        }
+    }
     impl Plugin for TerritoryTabsPlugin {
         fn build(&self, app: &mut App) {
 
             app
                 // Stuff
-                .add_plugins(TerritoryPlugin)
-                .insert_state(TerritoryTabsState::Natural)
-
-                .add_plugins(InputManagerPlugin::<DevControls>::default())
-                .init_resource::<ActionState<DevControls>>()
-                .insert_resource(DevControls::default_input_map())
+                .add_plugins(TerritoryPlugin { 
+                    initial_state: self.initial_state.clone(), 
+                    debug_gizmos: self.debug_gizmos, 
+                    default_display_library: self.default_display_library 
+                })
 
-                .add_event::<TestChordJustPressed>()
-                .add_event::<TestChordPressed>()
-                .add_event::<TestChordJustReleased>()
+                .add_event::<MoveTabsJustPressed>()
+                .add_event::<MoveTabsPressed>()
+                .add_event::<MoveTabsJustReleased>()
                 .add_event::<SpawnWindowKeyJustPressed>()
                 .add_event::<RemoveTerritoriesKeyPressed>()
 
-                // Test system
-                .add_systems(Update, 
-                    test_delete_all_territories_just_pressed
-                )
-
                 // Startup
                 .add_systems(Startup, initialize_ui_resources)
 
                 // State Transitions
-                .add_systems(OnEnter(TerritoryTabsState::MovingTabs),
-                    setup_tab_move_placeholders)
+                .add_systems(OnEnter(TerritoryTabsState::MovingTabs), (
+                    setup_tab_move_placeholders,
+                    spawn_tab_move_ghost
+                ))
                 .add_systems(OnExit(TerritoryTabsState::MovingTabs), (
                     activate_placeholders
                         .before(despawn_all_entities_with::<CleanupOnMovingTabExit>),
@@ -88,15 +162,16 @@ pub mod ui {
                 .add_systems(Update, (
 
                     (
-                        test_spawn_window,
-                        test_chord_pressed,
+                        update_territory_hit_grids,
                         get_mouse_location
-                    ).in_set(UpdateUIInput),
-                    // (
-                    //    
-                    // ).in_set(UpdateUIDisplay),
+                    ).chain().in_set(UpdateUIInput),
+                    display_territory_egui
+                        .in_set(UpdateUIDisplay),
                     (
-                        spawn_new_os_window
+                        spawn_new_os_window,
+                        move_tab_into_spawned_territory
+                            .run_if(resource_exists::<PendingTabMove>)
+                            .after(TerritoryDisplay)
                     ).in_set(UpdateUIWindowManagement),
                     (
                         (
@@ -109,18 +184,25 @@ pub mod ui {
                             check_placeholder_types_mouse_moving
                                 .run_if(on_event::<CursorMoved>())
                                 .before(calculate_placeholder_data),
-                            calculate_placeholder_data
+                            track_combine_territories_placeholder
+                                .run_if(any_with_component::<MotionOrigin>.or_else(any_component_removed::<MotionOrigin>()))
+                                .before(update_combine_territories_placeholder),
+                            update_combine_territories_placeholder
                                 .run_if(on_event::<CursorMoved>())
+                                .after(check_placeholder_types_mouse_moving),
+                            calculate_placeholder_data
+                                .run_if(on_event::<CursorMoved>()),
+                            follow_tab_move_ghost
+                                .run_if(in_state(TerritoryTabsState::MovingTabs))
                         ).in_set(UpdateUIPlaceholderManagement),
                     ).in_set(UpdateUIStateBehavior),
-                    (
-                        display_debug_info_with_egui,
-                        display_placeholders_egui
-                    ).in_set(UpdateUIDebug),
                     (
                         territory_tabs_main_state_exit
                             .before(territory_tabs_main_state_enter),
-                        territory_tabs_main_state_enter
+                        territory_tabs_main_state_enter,
+                        release_click_spawn_placeholder
+                            .before(start_click_spawn_placeholder),
+                        start_click_spawn_placeholder
                     ).in_set(UpdateUIStateChanges)
                 ))
 
@@ -145,6 +227,166 @@ pub mod ui {
                         UpdateUIStateChanges
                     )
                 ));
+
+            if self.show_debug_overlay {
+                app.add_systems(Update, (
+                    display_debug_info_with_egui,
+                    display_placeholders_egui
+                ).in_set(UpdateUIDebug));
+            }
+
+            app
+                .add_plugins(InputManagerPlugin::<DevControls>::default())
+                .init_resource::<ActionState<DevControls>>()
+                .insert_resource(self.input_map.clone().unwrap_or_else(DevControls::default_input_map))
+                // Plain Ctrl or Shift bindings shouldn't also fire under the chords built on them.
+                .insert_resource(ClashStrategy::PrioritizeLongest)
+                .add_systems(Update, (
+                    spawn_territory_at_cursor,
+                    scroll_resize_hovered_territory
+                        .run_if(on_event::<MouseWheel>())
+                ).in_set(UpdateUIStateChanges));
+
+            if self.dev_controls {
+                app
+                    // Test systems
+                    .add_systems(Update, 
+                        send_remove_territories_key_pressed
+                    )
+                    .add_systems(Update, (
+                        send_spawn_window_key_just_pressed,
+                        send_move_tabs_chord_events
+                    ).in_set(UpdateUIInput));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use bevy::state::app::StatesPlugin;
+
+        use super::*;
+
+        #[test]
+        fn plugin_starts_in_configured_initial_states() {
+            let initial_state_of = |plugin: TerritoryTabsPlugin| {
+                let mut app = App::new();
+                app
+                    .add_plugins(StatesPlugin)
+                    .add_plugins(plugin);
+                app.world().resource::<State<TerritoryTabsState>>().get().clone()
+            };
+
+            assert_eq!(
+                initial_state_of(TerritoryTabsPlugin::default()), 
+                TerritoryTabsState::Empty,
+                "TerritoryTabsState did not start Empty by default."
+            );
+            assert_eq!(
+                initial_state_of(TerritoryTabsPlugin::default().with_initial_state(TerritoryTabsState::LoadingLayouts)), 
+                TerritoryTabsState::LoadingLayouts,
+                "TerritoryTabsState did not start in the configured state."
+            );
+        }
+
+        /// Names of every system added to the `Update` schedule.
+        fn update_system_names(app: &mut App) -> Vec<String> {
+            app.get_schedule(Update).unwrap().graph().systems()
+                .map(|(_, system, _)| system.name().to_string())
+                .collect()
+        }
+
+        #[test]
+        fn dev_controls_off_leaves_only_test_systems_out() {
+            let mut app = App::new();
+            app
+                .add_plugins(StatesPlugin)
+                .add_plugins(TerritoryTabsPlugin::default());
+
+            // Focus, snapping, and resizing keys still work, but nothing is listening for N to spawn a window.
+            assert!(app.world().get_resource::<InputMap<DevControls>>().is_some(), "DevControls were not bound with dev controls off.");
+            assert_eq!(app.world().get_resource::<ClashStrategy>(), Some(&ClashStrategy::PrioritizeLongest), "Overlapping bindings would all fire.");
+            let system_names = update_system_names(&mut app);
+            assert!(
+                !system_names.iter().any(|name| name.contains("send_spawn_window_key_just_pressed")), 
+                "Window spawning dev system was added with dev controls off."
+            );
+            assert!(
+                system_names.iter().any(|name| name.contains("scroll_resize_hovered_territory")), 
+                "Ctrl + scroll resizing was left out with dev controls off."
+            );
+
+            let mut dev_app = App::new();
+            dev_app
+                .add_plugins(StatesPlugin)
+                .add_plugins(TerritoryTabsPlugin::default().with_dev_controls(true));
+            assert!(dev_app.world().get_resource::<InputMap<DevControls>>().is_some(), "DevControls were not bound.");
+            assert!(
+                update_system_names(&mut dev_app).iter().any(|name| name.contains("send_spawn_window_key_just_pressed")), 
+                "Window spawning dev system was not added with dev controls on."
+            );
+        }
+
+        #[test]
+        fn debug_gizmos_are_drawn_once_per_slot_only_when_on() {
+            let count_gizmo_systems = |debug_gizmos: bool| {
+                let mut app = App::new();
+                app
+                    .add_plugins(StatesPlugin)
+                    .add_plugins(TerritoryTabsPlugin::default().with_debug_gizmos(debug_gizmos));
+                update_system_names(&mut app).iter()
+                    .filter(|name| name.contains("display_debug_gizmos"))
+                    .count()
+            };
+            assert_eq!(count_gizmo_systems(true), 4, "Debug gizmos were not drawn exactly once for each render layer slot.");
+            assert_eq!(count_gizmo_systems(false), 0, "Debug gizmos were drawn with them turned off.");
+        }
+
+        #[test]
+        fn debug_overlay_can_be_left_out() {
+            let mut app = App::new();
+            app
+                .add_plugins(StatesPlugin)
+                .add_plugins(TerritoryTabsPlugin::default().with_debug_overlay(false));
+            let system_names = update_system_names(&mut app);
+            assert!(
+                !system_names.iter().any(|name| name.contains("display_debug_info_with_egui") || name.contains("display_placeholders_egui")), 
+                "Debug overlay was added with it turned off."
+            );
+            assert!(system_names.iter().any(|name| name.contains("get_mouse_location")), "Functional systems went missing with the overlay.");
+            assert!(system_names.iter().any(|name| name.contains("display_territory_egui")), "egui Territories lost their display with the overlay.");
+
+            let mut overlay_app = App::new();
+            overlay_app
+                .add_plugins(StatesPlugin)
+                .add_plugins(TerritoryTabsPlugin::default());
+            assert!(
+                update_system_names(&mut overlay_app).iter().any(|name| name.contains("display_debug_info_with_egui")), 
+                "Debug overlay was not shown by default."
+            );
+        }
+
+        #[test]
+        fn input_map_rebinds_spawn_window() {
+            let mut app = App::new();
+            app
+                .add_plugins(StatesPlugin)
+                .add_plugins(
+                    TerritoryTabsPlugin::default()
+                        .with_input_map(InputMap::new([(DevControls::SpawnNewWindow, KeyCode::F2)]))
+                );
+
+            let input_map = app.world().resource::<InputMap<DevControls>>();
+            assert!(
+                input_map.get(&DevControls::SpawnNewWindow)
+                    .is_some_and(|inputs| inputs.contains(&UserInput::Single(InputKind::PhysicalKey(KeyCode::F2)))),
+                "Spawn window was not rebound to F2."
+            );
+            assert!(input_map.get(&DevControls::MoveTabs).is_none(), "Default bindings leaked into the custom InputMap.");
+            assert!(
+                !update_system_names(&mut app).iter().any(|name| name.contains("send_spawn_window_key_just_pressed")), 
+                "Providing an InputMap turned the dev controls on."
+            );
         }
     }
-}
\ No newline at end of file
+}
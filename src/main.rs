@@ -14,7 +14,7 @@ fn main() {
         }))
         .add_plugins(EguiPlugin)
         .add_plugins(SickleUiPlugin)
-        .add_plugins(TerritoryTabsPlugin)
+        .add_plugins(TerritoryTabsPlugin::default().with_dev_controls(true))
         .run();
 
 }
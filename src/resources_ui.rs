@@ -1,4 +1,9 @@
+use std::ops::RangeInclusive;
+
 use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::components_ui::TabType;
 
 /// Global resource for getting the mouse position in Bevy's 2D camera space.\
 /// In **screenspace** (upper left is origin and y goes down)\
@@ -14,6 +19,7 @@ pub struct WorldMousePosition {
     pub interaction_pos: Vec2,
     pub window: Option<Entity>,
     pub territory: Option<Entity>,
+    pub content_territory: Option<Entity>,
     pub tab: Option<Entity>
 }
 impl Default for WorldMousePosition {
@@ -24,11 +30,26 @@ impl Default for WorldMousePosition {
             interaction_pos: Vec2::new(0.0, 0.0),
             window: None,
             territory: None,
+            content_territory: None,
             tab: None
         }
     }
 }
 
+/// A [`Tab`](crate::components_ui::Tab) dropped outside of any `Territory`, waiting for the `Territory` spawned there to move into.  
+/// \
+/// The new `Territory` is found by its **screenspace** [`Rect`] in the `Window`, since it has no [`Entity`] until it spawns. 
+/// A later drop replaces this, so a spawn that never happens doesn't leave anything stuck.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct PendingTabMove {
+    /// `Window` the `Territory` was requested in.
+    pub window_entity: Entity,
+    /// Requested **screenspace** [`Rect`] of the `Territory`.
+    pub screenspace: Rect,
+    /// [`Tab`](crate::components_ui::Tab) [`Entity`] to move in once it spawns.
+    pub tab: Entity
+}
+
 // Config stuff for Territories
 #[derive(Resource)]
 pub struct TerritorySettings {
@@ -63,4 +84,194 @@ impl Default for TabSettings {
             min_size: Vec2{x: 30.0, y: 15.0}
         }
     }
-}
\ No newline at end of file
+}
+
+
+/// Look of `Territory` base nodes that depends on focus.  
+/// \
+/// Unfocused `Territory`s can be dimmed by lowering `unfocused_alpha`. Both alphas default to `1.0`, so nothing is dimmed unless asked for.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct TerritoryStyle {
+    /// Background alpha of the base node of the `Focused` `Territory`, from `0.0` to `1.0`.
+    pub focused_alpha: f32,
+    /// Background alpha of the base node of every other `Territory`, from `0.0` to `1.0`.
+    pub unfocused_alpha: f32
+}
+impl Default for TerritoryStyle {
+    fn default() -> Self {
+        TerritoryStyle {
+            focused_alpha: 1.0,
+            unfocused_alpha: 1.0
+        }
+    }
+}
+impl TerritoryStyle {
+    /// Gets the background alpha for a `Territory`, clamped to `0.0..=1.0`.
+    pub fn alpha(&self, focused: bool) -> f32 {
+        let alpha = if focused { self.focused_alpha } else { self.unfocused_alpha };
+        alpha.clamp(0.0, 1.0)
+    }
+}
+
+/// A font to fall back on, and the ranges of characters it can be trusted to have glyphs for.
+#[derive(Clone, Debug)]
+pub struct FontFallback {
+    pub font: Handle<Font>,
+    pub coverage: Vec<RangeInclusive<char>>
+}
+impl FontFallback {
+    pub fn new(font: Handle<Font>, coverage: Vec<RangeInclusive<char>>) -> Self {
+        FontFallback { font, coverage }
+    }
+
+    /// Whether this fallback claims a glyph for this character.
+    pub fn covers(&self, character: char) -> bool {
+        self.coverage.iter().any(|range| range.contains(&character))
+    }
+}
+
+/// Fonts used when building Tab and Territory text.\
+/// Bevy renders each [`TextSection`] with a single font, so a glyph missing from that font shows up as tofu.
+/// Characters outside the primary font's `coverage` fall through the `fallbacks` in order,
+/// and only land back on the primary font if no fallback claims them.
+#[derive(Resource, Clone, Debug)]
+pub struct TabTextConfig {
+    pub font: Handle<Font>,
+    pub coverage: Vec<RangeInclusive<char>>,
+    pub fallbacks: Vec<FontFallback>
+}
+impl Default for TabTextConfig {
+    fn default() -> Self {
+        TabTextConfig {
+            font: Handle::default(),
+            // Bevy's built in font is a subset of Fira Mono, which is safe for Latin text.
+            coverage: vec![' '..='\u{024F}'],
+            fallbacks: Vec::new()
+        }
+    }
+}
+impl TabTextConfig {
+    /// Appends a fallback font to the end of the chain.
+    pub fn with_fallback(mut self, font: Handle<Font>, coverage: Vec<RangeInclusive<char>>) -> Self {
+        self.fallbacks.push(FontFallback::new(font, coverage));
+        self
+    }
+
+    /// The font that should render this character.
+    pub fn font_for(&self, character: char) -> &Handle<Font> {
+        if self.coverage.iter().any(|range| range.contains(&character)) {
+            return &self.font;
+        }
+        self.fallbacks
+            .iter()
+            .find(|fallback| fallback.covers(character))
+            .map_or(&self.font, |fallback| &fallback.font)
+    }
+
+    /// Splits text into [`TextSection`]s, one per run of characters sharing a font.
+    /// The style's font is replaced per section, everything else is kept.
+    pub fn sections(&self, text: &str, style: TextStyle) -> Vec<TextSection> {
+        let mut sections: Vec<TextSection> = Vec::new();
+        for character in text.chars() {
+            let font = self.font_for(character);
+            match sections.last_mut() {
+                Some(section) if section.style.font == *font => section.value.push(character),
+                _ => sections.push(TextSection::new(
+                    character.to_string(), 
+                    TextStyle { font: font.clone(), ..style.clone() }
+                ))
+            }
+        }
+        sections
+    }
+}
+
+/// What a [`TabRenderer`] gets to work with when it's called for the active [`Tab`](crate::components_ui::Tab) of a `Territory`.
+#[derive(Clone, Copy, Debug)]
+pub struct TabContentContext {
+    /// `Territory` [`Entity`] the [`Tab`](crate::components_ui::Tab) is active in.
+    pub territory: Entity,
+    /// [`Tab`](crate::components_ui::Tab) [`Entity`] being drawn.
+    pub tab: Entity,
+    /// Type of the [`Tab`](crate::components_ui::Tab) being drawn.
+    pub tab_type: TabType,
+    /// Empty bevy_ui [`Node`] [`Entity`] to spawn the content under. It fills the width of the content area and scrolls with it, 
+    /// and is despawned along with everything under it once another [`Tab`](crate::components_ui::Tab) becomes active.
+    pub node: Entity,
+    /// **Screenspace** content area of the `Territory` when the renderer was called, from `Territory::content_rect_screenspace`.
+    pub content_rect: Rect
+}
+
+/// Draws the content of a [`Tab`](crate::components_ui::Tab) into a [`TabContentContext::node`].
+pub type TabRenderer = Box<dyn Fn(&mut Commands, &TabContentContext) + Send + Sync>;
+
+/// Registry of the [`TabRenderer`]s that draw [`Tab`](crate::components_ui::Tab) content, by [`TabType`].  
+/// \
+/// Whenever a different [`Tab`](crate::components_ui::Tab) becomes active in a `Territory`, the old content is despawned and 
+/// the renderer registered for the new one's [`TabType`] is called with a fresh [`TabContentContext`]. 
+/// [`TabType`]s without a renderer are left empty. Registering again replaces the old renderer, and redraws every open [`Tab`](crate::components_ui::Tab).  
+/// \
+/// The content node is laid out by bevy_ui, so it keeps up with moves and resizes without calling the renderer again.
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use megalith::components_ui::TabType;
+/// # use megalith::resources_ui::TabRenderers;
+/// fn register_glossary(mut tab_renderers: ResMut<TabRenderers>) {
+///     tab_renderers.register(TabType::Glossary, |commands, context| {
+///         commands.entity(context.node).with_children(|parent| {
+///             for term in ["Territory", "Tab", "Window"] {
+///                 parent.spawn(TextBundle::from_section(term, TextStyle::default()));
+///             }
+///         });
+///     });
+/// }
+/// # App::new().init_resource::<TabRenderers>().add_systems(Startup, register_glossary);
+/// ```
+#[derive(Resource, Default)]
+pub struct TabRenderers {
+    renderers: HashMap<TabType, TabRenderer>
+}
+impl TabRenderers {
+    /// Registers the renderer for a [`TabType`], replacing any already there.
+    pub fn register(
+        &mut self, 
+        tab_type: TabType, 
+        renderer: impl Fn(&mut Commands, &TabContentContext) + Send + Sync + 'static
+    ) -> &mut Self {
+        self.renderers.insert(tab_type, Box::new(renderer));
+        self
+    }
+
+    /// Removes the renderer for a [`TabType`], so its [`Tab`](crate::components_ui::Tab)s are left empty.
+    pub fn unregister(&mut self, tab_type: TabType) -> Option<TabRenderer> {
+        self.renderers.remove(&tab_type)
+    }
+
+    /// Gets the renderer for a [`TabType`], if one is registered.
+    pub fn get(&self, tab_type: TabType) -> Option<&TabRenderer> {
+        self.renderers.get(&tab_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tab_text_falls_back_for_glyphs_outside_primary_font() {
+        let fallback_font: Handle<Font> = Handle::weak_from_u128(0x5EED_F0E7);
+        let config = TabTextConfig::default()
+            .with_fallback(fallback_font.clone(), vec!['\u{1F300}'..='\u{1FAFF}']);
+
+        let sections = config.sections("📁 Files", TextStyle::default());
+
+        assert_eq!(sections.len(), 2, "Expected the icon and the name in separate sections.");
+        assert_eq!(sections[0].value, "📁");
+        assert_eq!(sections[0].style.font, fallback_font, "Out of range glyph did not use the fallback font.");
+        assert_eq!(sections[1].value, " Files");
+        assert_eq!(sections[1].style.font, config.font, "Latin text did not stay on the primary font.");
+
+        // Nothing claims this one, so it stays on the primary font rather than vanishing.
+        assert_eq!(*config.font_for('\u{4E2D}'), config.font);
+    }
+}
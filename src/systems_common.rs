@@ -8,17 +8,38 @@ pub struct CleanupOnWindowClose;
 pub struct CleanupOnMovingTabExit;
 
 
-// App states.
-// Territory Tabs states.
+/// App State of the `Territory Tabs` UI. The one source of truth for what the user is up to.
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TerritoryTabsState {
     #[default]
-    Empty, // No territories exist. Need to make one before the user can do anything.
-    Natural, // Running normally. Operating the functions in Tabs.
-    MovingTabs, // A Tab move is underway! No Territories will be moved, and normal Tab operations are disabled.
-    DraggingTerritories, // User is dragging a Territory. Run collision detection and disable Tab operations.
-    ResizingTerritories, // User is resizing a Territory. Run resize logic and disable Tab operations.
-    LoadingLayouts // User is loading in a saved layout of Territories & Tabs. Existing ones cannot be interacted with.
+    /// No territories exist. Need to make one before the user can do anything, so each `Window` shows a "New Territory" button.
+    Empty,
+    /// Running normally. Operating the functions in Tabs.
+    Natural,
+    /// A Tab move is underway! No Territories will be moved, and normal Tab operations are disabled.
+    MovingTabs,
+    /// User is dragging a Territory. Run collision detection and disable Tab operations.
+    DraggingTerritories,
+    /// User is resizing a Territory. Run resize logic and disable Tab operations.
+    ResizingTerritories,
+    /// User is loading in a saved layout of Territories & Tabs. Existing ones cannot be interacted with.
+    LoadingLayouts
+}
+impl TerritoryTabsState {
+    /// Whether the state machine systems are ever meant to go from this state straight to `to`.  
+    /// \
+    /// Everything that isn't [`TerritoryTabsState::Empty`] or [`TerritoryTabsState::Natural`] can only go back to [`TerritoryTabsState::Natural`], 
+    /// except a layout load, which may end up with no Territories at all.
+    pub fn can_transition_to(&self, to: &TerritoryTabsState) -> bool {
+        use TerritoryTabsState::*;
+        matches!(
+            (self, to),
+            (Empty, Natural | MovingTabs | LoadingLayouts)
+                | (Natural, Empty | MovingTabs | DraggingTerritories | ResizingTerritories | LoadingLayouts)
+                | (MovingTabs | DraggingTerritories | ResizingTerritories, Natural)
+                | (LoadingLayouts, Natural | Empty)
+        )
+    }
 }
 
 /// Remove all entities with a specified component.
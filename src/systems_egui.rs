@@ -6,12 +6,20 @@ use bevy_egui::{egui, EguiContext};
 use crate::components_ui::*;
 use crate::resources_ui::*;
 use crate::components_territory::*;
+use crate::systems_common::TerritoryTabsState;
 
 // egui Debug Info Window until we get Tabs up and running.
+// Also lists the CardinalConnections of the hovered Territory, or the Focused one if none is hovered, 
+// so wrong adjacency shows up without digging through logs.
 pub fn display_debug_info_with_egui(
-    territory_tabs_current_state: Res<State<TerritoryTabsMode>>,
-    mut window_query: Query<(Entity, &Window, &mut EguiContext)>
+    territory_tabs_current_state: Res<State<TerritoryTabsState>>,
+    mouse_location_resource: Res<WorldMousePosition>,
+    mut window_query: Query<(Entity, &Window, &mut EguiContext)>,
+    focused_query: Query<Entity, (With<Territory>, With<Focused>)>,
+    connections_query: Query<&CardinalConnections>
 ) {
+    let inspected_territory = mouse_location_resource.territory.or_else(|| focused_query.iter().next());
+
     for (_window_entity, _window, mut context) in &mut window_query {
         egui::Window::new("Debug Window")
             .title_bar(false)
@@ -21,6 +29,24 @@ pub fn display_debug_info_with_egui(
                 let main_state_label = format!("Current State: {:?}", territory_tabs_current_state.get());
                 ui.label(main_state_label);
 
+                if let Some(territory_entity) = inspected_territory {
+                    ui.separator();
+                    ui.label(format!("Territory: {:?}", territory_entity));
+                    match connections_query.get(territory_entity) {
+                        Ok(connections) => {
+                            for (side, neighbors) in [
+                                ("North", &connections.northern), 
+                                ("East", &connections.eastern), 
+                                ("South", &connections.southern), 
+                                ("West", &connections.western)
+                            ] {
+                                ui.label(format!("{}: {} {:?}", side, neighbors.len(), neighbors));
+                            }
+                        },
+                        Err(_) => { ui.label("No CardinalConnections"); }
+                    }
+                }
+
                 ui.allocate_space(ui.available_size());
             }
         );
@@ -54,38 +80,45 @@ pub fn display_placeholders_egui(
     }
 }
 
+/// Draws every [`Territory`] with [`DisplayLibrary::BevyEgui`] as an egui window in its `Window`'s [`EguiContext`], 
+/// and turns drags and resizes of it into a [`MoveRequest`].
 pub fn display_territory_egui (
     mut commands: Commands,
     territory_settings: Res<TerritorySettings>,
     mut window_query: Query<(Entity, &Window, &mut EguiContext)>,
-    territory_query: Query<(Entity, &Parent, &Territory, &DisplayLibrary), Without<Overlay>>
+    territory_query: Query<(Entity, &Parent, &Territory, &DisplayLibrary), Without<Overlay>>,
+    focused_query: Query<(), With<Focused>>
 ) {
     for (
         window_entity, 
         window, 
         mut egui_context
     ) in &mut window_query {
+        // The Focused Territory goes last, so its egui window is painted on top.
+        let mut window_territories: Vec<_> = territory_query.iter().collect();
+        window_territories.sort_by_key(|(territory_entity, ..)| focused_query.contains(*territory_entity));
         for (
             territory_entity, 
             territory_parent, 
             territory, 
             territory_display
-        ) in & territory_query {
+        ) in window_territories {
             // Iterate through all Territory components with DisplayLibrary::BevyEgui and add 
             // egui ui to their Parent window's context.
             if territory_parent.get() == window_entity && matches!(territory_display, DisplayLibrary::BevyEgui) {
                 // egui doesn't really like to paint a window to your exact specifications.
                 // Some fighting and hair-pulling may be required. 
+                let territory_rect = territory.screenspace_rect();
                 let requested_egui_rect = egui::Rect::from_center_size(
                     egui::Pos2::new(
-                        territory.expanse.screenspace().center().x, 
-                        territory.expanse.screenspace().center().y
+                        territory_rect.center().x, 
+                        territory_rect.center().y
                     ), 
                     egui::Vec2::new(
-                        territory.expanse.screenspace().size().x,
+                        territory_rect.size().x,
                     //    - territory_settings.inner_margins.x * 2.0
                     //    - territory_settings.spacing, 
-                        territory.expanse.screenspace().size().y
+                        territory_rect.size().y
                     //    - territory_settings.inner_margins.y * 2.0
                     //    - territory_settings.spacing
                     )
@@ -124,6 +157,7 @@ pub fn display_territory_egui (
                     .title_bar(false)
                     .frame(territory_frame)
                     .pivot(egui::Align2::CENTER_CENTER)
+                    .order(if focused_query.contains(territory_entity) { egui::Order::Foreground } else { egui::Order::Middle })
                     .min_width(territory_settings.min_size.x)
                     .min_height(territory_settings.min_size.y) // Doesn't appear to do anything??
                     .max_width(window.width()) // Doesn't appear to do anything??
@@ -172,48 +206,40 @@ pub fn display_territory_egui (
                                     // Will conveniently overwrite an old MoveRequest should one exist, which it shouldn't!
                                     if bg_response.dragged() && delta_size.abs().length() == 0.0 {
                                         debug!("MoveRequest drag delta change sent: {:?}", bg_response.drag_delta());
-                                        let move_requested = 
-                                            MoveRequest::new(
-                                                RectKit::from_screenspace(
-                                                    Rect::from_center_size(
-                                                        Vec2::new(
-                                                        actual_egui_rect.center().x + bg_response.drag_delta().x,
-                                                        actual_egui_rect.center().y + bg_response.drag_delta().y
-                                                        ), 
-                                                        Vec2::new(
-                                                        actual_egui_rect.size().x, 
-                                                        actual_egui_rect.size().y
-                                                        )
-                                                    ),
-                                                window.width(),
-                                                window.height()
-                                                ),
-                                                MoveRequestType::Unknown
-                                            );
+                                        let move_requested = MoveRequest::from_screenspace_rect(
+                                            Rect::from_center_size(
+                                                Vec2::new(
+                                                    actual_egui_rect.center().x + bg_response.drag_delta().x,
+                                                    actual_egui_rect.center().y + bg_response.drag_delta().y
+                                                ), 
+                                                Vec2::new(
+                                                    actual_egui_rect.size().x, 
+                                                    actual_egui_rect.size().y
+                                                )
+                                            ),
+                                            window.width(),
+                                            window.height()
+                                        );
                                         commands.entity(territory_entity).insert(move_requested);
                                     }
                                     else if !bg_response.dragged() && delta_size.abs().length() > 0.0 {
                                         debug!("MoveRequest resize delta change sent: {:?}", delta_size);
                                         
 
-                                        let move_requested = 
-                                            MoveRequest::new(
-                                                RectKit::from_screenspace(
-                                                    Rect::from_corners(
-                                                        Vec2::new(
-                                                            actual_egui_rect.min.x, 
-                                                            actual_egui_rect.min.y
-                                                        ), 
-                                                        Vec2::new(
-                                                            actual_egui_rect.max.x, 
-                                                            actual_egui_rect.max.y
-                                                        )
-                                                    ), 
-                                                    window.width(), 
-                                                    window.height()
-                                                ),
-                                                MoveRequestType::Unknown
-                                            );
+                                        let move_requested = MoveRequest::from_screenspace_rect(
+                                            Rect::from_corners(
+                                                Vec2::new(
+                                                    actual_egui_rect.min.x, 
+                                                    actual_egui_rect.min.y
+                                                ), 
+                                                Vec2::new(
+                                                    actual_egui_rect.max.x, 
+                                                    actual_egui_rect.max.y
+                                                )
+                                            ), 
+                                            window.width(), 
+                                            window.height()
+                                        );
                                         commands.entity(territory_entity).insert(move_requested);
                                     }
 
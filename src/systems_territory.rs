@@ -3,34 +3,88 @@
 use std::f32::consts::FRAC_PI_4;
 use std::f32::consts::PI;
 
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::input::mouse::MouseWheel;
+use bevy::input::touch::TouchInput;
 use bevy::prelude::*;
 use bevy::window::*;
+use bevy::utils::{HashMap, HashSet};
 use bevy::render::camera::*;
+use bevy::render::view::RenderLayers;
+use leafwing_input_manager::prelude::ActionState;
 
 use crate::components_territory::*;
+use crate::components_ui::{Overlay, Tab, TabType};
 use crate::display_territory::*;
 use crate::display_territory_sickle::*;
 use crate::input_manager::*;
-use crate::systems_common::remove_all_components_of_type;
-
+use crate::resources_ui::{TabRenderers, TabTextConfig, TerritoryStyle};
+use crate::systems_common::{despawn_all_entities_with, remove_all_components_of_type, TerritoryTabsState};
+
+
+/// Plugin for all [`Territory`] logic.
+pub struct TerritoryPlugin {
+    /// [`TerritoryTabsState`] the app starts in. Defaults to [`TerritoryTabsState::Empty`], 
+    /// since no [`Territory`] exists until one is spawned.
+    pub initial_state: TerritoryTabsState,
+    /// Whether [`display_debug_gizmos`] draws each `Window`'s [`Territory`]s. On by default in debug builds only.
+    pub debug_gizmos: bool,
+    /// [`DisplayLibrary`] new `Window`s start with, unless they were spawned with their own.
+    pub default_display_library: DisplayLibrary
+}
 
-pub struct TerritoryPlugin;
+impl Default for TerritoryPlugin {
+    fn default() -> Self {
+        TerritoryPlugin { 
+            initial_state: TerritoryTabsState::Empty, 
+            debug_gizmos: cfg!(debug_assertions), 
+            default_display_library: DisplayLibrary::default() 
+        }
+    }
+}
 
 impl Plugin for TerritoryPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<GlobalTerritorySettings>()
-            .insert_state(TerritoryTabsMode::Operating)
-            .add_event::<MoveRequestApplied>()
+            .init_resource::<GridSnap>()
+            .init_resource::<TileMode>()
+            .init_resource::<MoveRequestThrottle>()
+            .init_resource::<WindowTouches>()
+            .init_resource::<WindowRootIndex>()
+            .init_resource::<TabTextConfig>()
+            .init_resource::<TabRenderers>()
+            .init_resource::<TerritoryStyle>()
+            .insert_resource(DefaultDisplayLibrary(self.default_display_library))
+            .insert_state(self.initial_state.clone())
+            .add_event::<TerritoryMoved>()
             .add_event::<TerritorySpawnRequest>()
+            .add_event::<TerritorySpawnRejected>()
             .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_event::<TerritoryDespawnApproved>()
+            .add_event::<CombineTerritoriesRequest>()
+            .add_event::<AddTabRequest>()
+            .add_event::<RemoveTabRequest>()
+            .add_event::<SelectTab>()
+            .add_event::<MoveTabRequest>()
+            .add_event::<ToggleMaximizeTerritory>()
+            .add_event::<ApplyLayoutDiff>()
+            .add_event::<PanWindowLayout>()
+            .add_event::<TerritoryTabsStateChanged>()
             .add_systems(Startup, 
                 configure_gizmos
             )
+            .add_systems(OnEnter(TerritoryTabsState::Empty), spawn_empty_window_spawn_buttons)
+            .add_systems(OnExit(TerritoryTabsState::Empty), despawn_all_entities_with::<EmptyWindowSpawnNode>)
             .add_systems(Update, (
                 (
                     configure_os_window
                         .run_if(on_event::<WindowCreated>()),
+                    despawn_closed_window_roots
+                        .run_if(on_event::<WindowClosed>()),
+                    index_added_ui_roots,
+                    unindex_removed_ui_roots
                 )
                     .chain()
                     .in_set(WindowConfig),
@@ -39,9 +93,25 @@ impl Plugin for TerritoryPlugin {
                         .run_if(on_event::<TerritorySpawnRequest>()),
                     spawn_territory_sickle
                         .run_if(on_event::<TerritorySpawnRequest>()),
+                    despawn_territories_with_dangling_nodes,
                     despawn_territory
                         .run_if(on_event::<TerritoryDespawnRequest>()),
-                    display_debug_gizmos,
+                    despawn_approved_territories
+                        .run_if(on_event::<TerritoryDespawnApproved>()),
+                    measure_territory_content,
+                    scroll_territory_content
+                        .run_if(on_event::<MouseWheel>()),
+                    update_territory_scroll_node,
+                    update_territory_empty_hint,
+                    render_active_tab_content,
+                    sync_territory_tab_buttons_sickle,
+                    update_floating_base_node,
+                    update_territory_base_node_alpha,
+                    apply_territory_settings,
+                    display_move_previews,
+                    sync_resize_buttons_to_edges,
+                    sync_resize_buttons_sickle,
+                    resize_cursor_icon_sickle
                 )
                     .chain()
                     .in_set(TerritoryDisplay),
@@ -50,17 +120,111 @@ impl Plugin for TerritoryPlugin {
                     (
                         empty_if_no_territories
                             .run_if(territory_removed.or_else(territory_spawned)),
-                        test_delete_all_territories
+                        delete_all_territories
                             .run_if(on_event::<RemoveTerritoriesKeyPressed>()),
                         update_territory_base_node,
-                        territory_drag_move_request_sickle,
-                        territory_resize_move_request_sickle
+                        animate_territory_base_node
                     ) 
                         .chain()
                         .in_set(TerritoryUpdateState),
+                    announce_territory_tabs_state_changes
+                        .run_if(on_event::<StateTransitionEvent<TerritoryTabsState>>())
+                        .in_set(TerritoryUpdateState),
+                    spawn_empty_window_spawn_buttons
+                        .run_if(in_state(TerritoryTabsState::Empty).and_then(resource_changed::<WindowRootIndex>))
+                        .after(WindowConfig)
+                        .in_set(TerritoryUpdateState),
+                    (
+                        track_motion_origin_sickle
+                            .before(territory_drag_move_request_sickle)
+                            .before(territory_resize_move_request_sickle),
+                        territory_drag_move_request_sickle,
+                        territory_resize_move_request_sickle,
+                        cancel_territory_motion
+                            .run_if(input_just_pressed(KeyCode::Escape))
+                            .after(territory_drag_move_request_sickle)
+                            .after(territory_resize_move_request_sickle)
+                            .after(territory_drag_move_request)
+                            .after(territory_resize_move_request),
+                        track_window_touches
+                            .run_if(on_event::<TouchInput>())
+                            .before(territory_drag_move_request)
+                            .before(territory_resize_move_request),
+                        track_motion_origin
+                            .before(territory_drag_move_request)
+                            .before(territory_resize_move_request),
+                        territory_drag_move_request,
+                        territory_resize_move_request,
+                        (
+                            add_tab_button_interaction,
+                            empty_window_spawn_button_interaction
+                        ),
+                        tab_button_interaction_sickle,
+                        focus_interacted_territory,
+                        focus_neighbor_territory
+                            .after(focus_interacted_territory),
+                        maximize_on_drag_node_double_click,
+                        toggle_dock_focused_territory,
+                        clear_removed_territory_focus
+                            .run_if(any_component_removed::<Territory>()),
+                        apply_layout_diff
+                            .run_if(on_event::<ApplyLayoutDiff>())
+                    )
+                        .in_set(TerritoryInput),
+                    (
+                        add_requested_tabs
+                            .run_if(on_event::<AddTabRequest>()),
+                        remove_requested_tabs
+                            .run_if(on_event::<RemoveTabRequest>()),
+                        select_requested_tabs
+                            .run_if(on_event::<SelectTab>()),
+                        move_requested_tabs
+                            .run_if(on_event::<MoveTabRequest>()),
+                        combine_requested_territories
+                            .run_if(on_event::<CombineTerritoriesRequest>())
+                    )
+                        .chain()
+                        .in_set(TerritoryApply),
+                    (
+                        territory_drag_to_other_window,
+                        territory_hand_off_requests
+                    )
+                        .chain()
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
+                    track_territory_motion_state
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
+                    pan_window_layout
+                        .run_if(on_event::<PanWindowLayout>())
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
+                    toggle_maximized_territories
+                        .run_if(on_event::<ToggleMaximizeTerritory>())
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
+                    refit_territories_to_resized_window
+                        .run_if(on_event::<WindowResized>())
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
+                    dock_territories
+                        .run_if(any_with_component::<Docked>)
+                        .after(toggle_maximized_territories)
+                        .after(refit_territories_to_resized_window)
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
+                    commit_deferred_expanses
+                        .run_if(any_with_component::<DeferredExpanse>)
+                        .in_set(TerritoryApply)
+                        .before(TerritoryUpdateMotion),
                     (
                         territory_move_eval_type,
+                        territory_move_snap_resize,
+                        territory_move_snap_drag_to_window,
                         territory_move_process_fringe,
+                        territory_move_group_drags,
+                        territory_move_resolve_mutual_resize,
+                        territory_move_tile_neighbors,
                         territory_move_check_others,
                         territory_move_apply_proposed
                     )
@@ -75,11 +239,11 @@ impl Plugin for TerritoryPlugin {
                     ),*/
                     (
                         remove_all_components_of_type::<DragTerritoryGroup>
-                            .run_if(any_component_removed::<DragRequest>()),
+                            .run_if(any_component_removed::<DragRequest>().or_else(any_component_removed::<MoveRequest>())),
                         remove_all_components_of_type::<AdvancingTerritoryGroup>
-                            .run_if(any_component_removed::<ResizeRequest>()),
+                            .run_if(any_component_removed::<ResizeRequest>().or_else(any_component_removed::<MoveRequest>())),
                         remove_all_components_of_type::<RetreatingTerritoryGroup>
-                            .run_if(any_component_removed::<ResizeRequest>())
+                            .run_if(any_component_removed::<ResizeRequest>().or_else(any_component_removed::<MoveRequest>()))
                     )
                         .chain()
                         .in_set(TerritoryUpdateMotionCleanup)
@@ -87,13 +251,17 @@ impl Plugin for TerritoryPlugin {
 
                 )
                     .in_set(TerritoryUpdate)
-            ))
-            .configure_sets(Update,
-                (
-                        WindowConfig.before(TerritoryDisplay),
-                        TerritoryDisplay.before(TerritoryUpdate)
-                ),
-        );
+            ));
+        configure_territory_sets(app);
+        add_cardinal_connection_systems(app);
+
+        // One debug gizmo group for each of the first DEBUG_GIZMO_SLOTS render layer slots, so each Window only draws its own.
+        if self.debug_gizmos {
+            add_debug_gizmo_slot::<0>(app);
+            add_debug_gizmo_slot::<1>(app);
+            add_debug_gizmo_slot::<2>(app);
+            add_debug_gizmo_slot::<3>(app);
+        }
     }
 }
 
@@ -114,7 +282,22 @@ pub struct TerritoryDisplaySickle;
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct TerritoryUpdate;
 
-/// Contains systems that act as state machines for [`TerritoryTabsMode`].
+/// Public entry point for turning input into requests. Always runs before [`TerritoryApply`].  
+/// \
+/// Systems in here read input, from sickle_ui or anywhere else, and insert [`DragRequest`], [`ResizeRequest`], 
+/// or [`MoveRequest`] components on a [`Territory`], or send tab request events. 
+/// Anything added here is applied by [`TerritoryApply`] in the same frame.
+/// Apps with their own input handling can add systems to this set, or order them `.before(TerritoryApply)`.
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct TerritoryInput;
+
+/// Public set of systems that process requests and apply them. Always runs after [`TerritoryInput`].  
+/// \
+/// Contains [`TerritoryUpdateMotion`] along with the tab request handlers.
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct TerritoryApply;
+
+/// Contains systems that act as state machines for [`TerritoryTabsState`].
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct TerritoryUpdateState;
 
@@ -134,9 +317,24 @@ pub struct TerritoryUpdateResizeMotion;
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct TerritoryUpdateMotionCleanup;
 
-/// Sent when a UI element is issued a [`MoveRequest`] component.
+/// Query filter for [`Territory`]s that take part in the tiled layout, leaving out [`Floating`], [`Overlay`], and [`Maximized`] ones.
+pub type TiledTerritory = (Without<Floating>, Without<Overlay>, Without<Maximized>);
+
+/// Sent when a [`MoveRequest`] is applied, or a [`CombineTerritoriesRequest`] grows the surviving [`Territory`], 
+/// and a [`Territory`]'s expanse actually changes.  
+/// \
+/// Lets systems react to layout changes without polling `Changed<Territory>`.
 #[derive(Event)]
-pub struct MoveRequestApplied;
+pub struct TerritoryMoved {
+    /// [`Territory`] [`Entity`] that moved.
+    pub territory: Entity,
+    /// [`Window`] [`Entity`] the [`Territory`] is in.
+    pub window: Entity,
+    /// Expanse before the move.
+    pub old: RectKit,
+    /// Expanse after the move.
+    pub new: RectKit
+}
 
 /// Sent when a system has commanded a [`Territory`] to spawn in a `Window` `Entity`.
 #[derive(Event)]
@@ -145,8 +343,106 @@ pub struct TerritorySpawnRequest {
     pub window_entity: Entity,
     /// Where the [`Territory`] should be.
     pub expanse: RectKit,
+    /// Where the [`Territory`] should be in relative **screenspace**, from `(0.0, 0.0)` at top left to `(1.0, 1.0)` at bottom right. 
+    /// If set, it replaces [`TerritorySpawnRequest::expanse`] once the `Window` size is known.
+    pub relative_screenspace: Option<Rect>,
     /// How the [`Territory`] should be represented in UI.
-    pub display_library: DisplayLibrary
+    pub display_library: DisplayLibrary,
+    /// Which sides of the [`Territory`] get resize handles.
+    pub resizable_edges: ResizableEdges
+}
+impl TerritorySpawnRequest {
+    /// Requests a [`Territory`] at a relative **screenspace** [`Rect`], without needing to know the size of the `Window`.  
+    /// \
+    /// The absolute [`RectKit`] is resolved by [`spawn_territory`], using the size the `Window` has at that point. 
+    /// Useful for seeding a default layout at startup regardless of resolution.
+    pub fn from_relative(window_entity: Entity, relative_screenspace: Rect, display_library: DisplayLibrary) -> Self {
+        TerritorySpawnRequest {
+            window_entity,
+            expanse: RectKit::empty(),
+            relative_screenspace: Some(relative_screenspace),
+            display_library,
+            resizable_edges: ResizableEdges::default()
+        }
+    }
+}
+
+/// Sent when a system or user has requested a new [`Tab`] be added to a [`Territory`].  
+/// \
+/// The new [`Tab`] is spawned as a child of the [`Territory`] and appended to its tab list.
+/// It becomes the active tab if it asks to be, or if the [`Territory`] has no active tab yet.
+#[derive(Event)]
+pub struct AddTabRequest {
+    /// [`Territory`] [`Entity`] receiving the new tab.
+    pub territory: Entity,
+    /// The [`Tab`] to spawn.
+    pub tab: Tab
+}
+
+/// Sent when a system or user has requested a [`Tab`] be removed from its [`Territory`] and despawned.  
+/// \
+/// If the removed [`Tab`] was active, its neighbor in the tab list becomes active.
+#[derive(Event)]
+pub struct RemoveTabRequest {
+    /// [`Tab`] [`Entity`] to be removed.
+    pub tab: Entity
+}
+
+/// Sent when a system or user wants a [`Tab`] moved out of its [`Territory`] and into another one.  
+/// \
+/// The moved [`Tab`] becomes the active tab of the [`Territory`] it lands in. 
+/// If it was active in the [`Territory`] it left, its neighbor in the tab list becomes active there.
+#[derive(Event)]
+pub struct MoveTabRequest {
+    /// [`Tab`] [`Entity`] to be moved.
+    pub tab: Entity,
+    /// [`Territory`] [`Entity`] receiving the [`Tab`].
+    pub territory: Entity
+}
+
+/// Sent when a system or user wants to make a specific [`Tab`] the active one in its [`Territory`].
+#[derive(Event)]
+pub struct SelectTab {
+    /// [`Territory`] [`Entity`] the [`Tab`] is in.
+    pub territory: Entity,
+    /// Which [`Tab`] to activate.
+    pub selector: TabSelector
+}
+
+/// Ways to pick out a [`Tab`] in a [`Territory`] for a [`SelectTab`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabSelector {
+    /// Position in the [`Territory`]'s tab list.
+    Index(usize),
+    /// First [`Tab`] in the list with this [`TabType`].
+    Type(TabType),
+    /// A specific [`Tab`] [`Entity`], which must be in the [`Territory`].
+    Entity(Entity)
+}
+
+/// Sent to maximize a [`Territory`] to fill its `Window`, or to restore it if it's already [`Maximized`].
+#[derive(Event)]
+pub struct ToggleMaximizeTerritory {
+    /// [`Territory`] [`Entity`] to maximize or restore.
+    pub territory: Entity
+}
+
+/// Sent when a [`TerritorySpawnRequest`] could not be carried out, so no [`Territory`] was spawned.
+#[derive(Event, Debug)]
+pub struct TerritorySpawnRejected {
+    /// The `Window` [`Entity`] the [`Territory`] was requested in.
+    pub window_entity: Entity,
+    /// Why the request was rejected.
+    pub reason: TerritorySpawnRejection
+}
+
+/// Reasons a [`TerritorySpawnRequest`] can be rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerritorySpawnRejection {
+    /// Target [`Entity`] is not a `Window` with a [`TerritoryTabs`] component.
+    NotTerritoryTabsWindow,
+    /// Target `Window` has no [`TerritoryTabsUIRoot`] node to put the [`Territory`] in, likely not configured yet.
+    MissingRootNode
 }
 
 /// Sent when a system has commanded a [`Territory`] to despawn.
@@ -156,6 +452,57 @@ pub struct TerritoryDespawnRequest {
     pub despawned_territory: Entity
 }
 
+/// Sent once for each [`Territory`] with a [`TerritoryDespawnRequest`], before it is despawned.  
+/// \
+/// With a [`TerritoryDespawnGuard`] in place, the [`Territory`] is only despawned once a 
+/// [`TerritoryDespawnApproved`] comes back for it. Otherwise this is just a heads up.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerritoryDespawnRequested {
+    /// [`Territory`] that wants to despawn.
+    pub territory: Entity
+}
+
+/// Sent in answer to a [`TerritoryDespawnRequested`] to let the [`Territory`] despawn while a [`TerritoryDespawnGuard`] is in place. 
+/// Never sending one vetoes the despawn.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerritoryDespawnApproved {
+    /// [`Territory`] that can despawn.
+    pub territory: Entity
+}
+
+/// Sent when a system or user wants two [`Territory`]s in the same `Window` merged into one.  
+/// \
+/// Every [`Tab`] of the absorbed [`Territory`] moves into the surviving one, which grows to cover both.
+#[derive(Event)]
+pub struct CombineTerritoriesRequest {
+    /// [`Territory`] [`Entity`] that takes the tabs and the combined expanse.
+    pub surviving: Entity,
+    /// [`Territory`] [`Entity`] that's emptied and despawned.
+    pub absorbed: Entity
+}
+
+/// Sent to pan the whole layout of a `Window`, offsetting all of its [`Territory`]s together.  
+/// \
+/// [`Locked`], [`RelativeAnchored`], [`Docked`], and non-tiled [`Territory`]s stay put. The others move as a group, and stop once only 
+/// [`GlobalTerritorySettings::min_size`] of their combined bounds is left on screen, or before running into one that stays put.
+#[derive(Event)]
+pub struct PanWindowLayout {
+    /// `Window` [`Entity`] whose layout is panned.
+    pub window: Entity,
+    /// How far to pan, in **worldspace**.
+    pub delta: Vec2
+}
+
+/// Sent to bring the current [`Territory`]s in line with a [`LayoutDiff`], such as one received from a collaborator.  
+/// \
+/// Added and removed [`Territory`]s become [`TerritorySpawnRequest`]s and [`TerritoryDespawnRequest`]s.
+/// Moved and resized [`Territory`]s get a [`MoveRequest`] and go through the usual motion systems.
+#[derive(Event)]
+pub struct ApplyLayoutDiff {
+    /// Changes to apply.
+    pub diff: LayoutDiff
+}
+
 /// Make debug gizmos not be covered up by nodes.
 pub fn configure_gizmos (
     mut gizmo_central_resource: ResMut<GizmoConfigStore>
@@ -164,37 +511,143 @@ pub fn configure_gizmos (
     config.depth_bias = -1.0;
 }
 
-/// Debug gizmos!
-pub fn display_debug_gizmos (
-    mut gizmos: Gizmos,
-    territory_query: Query<&Territory>
+/// Puts one slot's [`TerritoryDebugGizmos`] on its own render layer, and keeps them from being covered up by nodes.
+pub fn configure_debug_gizmos<const SLOT: usize> (
+    mut gizmo_central_resource: ResMut<GizmoConfigStore>
 ) {
-    for territory in & territory_query {
-        gizmos.rect_2d(
-            territory.expanse.worldspace().center(), 
-            0.0,
-            territory.expanse.worldspace().size(),
-            bevy::color::palettes::css::BLUE,
-        );
+    let (config, _) = gizmo_central_resource.config_mut::<TerritoryDebugGizmos<SLOT>>();
+    config.depth_bias = -1.0;
+    config.render_layers = RenderLayers::layer(WindowRenderLayer::new(SLOT).gizmo_layer());
+}
+
+/// Debug gizmos! Each `Window`'s [`Territory`]s are drawn with the gizmo group of its [`WindowRenderLayer`] slot, 
+/// colored by [`debug_gizmo_color`]. Their [`CardinalConnections`] are drawn as lines between neighbor centers, 
+/// and any [`DeferredExpanse`] as a white outline.
+pub fn display_debug_gizmos<const SLOT: usize> (
+    mut gizmos: Gizmos<TerritoryDebugGizmos<SLOT>>,
+    window_query: Query<(&WindowRenderLayer, &Children)>,
+    territory_query: Query<(Entity, &Territory)>,
+    state_query: Query<(Has<Locked>, Has<Focused>, Has<MotionOrigin>)>,
+    deferred_expanse_query: Query<&DeferredExpanse>,
+    connections_query: Query<&CardinalConnections>
+) {
+    for (window_render_layer, window_children) in & window_query {
+        if window_render_layer.slot() != SLOT { continue; }
+        for (territory_entity, territory) in territory_query.iter_many(window_children) {
+            let (locked, focused, moving) = state_query.get(territory_entity).unwrap_or_default();
+            let worldspace = territory.expanse.worldspace();
+            gizmos.rect_2d(
+                worldspace.center(), 
+                0.0,
+                worldspace.size(),
+                debug_gizmo_color(locked, focused, moving),
+            );
+            if let Ok(deferred_expanse) = deferred_expanse_query.get(territory_entity) {
+                let deferred_worldspace = deferred_expanse.expanse().worldspace();
+                gizmos.rect_2d(deferred_worldspace.center(), 0.0, deferred_worldspace.size(), bevy::color::palettes::css::WHITE);
+            }
+
+            let Ok(connections) = connections_query.get(territory_entity) else { continue; };
+            for neighbor_entity in connections.get_all_vec() {
+                // Each link is stored on both ends, so only draw it once.
+                if neighbor_entity < territory_entity { continue; }
+                let Ok((_, neighbor)) = territory_query.get(neighbor_entity) else { continue; };
+                gizmos.line_2d(worldspace.center(), neighbor.expanse.worldspace().center(), bevy::color::palettes::css::GRAY);
+            }
+        }
     }
 }
 
+/// Color of a [`Territory`]'s debug gizmo. [`Locked`] is red, one being dragged or resized (with a [`MotionOrigin`]) yellow, 
+/// [`Focused`] green, and anything else blue, in that order of precedence.
+pub fn debug_gizmo_color(locked: bool, focused: bool, moving: bool) -> Srgba {
+    use bevy::color::palettes::css::{BLUE, GREEN, RED, YELLOW};
+    if locked { RED }
+    else if moving { YELLOW }
+    else if focused { GREEN }
+    else { BLUE }
+}
+
+/// Orders the [`TerritoryTabs`] system sets against each other.
+fn configure_territory_sets(app: &mut App) {
+    app.configure_sets(Update,
+        (
+                WindowConfig.before(TerritoryDisplay),
+                TerritoryDisplay.before(TerritoryUpdate),
+                TerritoryInput.before(TerritoryApply),
+                TerritoryUpdateMotion.in_set(TerritoryApply),
+                TerritoryUpdateMotionCleanup.after(TerritoryApply)
+        ),
+    );
+}
+
+/// Registers [`rebuild_cardinal_connections`], and [`restore_layout_connections`] right after it with the `serde` feature.
+fn add_cardinal_connection_systems(app: &mut App) {
+    app.add_systems(Update, 
+        rebuild_cardinal_connections
+            .after(TerritoryApply)
+            .before(TerritoryUpdateMotionCleanup)
+            .in_set(TerritoryUpdate)
+    );
+
+    // Connections from a loaded layout go on after the rebuild, which would otherwise overwrite them 
+    // as soon as it sees their freshly spawned Territories.
+    #[cfg(feature = "serde")]
+    app.add_systems(Update, 
+        restore_layout_connections
+            .run_if(resource_exists::<PendingLayoutConnections>)
+            .after(rebuild_cardinal_connections)
+            .before(TerritoryUpdateMotionCleanup)
+            .in_set(TerritoryUpdate)
+    );
+}
+
+/// Registers the [`TerritoryDebugGizmos`] group for one [`WindowRenderLayer`] slot, along with its systems.
+fn add_debug_gizmo_slot<const SLOT: usize>(app: &mut App) {
+    app
+        .init_gizmo_group::<TerritoryDebugGizmos<SLOT>>()
+        .add_systems(Startup, configure_debug_gizmos::<SLOT>)
+        .add_systems(Update, display_debug_gizmos::<SLOT>.in_set(TerritoryDisplay));
+}
+
 
 /// TODO: Refactor this out!
 #[derive(Component)]
 pub struct MouseSeekingCamera;
 
 /// A default configuration for the OS windows. Background camera, names, etc.
-/// Summoned by a [`WindowCreated`] event and configures that exact window.
+/// Summoned by a [`WindowCreated`] event and configures that exact window.  
+/// \
+/// A `Window` spawned without a [`DisplayLibrary`] gets the [`DefaultDisplayLibrary`], and one spawned with its own keeps it.
 pub fn configure_os_window(
     mut commands: Commands,
     mut window_spawn_detected_events: EventReader<WindowCreated>,
-    mut window_query: Query<&mut Window>
+    default_display_library: Option<Res<DefaultDisplayLibrary>>,
+    mut window_query: Query<(&mut Window, Option<&DisplayLibrary>)>,
+    render_layer_query: Query<&WindowRenderLayer>
 ) {
+    let default_display_library = default_display_library.map(|default_display_library| default_display_library.0).unwrap_or_default();
+
+    // Hand out the render layer slots nobody is using first.
+    let mut used_slots: Vec<usize> = render_layer_query.iter().map(|window_render_layer| window_render_layer.slot()).collect();
+
     for event in window_spawn_detected_events.read() {
-        if let Ok(mut window) = window_query.get_mut(event.window) {
+        if let Ok((mut window, display_library)) = window_query.get_mut(event.window) {
             window.title = "Territory Tabs".to_string();
 
+            // One of the first `used_slots.len() + 1` slots is always free.
+            let free_slot = (0..=used_slots.len())
+                .find(|slot| !used_slots.contains(slot))
+                .unwrap_or(used_slots.len());
+            used_slots.push(free_slot);
+            let window_render_layer = WindowRenderLayer::new(free_slot);
+            if !window_render_layer.has_debug_gizmos() {
+                warn!(
+                    "Window {:?} got render layer slot {}, past the {} with debug gizmos. It won't draw any.", 
+                    event.window, free_slot, DEBUG_GIZMO_SLOTS
+                );
+            }
+
             let child_camera = commands.spawn((
                 Name::new("[CAMERA] Territory Tabs UI Camera"),
                 Camera2dBundle {
@@ -205,6 +658,7 @@ pub fn configure_os_window(
                         }, 
                     ..Default::default()
                 },
+                window_render_layer.camera_layers(),
                 TerritoryTabsCamera,
                 MouseSeekingCamera // TODO: Refactor this out.
             )).id();
@@ -232,344 +686,1194 @@ pub fn configure_os_window(
                 .insert((
                     Name::new("[WINDOW] Territory Tabs Window"),
                     TerritoryTabs,
-                    DisplayLibrary::BevySickle,
+                    display_library.copied().unwrap_or(default_display_library),
+                    WindowCursor::default(),
+                    TerritoryHitGrid::default(),
+                    FocusedTerritory::default(),
+                    window_render_layer,
                     SpatialBundle::default()
             ));
         }
     }
 }
 
-/// Run condition checking if a [`Territory`] spawned recently.
-pub fn territory_spawned (
-    added_query: Query<&Territory, Added<Territory>>
-) -> bool {
-    !added_query.is_empty()
+/// When a `Window` closes, despawn the root node it was associated with.
+pub fn despawn_closed_window_roots (
+    mut commands: Commands,
+    mut window_closed_events: EventReader<WindowClosed>,
+    window_root_index: Res<WindowRootIndex>
+) {
+    for closed_event in window_closed_events.read() {
+        if let Some(root_node_entity) = window_root_index.root_of(closed_event.window) {
+            commands.entity(root_node_entity).despawn_recursive();
+        }
+    }
 }
 
-/// Run condition checking if a [`Territory`] was removed recently.
-pub fn territory_removed (
-    removed_query: RemovedComponents<Territory>
-) -> bool {
-    !removed_query.is_empty()
+/// Adds any new [`TerritoryTabsUIRoot`] to the [`WindowRootIndex`].
+pub fn index_added_ui_roots (
+    mut window_root_index: ResMut<WindowRootIndex>,
+    added_root_query: Query<(Entity, &TerritoryTabsUIRoot), Added<TerritoryTabsUIRoot>>
+) {
+    for (root_node_entity, ui_root) in & added_root_query {
+        window_root_index.insert(ui_root.associated_window_entity, root_node_entity);
+    }
 }
 
-/// When a [`Territory`] component is removed, check to see if there are any left.
-/// Change [`TerritoryTabsMode`] state to [`TerritoryTabsMode::Empty`] if so.
-/// Change it back when a new one is spawned.
-pub fn empty_if_no_territories (
-    territory_tabs_mode: Res<State<TerritoryTabsMode>>,
-    mut set_territory_tabs_mode: ResMut<NextState<TerritoryTabsMode>>,
-    territory_query: Query<&Territory>,
+/// Removes any despawned [`TerritoryTabsUIRoot`] from the [`WindowRootIndex`].
+pub fn unindex_removed_ui_roots (
+    mut window_root_index: ResMut<WindowRootIndex>,
+    mut removed_roots: RemovedComponents<TerritoryTabsUIRoot>
 ) {
-    if territory_query.is_empty() {
-        match territory_tabs_mode.get() {
-            TerritoryTabsMode::Empty => { 
-                //warn!("Unexpected transition: Empty -> Empty"); 
-            }
-            TerritoryTabsMode::Operating => { 
-                set_territory_tabs_mode.set(TerritoryTabsMode::Empty); 
-            }
-            TerritoryTabsMode::MovingTerritories => { 
-                set_territory_tabs_mode.set(TerritoryTabsMode::Empty);
-                warn!("Unexpected transition: MovingTerritories -> Empty"); 
-            }
-            TerritoryTabsMode::MovingTabs => { 
-                set_territory_tabs_mode.set(TerritoryTabsMode::Empty);
-                warn!("Unexpected transition: MovingTabs -> Empty"); 
-            }
-        }
-    }
-    else {
-        match territory_tabs_mode.get() {
-            TerritoryTabsMode::Empty => { set_territory_tabs_mode.set(TerritoryTabsMode::Operating); }
-            _ => {}
-        }
+    for root_node_entity in removed_roots.read() {
+        window_root_index.remove_root(root_node_entity);
     }
 }
 
-/// Debug system Removes all entities with [`Territory`] when the dev key chord event is read..
-pub fn test_delete_all_territories (
-    mut remove_territories_key_pressed: EventReader<RemoveTerritoriesKeyPressed>,
-    mut despawn_territory_request:EventWriter<TerritoryDespawnRequest>,
-    window_query: Query<&Children, With<Window>>,
-    territory_query: Query<Entity, With<Territory>>
+/// Handles all [`CombineTerritoriesRequest`]s, moving the [`Tab`]s of the absorbed [`Territory`] into the surviving one.  
+/// \
+/// The surviving [`Territory`] grows to cover both, clamped to the `Window`, sending a [`TerritoryMoved`] if it changed, 
+/// and the emptied one is sent off with a [`TerritoryDespawnRequest`].
+/// Requests across `Window`s, or whose combined rect would overlap another tiled [`Territory`], are thrown out.
+/// [`CardinalConnections`] catch up afterwards in [`rebuild_cardinal_connections`].
+pub fn combine_requested_territories (
+    mut commands: Commands,
+    mut combine_request_event: EventReader<CombineTerritoriesRequest>,
+    mut territory_despawn_request: EventWriter<TerritoryDespawnRequest>,
+    mut territory_moved_events: EventWriter<TerritoryMoved>,
+    window_query: Query<&Window, With<TerritoryTabs>>,
+    mut territory_query: Query<(Entity, &mut Territory, &Parent)>,
+    tiled_query: Query<(), (With<Territory>, TiledTerritory)>,
+    mut tab_query: Query<&mut Tab>
 ) {
-    for _event in remove_territories_key_pressed.read() {
-        for window_children in & window_query {
-            let mut territories = territory_query.iter_many(window_children);
-            while let Some(territory_entity) =  territories.fetch_next(){
-                despawn_territory_request.send(TerritoryDespawnRequest { despawned_territory: territory_entity });
+    for combine_event in combine_request_event.read() {
+        let Ok([(_, surviving, surviving_parent), (_, absorbed, absorbed_parent)]) 
+            = territory_query.get_many([combine_event.surviving, combine_event.absorbed]) else {
+            warn!("CombineTerritoriesRequest needs two different Territories! {:?} and {:?} not combined.", combine_event.surviving, combine_event.absorbed);
+            continue;
+        };
+        let window_entity = surviving_parent.get();
+        if absorbed_parent.get() != window_entity {
+            warn!("CombineTerritoriesRequest across Windows! {:?} and {:?} not combined.", combine_event.surviving, combine_event.absorbed);
+            continue;
+        }
+        let Ok(window) = window_query.get(window_entity) else {
+            error!("Failed to find Territory Tabs Window {:?} while combining Territories!", window_entity);
+            continue;
+        };
+
+        let window_rect = Rect::from_center_size(Vec2::ZERO, Vec2::new(window.width(), window.height()));
+        let others = territory_query.iter()
+            .filter(|(other_entity, _, other_parent)| {
+                other_parent.get() == window_entity
+                    && *other_entity != combine_event.surviving 
+                    && *other_entity != combine_event.absorbed
+                    && tiled_query.contains(*other_entity)
+            })
+            .map(|(_, other_territory, _)| other_territory);
+        let Some(combined_rect) = combined_territory_rect(surviving, absorbed, window_rect, others) else {
+            warn!("Combining {:?} and {:?} would overlap another Territory. Not combined.", combine_event.surviving, combine_event.absorbed);
+            continue;
+        };
+
+        let Ok([(_, mut surviving, _), (_, mut absorbed, _)]) 
+            = territory_query.get_many_mut([combine_event.surviving, combine_event.absorbed]) else {
+            continue;
+        };
+
+        // Only one active tab survives the merge.
+        let moved_tabs = std::mem::take(&mut absorbed.tabs);
+        if tab_query.iter_many(surviving.tabs()).any(|tab| tab.active) {
+            let mut moved_tab_iter = tab_query.iter_many_mut(&moved_tabs);
+            while let Some(mut moved_tab) = moved_tab_iter.fetch_next() {
+                if moved_tab.active {
+                    moved_tab.active = false;
+                }
             }
         }
+        commands.entity(combine_event.surviving).push_children(&moved_tabs);
+        surviving.tabs.extend(moved_tabs);
+        let old_expanse = surviving.expanse;
+        surviving.expanse.set_worldspace(combined_rect, window.width(), window.height());
+        if old_expanse.worldspace() != surviving.expanse.worldspace() {
+            territory_moved_events.send(TerritoryMoved {
+                territory: combine_event.surviving,
+                window: window_entity,
+                old: old_expanse,
+                new: surviving.expanse
+            });
+        }
+
+        territory_despawn_request.send(TerritoryDespawnRequest { despawned_territory: combine_event.absorbed });
+        debug!("Combined Territory {:?} into {:?}.", combine_event.absorbed, combine_event.surviving);
     }
 }
 
+/// Handles all [`AddTabRequest`], spawning the [`Tab`]s under their [`Territory`] and keeping exactly one of them active.
+pub fn add_requested_tabs (
+    mut commands: Commands,
+    mut add_tab_request_event: EventReader<AddTabRequest>,
+    mut territory_query: Query<&mut Territory>,
+    mut tab_query: Query<&mut Tab>
+) {
+    // Group requests by Territory first, so several tabs added in the same frame still end up with one active tab.
+    let mut requested_tabs: Vec<(Entity, Vec<Tab>)> = Vec::new();
+    for add_event in add_tab_request_event.read() {
+        if !territory_query.contains(add_event.territory) {
+            warn!("AddTabRequest for {:?}, which is not a Territory! Tab not added.", add_event.territory);
+            continue;
+        }
+        match requested_tabs.iter_mut().find(|(territory_entity, _)| *territory_entity == add_event.territory) {
+            Some((_, new_tabs)) => new_tabs.push(add_event.tab.clone()),
+            None => requested_tabs.push((add_event.territory, vec![add_event.tab.clone()]))
+        }
+    }
 
+    for (territory_entity, mut new_tabs) in requested_tabs {
+        let Ok(mut territory) = territory_query.get_mut(territory_entity) else {
+            continue;
+        };
 
+        // Settle which tab ends up active.
+        let mut has_active_tab = tab_query.iter_many(territory.tabs()).any(|tab| tab.active);
+        let mut new_active_index = None;
+        for (index, new_tab) in new_tabs.iter().enumerate() {
+            if new_tab.active || !has_active_tab {
+                new_active_index = Some(index);
+                has_active_tab = true;
+            }
+        }
+        if let Some(new_active_index) = new_active_index {
+            let mut existing_tabs = tab_query.iter_many_mut(territory.tabs());
+            while let Some(mut existing_tab) = existing_tabs.fetch_next() {
+                if existing_tab.active {
+                    existing_tab.active = false;
+                }
+            }
+            for (index, new_tab) in new_tabs.iter_mut().enumerate() {
+                new_tab.active = index == new_active_index;
+            }
+        }
 
+        for new_tab in new_tabs {
+            let new_tab_entity = commands.spawn((
+                Name::new(format!("[TAB] {}", new_tab.name)),
+                new_tab
+            )).id();
+            commands.entity(territory_entity).add_child(new_tab_entity);
+            territory.tabs.push(new_tab_entity);
+        }
+    }
+}
 
+/// Handles all [`RemoveTabRequest`], despawning the [`Tab`] and picking a new active tab for its [`Territory`] if needed.
+pub fn remove_requested_tabs (
+    mut commands: Commands,
+    mut remove_tab_request_event: EventReader<RemoveTabRequest>,
+    mut territory_query: Query<&mut Territory>,
+    mut tab_query: Query<(&mut Tab, &Parent)>
+) {
+    for remove_event in remove_tab_request_event.read() {
+        let Ok((removed_tab, tab_parent)) = tab_query.get(remove_event.tab) else {
+            warn!("RemoveTabRequest for {:?}, which is not a Tab with a Territory! Nothing removed.", remove_event.tab);
+            continue;
+        };
+        let was_active = removed_tab.active;
 
+        let Ok(mut territory) = territory_query.get_mut(tab_parent.get()) else {
+            warn!("RemoveTabRequest for {:?}, whose parent is not a Territory! Nothing removed.", remove_event.tab);
+            continue;
+        };
+        let Some(removed_index) = territory.tabs.iter().position(|tab_entity| *tab_entity == remove_event.tab) else {
+            continue;
+        };
 
+        territory.tabs.remove(removed_index);
+        commands.entity(remove_event.tab).despawn_recursive();
 
+        // Prefer the tab that slid into the removed tab's place, otherwise the one before it.
+        if was_active && !territory.has_no_tabs() {
+            let new_active_entity = territory.tabs[removed_index.min(territory.tabs.len() - 1)];
+            if let Ok((mut new_active_tab, _)) = tab_query.get_mut(new_active_entity) {
+                new_active_tab.active = true;
+            }
+        }
+    }
+}
 
+/// Handles all [`MoveTabRequest`], reparenting the [`Tab`] and settling the active tab of both [`Territory`]s.
+pub fn move_requested_tabs (
+    mut commands: Commands,
+    mut move_tab_request_event: EventReader<MoveTabRequest>,
+    mut territory_query: Query<&mut Territory>,
+    mut tab_query: Query<(&mut Tab, &Parent)>
+) {
+    for move_event in move_tab_request_event.read() {
+        let Ok((moved_tab, tab_parent)) = tab_query.get(move_event.tab) else {
+            warn!("MoveTabRequest for {:?}, which is not a Tab with a Territory! Nothing moved.", move_event.tab);
+            continue;
+        };
+        let was_active = moved_tab.active;
+        let origin_entity = tab_parent.get();
+        if origin_entity == move_event.territory {
+            debug!("MoveTabRequest for {:?} into the Territory it's already in. Nothing moved.", move_event.tab);
+            continue;
+        }
 
+        let Ok([mut origin, mut destination]) = territory_query.get_many_mut([origin_entity, move_event.territory]) else {
+            warn!("MoveTabRequest from {:?} to {:?} needs two Territories! Nothing moved.", origin_entity, move_event.territory);
+            continue;
+        };
+        let Some(moved_index) = origin.tabs.iter().position(|tab_entity| *tab_entity == move_event.tab) else {
+            continue;
+        };
+        origin.tabs.remove(moved_index);
 
+        // Same pick as a removed tab: the one that slid into its place, otherwise the one before it.
+        if was_active && !origin.has_no_tabs() {
+            let new_active_entity = origin.tabs[moved_index.min(origin.tabs.len() - 1)];
+            if let Ok((mut new_active_tab, _)) = tab_query.get_mut(new_active_entity) {
+                new_active_tab.active = true;
+            }
+        }
 
+        let mut destination_tabs = tab_query.iter_many_mut(destination.tabs());
+        while let Some((mut destination_tab, _)) = destination_tabs.fetch_next() {
+            if destination_tab.active {
+                destination_tab.active = false;
+            }
+        }
+        if let Ok((mut moved_tab, _)) = tab_query.get_mut(move_event.tab) {
+            moved_tab.active = true;
+        }
+        commands.entity(move_event.territory).add_child(move_event.tab);
+        destination.tabs.push(move_event.tab);
+    }
+}
 
+/// Handles all [`SelectTab`], activating the selected [`Tab`] and deactivating every other one in its [`Territory`].
+pub fn select_requested_tabs (
+    mut select_tab_event: EventReader<SelectTab>,
+    territory_query: Query<&Territory>,
+    mut tab_query: Query<&mut Tab>
+) {
+    for select_event in select_tab_event.read() {
+        let Ok(territory) = territory_query.get(select_event.territory) else {
+            warn!("SelectTab for {:?}, which is not a Territory! No tab selected.", select_event.territory);
+            continue;
+        };
 
+        let selected_tab_entity = match select_event.selector {
+            TabSelector::Index(index) => territory.tabs().get(index).copied(),
+            TabSelector::Type(tab_type) => territory.tabs().iter()
+                .find(|tab_entity| tab_query.get(**tab_entity).is_ok_and(|tab| tab.tab_type == tab_type))
+                .copied(),
+            TabSelector::Entity(tab_entity) => territory.tabs().contains(&tab_entity).then_some(tab_entity)
+        };
+        let Some(selected_tab_entity) = selected_tab_entity else {
+            warn!("SelectTab found no tab matching {:?} in {:?}. No tab selected.", select_event.selector, select_event.territory);
+            continue;
+        };
 
+        let mut territory_tabs = tab_query.iter_many_mut(territory.tabs());
+        while let Some(mut tab) = territory_tabs.fetch_next() {
+            if tab.active {
+                tab.active = false;
+            }
+        }
+        if let Ok(mut selected_tab) = tab_query.get_mut(selected_tab_entity) {
+            selected_tab.active = true;
+        }
+    }
+}
 
-
-
-/// Initial examination of all [`DragRequest`]s attached to [`Territory`] entities.  
-///   
-/// Other than the basic checks, the big operation here is to determine what [Territory]s
-/// are connected to this one directly or indirectly down the graph and add a [`DragTerritoryGroup`]
-/// marker component to them for ease of later processing. This is so collision logic
-/// can be run on all connected [`Territory`]s and they all appear to move as one connected whole.
-pub fn territory_drag_request_eval (
+/// When any node of a [`Territory`] is pressed, that [`Territory`] becomes the [`FocusedTerritory`] of its own `Window`, 
+/// and the [`Focused`] marker moves over to it.
+pub fn focus_interacted_territory (
     mut commands: Commands,
-    dragging_territory_query: Query<(Entity, &Territory, Option<&Locked>, &DragRequest)>,
-    potential_neighbor_query: Query<&CardinalConnections, With<Territory>>
+    focused_query: Query<Entity, With<Focused>>,
+    territory_query: Query<(Entity, &Territory, &Parent)>,
+    children_query: Query<&Children>,
+    interaction_query: Query<&Interaction, Changed<Interaction>>,
+    mut window_focus_query: Query<&mut FocusedTerritory>
 ) {
-    let Ok(
-        (territory_entity, territory, territory_locked, drag_request)
-        ) = dragging_territory_query.get_single() else {
-        error!("Drag request systems activated but drag query did not have single entity!");
-        return;
+    let is_pressed = |node_entity: Entity| {
+        matches!(interaction_query.get(node_entity), Ok(Interaction::Pressed))
     };
 
-    // Locked Territories don't move anywhere.
-    if territory_locked.is_some() {
-        debug!("Removed a DragRequest from a locked Territory!");
-        commands.entity(territory_entity).remove::<DragRequest>();
-        return;
+    let mut newly_focused = None;
+    for (territory_entity, territory, territory_parent) in & territory_query {
+        let drag_node_pressed = territory.drag_node().is_some_and(is_pressed);
+        let resize_button_pressed = territory.resize_node()
+            .and_then(|resize_node_entity| children_query.get(resize_node_entity).ok())
+            .is_some_and(|resize_buttons| resize_buttons.iter().any(|resize_button| is_pressed(*resize_button)));
+
+        if !drag_node_pressed && !resize_button_pressed {
+            continue;
+        }
+
+        let Ok(mut window_focus) = window_focus_query.get_mut(territory_parent.get()) else {
+            warn!("Interacted Territory's parent has no FocusedTerritory. Is it in a Territory Tabs window?");
+            continue;
+        };
+        if window_focus.get() != Some(territory_entity) {
+            window_focus.0 = Some(territory_entity);
+        }
+        newly_focused = Some(territory_entity);
     }
 
-    // Catch any zero-movement requests.
-    if drag_request.proposed_expanse().worldspace() == territory.expanse().worldspace() {
-        debug!("Removed a zero-movement DragRequest from a Territory!");
-        commands.entity(territory_entity).remove::<DragRequest>();
+    // Only one Territory keeps the marker, even if several were pressed at once.
+    let Some(newly_focused) = newly_focused else {
         return;
+    };
+    for focused_entity in & focused_query {
+        if focused_entity != newly_focused {
+            commands.entity(focused_entity).remove::<Focused>();
+        }
     }
-    
-    // Depth first traversal to collect all territory entities connected to the one with the DragRequest.
-    let mut to_be_traversed_entities: Vec<Entity> = Vec::new();
-    let mut collected_entities: Vec<Entity> = Vec::new();
-
-    // Add the OG DragRequest Territory to the stack.
-    to_be_traversed_entities.push(territory_entity);
-    debug!("[DFS] Added DragRequest Territory to stack.");
-
-    // Find all connections and add them to the dragged territory group.
-    while let Some(current_entity) =  to_be_traversed_entities.pop() {
-        collected_entities.push(current_entity);
-        debug!("[DFS] Popped Territory off of the stack and added to visited.");
+    commands.entity(newly_focused).insert(Focused);
+}
 
-        commands.entity(current_entity).insert(DragTerritoryGroup);
+/// Refits every [`Territory`] in a resized `Window` to its new size, leaving the ones in other `Window`s alone. 
+/// The base nodes follow along in [`update_territory_base_node`].  
+/// \
+/// [`RelativeAnchored`] ones keep the fraction of the `Window` they took up before, and [`Maximized`] ones fill it again.
+/// The rest keep their **worldspace** rect, moved back inside [`GlobalTerritorySettings::outer_margins`] and only shrunk 
+/// if the `Window` got too small for them.
+pub fn refit_territories_to_resized_window (
+    territory_settings: Res<GlobalTerritorySettings>,
+    mut window_resized_events: EventReader<WindowResized>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut territory_query: Query<(&mut Territory, Has<RelativeAnchored>, Has<Maximized>)>
+) {
+    let resized_windows: HashSet<Entity> = window_resized_events.read()
+        .map(|window_resized| window_resized.window)
+        .collect();
 
-        let Ok(current_connections) = potential_neighbor_query.get(current_entity) else {
-            error!("[DFS] CardinalConnections component get error!");
+    for window_entity in resized_windows {
+        let Ok((window, window_children)) = window_query.get(window_entity) else {
             continue;
         };
+        let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
 
-        for next_entity in current_connections.get_all_vec() {
-            if collected_entities.contains(&next_entity) { 
-                debug!("[DFS] Popped Territory neighbor already visited.");
-                continue; 
+        let mut territories = territory_query.iter_many_mut(window_children);
+        while let Some((mut territory, relative_anchored, maximized)) = territories.fetch_next() {
+            if maximized {
+                territory.expanse = RectKit::from_worldspace(window_rect, window.width(), window.height());
+                continue;
+            }
+            if relative_anchored {
+                territory.expanse = RectKit::from_relative_screenspace(territory.expanse.relative_screenspace(), window.width(), window.height());
+                continue;
             }
-            to_be_traversed_entities.push(next_entity);
-            debug!("[DFS] Popped Territory neighbor pushed to stack.");
+            let mut refitted_expanse = RectKit::from_worldspace(territory.expanse.worldspace(), window.width(), window.height());
+            refitted_expanse.fit_within_worldspace(window_rect, window.width(), window.height());
+            territory.expanse = refitted_expanse;
         }
     }
 }
 
-/// Initial examination of all [`ResizeRequest`]s attached to [`Territory`] entities.  
-///   
-/// Basic sanity checks and a depth first traversal to find connected [`Territory`]s
-/// with similar and opposite resizing, to be marked with [`AdvancingTerritoryGroup`] and [`RetreatingTerritoryGroup`].
-pub fn territory_resize_request_eval (
+/// Moves the [`Focused`] marker, and the [`FocusedTerritory`] of its `Window`, to the nearest neighbor 
+/// in the direction of a just pressed [`DevControls::FocusNorth`] and friends.  
+/// \
+/// Neighbors come from the [`TerritoryGraph`]. A [`Territory`] without connections that way falls back to 
+/// the nearest one past that side in the same `Window`. Focus stays put if there's nothing there. 
+/// With nothing [`Focused`] yet, it starts from the [`FocusedTerritory`] of the focused OS `Window`.
+pub fn focus_neighbor_territory (
     mut commands: Commands,
-    resizing_territory_query: Query<(Entity, &Territory, &CardinalConnections, Option<&Locked>, &ResizeRequest)>,
-    potential_neighbor_query: Query<(&CardinalConnections, &Territory, Option<&Locked>), Without<ResizeRequest>>
+    dev_controls: Option<Res<ActionState<DevControls>>>,
+    territory_graph: TerritoryGraph,
+    focused_query: Query<Entity, With<Focused>>,
+    territory_query: Query<(Entity, &Territory, &Parent)>,
+    mut window_focus_query: Query<(Entity, &Window, &mut FocusedTerritory)>
 ) {
-    let Ok(
-        (territory_entity, territory, initial_connections, territory_locked, resize_request)
-        ) = resizing_territory_query.get_single() else {
-        error!("Resize request systems activated but resize query did not have single entity!");
+    let Some(dev_controls) = dev_controls else {
         return;
     };
-
-    // Locked Territories don't change size.
-    if territory_locked.is_some() {
-        debug!("Removed a ResizeRequest from a locked Territory!");
-        commands.entity(territory_entity).remove::<ResizeRequest>();
+    let Some((_, direction)) = DevControls::FOCUS_DIRECTIONS.into_iter()
+        .find(|(action, _)| dev_controls.just_pressed(action)) else {
         return;
-    }
+    };
 
-    // Catch any zero-movement requests. These are common on ResizeRequests when the user drags parallel to the resize bar.
-    if resize_request.proposed_expanse().worldspace() == territory.expanse().worldspace() {
-        commands.entity(territory_entity).remove::<ResizeRequest>();
+    let start_entity = focused_query.iter().next().or_else(|| {
+        window_focus_query.iter()
+            .find(|(_, window, _)| window.focused)
+            .and_then(|(_, _, window_focus)| window_focus.get())
+    });
+    let Some((start_entity, start_territory, start_parent)) = start_entity.and_then(|start_entity| territory_query.get(start_entity).ok()) else {
         return;
-    }
-
-    // If our OG DragRequesting Territory is a corner or other multi-side resize with a retreating side,
-    // there is a possibility of collisions between the OG's connecting Territories.
-    // More efficient to handle this special case here and now rather than later.
-    // Thankfully, only the OG territory will do any multi-side resizing. Any downstream effects are all one-sided.
-    if resize_request.resize_direction().is_multi_side_resize() && resize_request.resize_direction().has_any_retreating() {
-
-        // Collection of screenspace neighbor rects modified by the impending resize, to be checked for collisions.
-        let mut neighbor_rects: Vec<Rect> = Vec::new();
+    };
 
-        // For each basic direction our special multi-side resize affects:
-        for cardinal_direction in resize_request.resize_direction().get_cardinal_directions() {
+    let target_entity = territory_graph.nearest_neighbor(start_entity, direction).or_else(|| {
+        nearest_territory_past_side(
+            start_territory.expanse.worldspace(),
+            direction,
+            territory_query.iter()
+                .filter(|(other_entity, _, other_parent)| *other_entity != start_entity && other_parent.get() == start_parent.get())
+                .map(|(other_entity, other_territory, _)| (other_entity, other_territory.expanse.worldspace()))
+        )
+    });
+    let Some(target_entity) = target_entity else {
+        debug!("No Territory to move focus to in direction {:?}.", direction);
+        return;
+    };
 
-            // Get all entities connected to that specific basic direction.
-            let neighbor_entities = initial_connections.get_resize_direction_vec(cardinal_direction);
+    let Ok((_, _, mut window_focus)) = window_focus_query.get_mut(start_parent.get()) else {
+        warn!("Focused Territory's parent has no FocusedTerritory. Is it in a Territory Tabs window?");
+        return;
+    };
+    window_focus.0 = Some(target_entity);
+    for focused_entity in & focused_query {
+        commands.entity(focused_entity).remove::<Focused>();
+    }
+    commands.entity(target_entity).insert(Focused);
+}
 
-            // For each of these entity's territories:
-            for (_, checked_territory, _) in potential_neighbor_query.iter_many(neighbor_entities) {
+/// Finds the **worldspace** [`Rect`] past the side of `worldspace` in a cardinal [`ResizeDirection`] with the nearest facing side. 
+/// Ties go to whichever is closer to lining up with `worldspace`. Other directions find nothing.
+fn nearest_territory_past_side(
+    worldspace: Rect,
+    direction: ResizeDirection,
+    others: impl Iterator<Item = (Entity, Rect)>
+) -> Option<Entity> {
+    let tolerance = CardinalConnections::ADJACENCY_TOLERANCE;
+    others
+        .filter_map(|(other_entity, other_rect)| {
+            // Gap between the facing sides, and how far off the centers are across it.
+            let (gap, offset) = match direction {
+                ResizeDirection::North {..} => (other_rect.min.y - worldspace.max.y, other_rect.center().x - worldspace.center().x),
+                ResizeDirection::East {..} => (other_rect.min.x - worldspace.max.x, other_rect.center().y - worldspace.center().y),
+                ResizeDirection::South {..} => (worldspace.min.y - other_rect.max.y, other_rect.center().x - worldspace.center().x),
+                ResizeDirection::West {..} => (worldspace.min.x - other_rect.max.x, other_rect.center().y - worldspace.center().y),
+                _ => return None
+            };
+            (gap >= -tolerance).then_some((other_entity, gap, offset.abs()))
+        })
+        .min_by(|(_, gap_a, offset_a), (_, gap_b, offset_b)| gap_a.total_cmp(gap_b).then(offset_a.total_cmp(offset_b)))
+        .map(|(other_entity, _, _)| other_entity)
+}
 
-                // Push the modifed rect, noting that the connecting rect will have opposite border movement.
-                neighbor_rects.push(cardinal_direction.get_opposite().apply_to_rect(checked_territory.expanse().screenspace()));
+/// Keeps [`WindowTouches`] up to date, so touches can stand in for the cursor.
+pub fn track_window_touches (
+    mut touch_input_events: EventReader<TouchInput>,
+    mut window_touches: ResMut<WindowTouches>
+) {
+    for touch_input in touch_input_events.read() {
+        window_touches.update(touch_input);
+    }
+}
 
+/// Clears the [`FocusedTerritory`] of any `Window` whose focused [`Territory`] went away.
+pub fn clear_removed_territory_focus (
+    mut removed_territories: RemovedComponents<Territory>,
+    mut window_focus_query: Query<&mut FocusedTerritory>
+) {
+    for removed_territory in removed_territories.read() {
+        for mut window_focus in &mut window_focus_query {
+            if window_focus.get() == Some(removed_territory) {
+                window_focus.0 = None;
             }
         }
+    }
+}
 
-        // Check unique pairs of the modifed rects for collisions.
-        // There are many options for what to do if a collision occurs.
-        // The least annoying option for the user is to cancel the ResizeRequest.
-        for (index, rect1) in neighbor_rects.iter().enumerate() {
-            for rect2 in &neighbor_rects[index + 1..] {
-                if rect1.intersect(*rect2).is_empty() { 
-                    continue; 
-                }
-                else { 
-                    commands.entity(territory_entity).remove::<ResizeRequest>(); 
-                    return;
-                }
-            }
+/// Two presses on the same [`Territory`]'s drag node within [`Maximized::DOUBLE_CLICK_SECONDS`] 
+/// send a [`ToggleMaximizeTerritory`] for it.
+pub fn maximize_on_drag_node_double_click (
+    time: Res<Time>,
+    territory_query: Query<(Entity, &Territory)>,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<TerritoryDragNode>)>,
+    mut toggle_maximize_event: EventWriter<ToggleMaximizeTerritory>,
+    mut last_press: Local<Option<(Entity, f32)>>
+) {
+    let now = time.elapsed_seconds();
+    for (territory_entity, territory) in & territory_query {
+        let drag_node_pressed = territory.drag_node()
+            .is_some_and(|drag_node_entity| matches!(interaction_query.get(drag_node_entity), Ok(Interaction::Pressed)));
+        if !drag_node_pressed {
+            continue;
         }
 
+        match *last_press {
+            Some((last_entity, last_time)) if last_entity == territory_entity && now - last_time <= Maximized::DOUBLE_CLICK_SECONDS => {
+                toggle_maximize_event.send(ToggleMaximizeTerritory { territory: territory_entity });
+                *last_press = None;
+            },
+            _ => { *last_press = Some((territory_entity, now)); }
+        }
     }
+}
 
-    // For easier interaction with Locked territories, 
-    // it's best to have an individual DFS per cardinal direction for multi-side resizing.
-    for cardinal_direction in resize_request.resize_direction().get_cardinal_directions() {
+/// Handles all [`ToggleMaximizeTerritory`]. A [`Territory`] is either grown to fill its `Window` inside 
+/// [`GlobalTerritorySettings::outer_margins`], or put back where it was before.  
+/// \
+/// The `Window` may have been resized while the [`Territory`] was [`Maximized`], 
+/// so the restored expanse is shrunk and shifted as needed to stay inside it.
+pub fn toggle_maximized_territories (
+    mut commands: Commands,
+    mut toggle_maximize_event: EventReader<ToggleMaximizeTerritory>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<&Window, With<TerritoryTabs>>,
+    mut territory_query: Query<(&mut Territory, &Parent, Option<&Maximized>)>
+) {
+    for toggle_event in toggle_maximize_event.read() {
+        let Ok((mut territory, territory_parent, maximized)) = territory_query.get_mut(toggle_event.territory) else {
+            warn!("ToggleMaximizeTerritory for {:?}, which is not a Territory in a Window! Nothing toggled.", toggle_event.territory);
+            continue;
+        };
+        let Ok(window) = window_query.get(territory_parent.get()) else {
+            warn!("ToggleMaximizeTerritory for {:?}, which is not in a Territory Tabs window! Nothing toggled.", toggle_event.territory);
+            continue;
+        };
+        let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
+
+        match maximized {
+            None => {
+                commands.entity(toggle_event.territory)
+                    .insert(Maximized { previous_expanse: territory.expanse })
+                    .remove::<MoveRequest>();
+                territory.expanse.set_worldspace(window_rect, window.width(), window.height());
+            },
+            Some(maximized) => {
+                let mut restored_expanse = RectKit::from_worldspace(maximized.previous_expanse().worldspace(), window.width(), window.height());
+                restored_expanse.fit_within_worldspace(window_rect, window.width(), window.height());
+                territory.expanse = restored_expanse;
+                commands.entity(toggle_event.territory).remove::<Maximized>();
+            }
+        }
+    }
+}
+
+/// Docks a [`Territory`] to a side of its `Window` by marking it [`Docked`]. 
+/// Once the [`Commands`] are applied, [`dock_territories`] stretches it along that side and moves the others out of the way.
+pub fn dock_territory(commands: &mut Commands, territory_entity: Entity, side: DockSide) {
+    match commands.get_entity(territory_entity) {
+        Some(mut territory_commands) => { territory_commands.insert(Docked(side)); },
+        None => warn!("Tried to dock {:?} to the {:?} side, but it doesn't exist!", territory_entity, side)
+    }
+}
+
+/// Undocks a [`Territory`], so it can be dragged and resized freely again. It stays where it was docked until then.
+pub fn undock_territory(commands: &mut Commands, territory_entity: Entity) {
+    if let Some(mut territory_commands) = commands.get_entity(territory_entity) {
+        territory_commands.remove::<Docked>();
+    }
+}
+
+/// Docks the [`Focused`] [`Territory`] to its nearest `Window` edge when [`DevControls::ToggleDock`] is just pressed, 
+/// or undocks it if it's already [`Docked`].
+pub fn toggle_dock_focused_territory (
+    mut commands: Commands,
+    dev_controls: Option<Res<ActionState<DevControls>>>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<&Window, With<TerritoryTabs>>,
+    focused_query: Query<(Entity, &Territory, &Parent, Has<Docked>), With<Focused>>
+) {
+    if !dev_controls.is_some_and(|dev_controls| dev_controls.just_pressed(&DevControls::ToggleDock)) {
+        return;
+    }
+    for (territory_entity, territory, territory_parent, docked) in & focused_query {
+        if docked {
+            undock_territory(&mut commands, territory_entity);
+            continue;
+        }
+        let Ok(window) = window_query.get(territory_parent.get()) else {
+            continue;
+        };
+        let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
+        dock_territory(&mut commands, territory_entity, DockSide::nearest(territory.expanse.worldspace(), window_rect));
+    }
+}
+
+/// Lays out every [`Docked`] [`Territory`] in a `Window` whenever one of them changes, which includes the `Window` being resized. 
+/// Each keeps its thickness and spans its whole side, inside [`GlobalTerritorySettings::outer_margins`].  
+/// \
+/// Panels on the same side stack in the order they already sit from the edge, and a newly docked one goes innermost. 
+/// Undocking one restacks the rest, so the panels inside it move out to fill the gap. 
+/// Other tiled [`Territory`]s overlapping a dock get a [`MoveRequest`] pulling their facing side back out of it, 
+/// or sliding them away whole if that would take them under their minimum size. [`Locked`] ones are left alone.
+#[allow(clippy::too_many_arguments)]
+pub fn dock_territories (
+    mut commands: Commands,
+    mut undocked_territories: RemovedComponents<Docked>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut docked_query: Query<(Entity, &mut Territory, Ref<Docked>)>,
+    other_query: Query<(Entity, &Territory, Option<&MinSizeOverride>), Without<Docked>>,
+    tiled_query: Query<(), TiledTerritory>,
+    locked_query: Query<(), With<Locked>>,
+    moving_query: Query<(), With<MoveRequest>>
+) {
+    let undocked_entities: Vec<Entity> = undocked_territories.read().collect();
 
-        // Depth first traversal like drag, but we only care about connections that share an opposing advancing or retreating border.
-        let mut to_be_traversed_entities: Vec<(ResizeDirection, Entity)> = Vec::new();
-        let mut collected_entities: Vec<(ResizeDirection, Entity)> = Vec::new();
-
-        // Push OG territory's cardinal side to stack
-        to_be_traversed_entities.push((cardinal_direction, territory_entity));
-        debug!("[DFS] Added OG ResizeRequest Territory side {:?} to stack.", cardinal_direction);
-
-        // Find the connections who will be affected by the ResizeRequest.
-        // Mark them as part of an advancing or retreating group of territories.
-        while let Some((resize_direction, current_entity)) =  to_be_traversed_entities.pop() {
-            // We've visited this territory's side, so add to list of ones we've already seen.
-            collected_entities.push((resize_direction, current_entity));
-            debug!("[DFS] Popped Territory with side {:?} off stack and added to visited.", resize_direction);
-
-            // Get the connections of the just-popped territory, and see if they're locked too. 
-            let Ok((current_connections, _, locked
-            )) = potential_neighbor_query.get(current_entity) else {
-                // Failure here would mean a more broad-scoped component error.
-                error!("[DFS] CardinalConnections component get error!");
+    for (window, window_children) in & window_query {
+        let mut docked_territories = Vec::new();
+        let mut any_changed = window_children.iter().any(|child_entity| undocked_entities.contains(child_entity));
+        let mut docked_iter = docked_query.iter_many_mut(window_children);
+        while let Some((territory_entity, territory, docked)) = docked_iter.fetch_next() {
+            if !tiled_query.contains(territory_entity) {
                 continue;
-            };
+            }
+            any_changed |= territory.is_changed() || docked.is_changed();
+            docked_territories.push((territory_entity, docked.0, territory.expanse.worldspace(), docked.is_changed()));
+        }
+        if !any_changed {
+            continue;
+        }
 
-            // A locked territory means this entire side's resize chain is invalid. 
-            // But, any other cardinal directions could still be valid, so we can't remove the ResizeRequest entirely.
-            // Instead, remove all group components from the collection of visited entities and bail.
-            if locked.is_some() {
-                for (visited_direction, visited_entity) in collected_entities {
-                    match visited_direction.get_single_magnitude() {
-                        ResizeMagnitude::None => { 
-                            warn!("{:?} somehow in collection of DFS visited entities??", ResizeMagnitude::None);
-                        }
-                        ResizeMagnitude::Advancing(_) => {
-                            commands.entity(visited_entity).remove::<AdvancingTerritoryGroup>();
-                        }
-                        ResizeMagnitude::Retreating(_) => {
-                            commands.entity(visited_entity).remove::<RetreatingTerritoryGroup>();
-                        }
-                    }
+        let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
+        docked_territories.sort_by(|(_, side_a, rect_a, new_a), (_, side_b, rect_b, new_b)| {
+            new_a.cmp(new_b).then(side_a.distance(*rect_a, window_rect).total_cmp(&side_b.distance(*rect_b, window_rect)))
+        });
+        let mut free_space = window_rect;
+        let mut docked_rects = Vec::new();
+        for side in DockSide::LAYOUT_ORDER {
+            for (territory_entity, _, worldspace, _) in docked_territories.iter().filter(|(_, docked_side, _, _)| *docked_side == side) {
+                let docked_rect = side.dock(side.thickness(*worldspace), &mut free_space, territory_settings.outer_margins);
+                docked_rects.push((side, docked_rect));
+                let Ok((_, mut territory, _)) = docked_query.get_mut(*territory_entity) else {
+                    continue;
+                };
+                if territory.expanse.worldspace() != docked_rect {
+                    territory.expanse.set_worldspace(docked_rect, window.width(), window.height());
                 }
-                break;
             }
+        }
 
-            // Add to group depending on resize magnitude.
-            match resize_direction.get_single_magnitude() {
-                ResizeMagnitude::None => { warn!("Popped resize territory had {:?}!", ResizeMagnitude::None) }
-                ResizeMagnitude::Advancing(_) => { 
-                    commands.entity(current_entity).insert(AdvancingTerritoryGroup(resize_direction)); 
-                }
-                ResizeMagnitude::Retreating(_) => {
-                    commands.entity(current_entity).insert(RetreatingTerritoryGroup(resize_direction));
+        for (other_entity, other_territory, min_size_override) in other_query.iter_many(window_children) {
+            if !tiled_query.contains(other_entity) || locked_query.contains(other_entity) || moving_query.contains(other_entity) {
+                continue;
+            }
+            let min_size = territory_settings.min_size_for(min_size_override, Vec2::new(window.width(), window.height()));
+            let worldspace = other_territory.expanse.worldspace();
+            let mut cleared_rect = worldspace;
+            for (side, docked_rect) in &docked_rects {
+                let blocked_rect = territory_settings.with_outer_margins(*docked_rect);
+                if !cleared_rect.intersect(blocked_rect).is_empty() {
+                    cleared_rect = side.clear(cleared_rect, blocked_rect, min_size);
                 }
             }
+            if cleared_rect != worldspace {
+                commands.entity(other_entity).insert(MoveRequest::new(
+                    RectKit::from_worldspace(cleared_rect, window.width(), window.height()),
+                    MoveRequestType::Unknown
+                ));
+            }
+        }
+    }
+}
 
-            // Add relevant connections to the stack to be popped later. We'll need the opposite ResizeDirection:
-            let opposite_direction = resize_direction.get_opposite();
-            for next_entity in current_connections.get_resize_direction_vec(resize_direction) {
-                if collected_entities.contains(&(opposite_direction, next_entity)) { 
-                    debug!("[DFS] Popped Territory neighbor already visited.");
-                    continue; 
+/// Turns every [`ApplyLayoutDiff`] into the spawn, despawn, and move requests that carry it out.  
+/// \
+/// Resizes are sent as [`MoveRequestType::Unknown`] so the [`ResizeDirection`] gets inferred, 
+/// which means a resize moving two opposite sides of a [`Territory`], without moving every side, is thrown out.
+pub fn apply_layout_diff (
+    mut commands: Commands,
+    mut apply_layout_diff_event: EventReader<ApplyLayoutDiff>,
+    mut territory_spawn_request_event: EventWriter<TerritorySpawnRequest>,
+    mut territory_despawn_request_event: EventWriter<TerritoryDespawnRequest>,
+    window_query: Query<&Window>,
+    territory_query: Query<&Parent, With<Territory>>
+) {
+    for apply_event in apply_layout_diff_event.read() {
+        let layout_diff = &apply_event.diff;
+
+        for removed_territory in &layout_diff.removed {
+            territory_despawn_request_event.send(TerritoryDespawnRequest { despawned_territory: *removed_territory });
+        }
+
+        for (_, added_entry) in &layout_diff.added {
+            let Ok(window) = window_query.get(added_entry.window_entity) else {
+                warn!("LayoutDiff adds a Territory to {:?}, which is not a Window. Skipped!", added_entry.window_entity);
+                continue;
+            };
+            territory_spawn_request_event.send(TerritorySpawnRequest {
+                window_entity: added_entry.window_entity,
+                expanse: RectKit::from_relative_worldspace(added_entry.relative_worldspace, window.width(), window.height()),
+                relative_screenspace: None,
+                display_library: added_entry.display_library,
+                resizable_edges: added_entry.resizable_edges
+            });
+        }
+
+        let moves = layout_diff.moved.iter().map(|(territory_entity, relative_worldspace)| 
+            (territory_entity, relative_worldspace, MoveRequestType::Drag));
+        let resizes = layout_diff.resized.iter().map(|(territory_entity, relative_worldspace)| 
+            (territory_entity, relative_worldspace, MoveRequestType::Unknown));
+        for (territory_entity, relative_worldspace, move_type) in moves.chain(resizes) {
+            let Some(window) = territory_query.get(*territory_entity).ok()
+                .and_then(|territory_parent| window_query.get(territory_parent.get()).ok()) else {
+                warn!("LayoutDiff moves {:?}, which is not a Territory in a Window. Skipped!", territory_entity);
+                continue;
+            };
+            commands.entity(*territory_entity).insert(MoveRequest::new(
+                RectKit::from_relative_worldspace(*relative_worldspace, window.width(), window.height()),
+                move_type
+            ));
+        }
+    }
+}
+
+/// Replaces every [`Territory`] in a `Window` with a new layout in one call, handy for tests and presets.  
+/// \
+/// Each entry is a relative **screenspace** [`Rect`], from `(0.0, 0.0)` at top left to `(1.0, 1.0)` at bottom right, 
+/// and the [`DisplayLibrary`] to show it with. Overlaps are resolved in order: an entry overlapping an earlier one 
+/// is cut back on whichever side keeps the most of it, and is skipped if there's nothing left.  
+/// \
+/// Nothing happens right away. Once the [`Commands`] are applied, a [`TerritoryDespawnRequest`] goes out for every existing 
+/// [`Territory`] in the `Window`, followed by a [`TerritorySpawnRequest`] for each entry, so the new layout is spawned in one frame.
+pub fn apply_layout(commands: &mut Commands, window_entity: Entity, layout: &[(Rect, DisplayLibrary)]) {
+    let mut resolved_layout: Vec<(Rect, DisplayLibrary)> = Vec::new();
+    'layout: for (relative_screenspace, display_library) in layout {
+        let mut resolved_rect = *relative_screenspace;
+        for (earlier_rect, _) in &resolved_layout {
+            if resolved_rect.intersect(*earlier_rect).is_empty() { continue; }
+
+            // Every way of cutting back one side far enough to clear the earlier entry.
+            let mut cut_rects: Vec<Rect> = Vec::new();
+            for axis in 0..2 {
+                if earlier_rect.max[axis] < resolved_rect.max[axis] {
+                    let mut cut_rect = resolved_rect;
+                    cut_rect.min[axis] = earlier_rect.max[axis];
+                    cut_rects.push(cut_rect);
+                }
+                if earlier_rect.min[axis] > resolved_rect.min[axis] {
+                    let mut cut_rect = resolved_rect;
+                    cut_rect.max[axis] = earlier_rect.min[axis];
+                    cut_rects.push(cut_rect);
                 }
+            }
+            let Some(cut_rect) = cut_rects.into_iter()
+                .max_by(|rect_a, rect_b| rect_a.size().element_product().total_cmp(&rect_b.size().element_product())) else {
+                warn!("Layout entry {:?} is covered by an earlier one, and was skipped!", relative_screenspace);
+                continue 'layout;
+            };
+            resolved_rect = cut_rect;
+        }
+        resolved_layout.push((resolved_rect, *display_library));
+    }
+
+    commands.add(move |world: &mut World| {
+        let mut territory_query = world.query_filtered::<(Entity, &Parent), With<Territory>>();
+        let existing_territories: Vec<Entity> = territory_query.iter(world)
+            .filter(|(_, territory_parent)| territory_parent.get() == window_entity)
+            .map(|(territory_entity, _)| territory_entity)
+            .collect();
+        for existing_territory in existing_territories {
+            world.send_event(TerritoryDespawnRequest { despawned_territory: existing_territory });
+        }
+
+        for (relative_screenspace, display_library) in resolved_layout {
+            world.send_event(TerritorySpawnRequest::from_relative(window_entity, relative_screenspace, display_library));
+        }
+        debug!("Applied a layout to {:?}.", window_entity);
+    });
+}
+
+/// Moves every tiled [`Territory`] in a `Window` by the [`PanWindowLayout`] delta at once, 
+/// skipping the [`MoveRequest`] pipeline since nothing inside the group can collide.  
+/// \
+/// [`Locked`], [`RelativeAnchored`], and [`Docked`] [`Territory`]s stay put, and the delta is cut short 
+/// so the group stops [`GlobalTerritorySettings::outer_margins`] away from the first one it would run into. 
+/// [`Floating`], [`Overlay`], and [`Maximized`] ones sit above the layout, so they stay put without getting in the way.
+pub fn pan_window_layout (
+    mut pan_window_layout_event: EventReader<PanWindowLayout>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<(&Window, &Children)>,
+    mut territory_query: Query<(&mut Territory, Has<Locked>, Has<RelativeAnchored>, Has<Docked>), TiledTerritory>
+) {
+    for pan_event in pan_window_layout_event.read() {
+        let Ok((window, window_children)) = window_query.get(pan_event.window) else {
+            warn!("Pan requested for {:?}, which is not a Window with Territories. Ignored!", pan_event.window);
+            continue;
+        };
 
-                // Push unvisited, relevant connection to stack.
-                to_be_traversed_entities.push((opposite_direction, next_entity));
-                debug!("[DFS] Popped Territory neighbor with side {:?} pushed to stack.", opposite_direction);
+        // Sort out what pans from what stays put. Tiled neighbors sit right on the margin, so a hair of rounding inside it doesn't count.
+        let mut group_rects: Vec<Rect> = Vec::new();
+        let mut fixed_rects: Vec<Rect> = Vec::new();
+        for (territory, is_locked, is_anchored, is_docked) in territory_query.iter_many(window_children) {
+            if is_locked || is_anchored || is_docked {
+                fixed_rects.push(territory_settings.with_outer_margins(territory.expanse.worldspace()).inflate(-PINNED_TOLERANCE));
             }
-        } 
+            else {
+                group_rects.push(territory.expanse.worldspace());
+            }
+        }
+
+        // Bounding box of everything that pans.
+        let Some(group_rect) = group_rects.iter().copied().reduce(|group_rect, territory_rect| group_rect.union(territory_rect)) else {
+            continue;
+        };
+
+        // Keep at least a sliver of the group on screen, so it can't be lost off the edge.
+        let window_rect = Rect::from_center_size(Vec2::ZERO, Vec2::new(window.width(), window.height()));
+        let visible_size = territory_settings.min_size.min(group_rect.size());
+        let clamped_delta = pan_event.delta
+            .max(window_rect.min + visible_size - group_rect.max)
+            .min(window_rect.max - visible_size - group_rect.min);
+
+        // Then stop short of the first Territory that stays put.
+        let pan_fraction = group_rects.iter()
+            .flat_map(|group_rect| fixed_rects.iter().filter_map(move |fixed_rect| pan_contact(*group_rect, clamped_delta, *fixed_rect)))
+            .fold(1.0, f32::min);
+        let clamped_delta = clamped_delta * pan_fraction;
+        if clamped_delta == Vec2::ZERO {
+            continue;
+        }
+
+        let mut territory_iter = territory_query.iter_many_mut(window_children);
+        while let Some((mut territory, is_locked, is_anchored, is_docked)) = territory_iter.fetch_next() {
+            if is_locked || is_anchored || is_docked {
+                continue;
+            }
+            territory.expanse.move_worldspace_pos(clamped_delta.x, clamped_delta.y, window.width(), window.height());
+        }
+    }
+}
+
+/// How far along `delta`, from `0.0` to `1.0`, the **worldspace** `moving_rect` gets before it first overlaps `fixed_rect`.  
+/// \
+/// Returns `None` if it never does, or if the two already overlap, since panning isn't what put them there.
+fn pan_contact(moving_rect: Rect, delta: Vec2, fixed_rect: Rect) -> Option<f32> {
+    let mut entry = f32::NEG_INFINITY;
+    let mut exit = f32::INFINITY;
+    for axis in 0..2 {
+        if delta[axis] == 0.0 {
+            // Never lines up along this axis, so they can't meet.
+            if moving_rect.max[axis] <= fixed_rect.min[axis] || moving_rect.min[axis] >= fixed_rect.max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let (near, far) = if delta[axis] > 0.0 {
+            (fixed_rect.min[axis] - moving_rect.max[axis], fixed_rect.max[axis] - moving_rect.min[axis])
+        } else {
+            (fixed_rect.max[axis] - moving_rect.min[axis], fixed_rect.min[axis] - moving_rect.max[axis])
+        };
+        entry = entry.max(near / delta[axis]);
+        exit = exit.min(far / delta[axis]);
     }
+    (entry < exit && (0.0..1.0).contains(&entry)).then_some(entry)
+}
 
+/// Everything that can go wrong in [`save_layout`] or [`load_layout`].
+#[cfg(feature = "serde")]
+#[derive(thiserror::Error, Debug)]
+pub enum LayoutFileError {
+    #[error("Could not access the layout file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not write the layout as RON: {0}")]
+    Serialize(#[from] ron::Error),
+    #[error("Could not read the layout RON: {0}")]
+    Deserialize(#[from] ron::error::SpannedError),
+    #[error("{0:?} is not a Territory Tabs window")]
+    NotTerritoryTabsWindow(Entity)
 }
 
-/// Modify [`DragRequest`]s that try to move any [`Territory`] tagged with [`DragTerritoryGroup`] beyond the window edge.
-///   
-/// This is better handled in **screenspace**.
-pub fn territory_drag_request_window_edge (
-    window_query: Query<&Window, With<TerritoryTabs>>,
-    mut drag_request_territory_query: Query<(&mut DragRequest, &Parent)>,
-    connected_territories_query: Query<&Territory, (With<DragTerritoryGroup>, Without<DragRequest>)>
+/// [`CardinalConnections`] from a loaded layout, waiting for their [`Territory`]s to spawn.  
+/// \
+/// Inserted by [`load_layout`] and consumed by [`restore_layout_connections`].
+#[cfg(feature = "serde")]
+#[derive(Resource)]
+pub struct PendingLayoutConnections {
+    /// The `Window` the layout was loaded into.
+    pub window_entity: Entity,
+    /// Each saved [`Territory`]'s relative **worldspace** [`Rect`] and connections, in saved order.
+    pub territories: Vec<(Rect, SavedConnections)>
+}
+
+/// Writes every [`Territory`] in a [`TerritoryTabs`] `Window` to a RON file at `path`.  
+/// \
+/// Each [`Territory`] is saved with its relative **worldspace** [`Rect`], [`DisplayLibrary`], 
+/// [`ResizableEdges`], and [`CardinalConnections`] to other [`Territory`]s in the same `Window`.
+#[cfg(feature = "serde")]
+pub fn save_layout(world: &mut World, window_entity: Entity, path: impl AsRef<std::path::Path>) -> Result<(), LayoutFileError> {
+    let mut window_query = world.query_filtered::<Option<&Children>, (With<Window>, With<TerritoryTabs>)>();
+    let Ok(window_children) = window_query.get(world, window_entity) else {
+        return Err(LayoutFileError::NotTerritoryTabsWindow(window_entity));
+    };
+    let window_children: Vec<Entity> = window_children.map(|children| children.to_vec()).unwrap_or_default();
+
+    let mut territory_query = world.query::<(Entity, &Territory, &DisplayLibrary, Option<&ResizableEdges>, Option<&CardinalConnections>)>();
+    let saved_entities: Vec<Entity> = territory_query.iter_many(world, &window_children)
+        .map(|(territory_entity, ..)| territory_entity)
+        .collect();
+
+    // Neighbors outside this Window can't be restored, so they're left out.
+    let to_indices = |neighbors: &[Entity]| -> Vec<usize> {
+        neighbors.iter()
+            .filter_map(|neighbor| saved_entities.iter().position(|saved_entity| saved_entity == neighbor))
+            .collect()
+    };
+
+    let saved_layout = SavedLayout {
+        territories: territory_query.iter_many(world, &window_children)
+            .map(|(_, territory, display_library, resizable_edges, connections)| {
+                let relative_worldspace = territory.expanse().relative_worldspace();
+                SavedTerritory {
+                    relative_worldspace: [
+                        relative_worldspace.min.x, 
+                        relative_worldspace.min.y, 
+                        relative_worldspace.max.x, 
+                        relative_worldspace.max.y
+                    ],
+                    display_library: *display_library,
+                    resizable_edges: resizable_edges.copied().unwrap_or_default(),
+                    connections: connections.map(|connections| SavedConnections {
+                        northern: to_indices(&connections.northern),
+                        eastern: to_indices(&connections.eastern),
+                        southern: to_indices(&connections.southern),
+                        western: to_indices(&connections.western)
+                    }).unwrap_or_default()
+                }
+            })
+            .collect()
+    };
+
+    let layout_ron = ron::ser::to_string_pretty(&saved_layout, ron::ser::PrettyConfig::default())?;
+    std::fs::write(path, layout_ron)?;
+    debug!("Saved {} Territories from {:?}.", saved_layout.territories.len(), window_entity);
+    Ok(())
+}
+
+/// Reads a RON layout file written by [`save_layout`] and sends a [`TerritorySpawnRequest`] 
+/// into the `Window` for every saved [`Territory`], returning how many were requested.  
+/// \
+/// Existing [`Territory`]s are left alone. Saved [`CardinalConnections`] are put back by 
+/// [`restore_layout_connections`] once the new [`Territory`]s spawn.
+#[cfg(feature = "serde")]
+pub fn load_layout(world: &mut World, window_entity: Entity, path: impl AsRef<std::path::Path>) -> Result<usize, LayoutFileError> {
+    let mut window_query = world.query_filtered::<&Window, With<TerritoryTabs>>();
+    let Ok(window) = window_query.get(world, window_entity) else {
+        return Err(LayoutFileError::NotTerritoryTabsWindow(window_entity));
+    };
+    let (window_width, window_height) = (window.width(), window.height());
+
+    let saved_layout: SavedLayout = ron::from_str(&std::fs::read_to_string(path)?)?;
+
+    for saved_territory in &saved_layout.territories {
+        world.send_event(TerritorySpawnRequest {
+            window_entity,
+            expanse: RectKit::from_relative_worldspace(saved_territory.relative_worldspace(), window_width, window_height),
+            relative_screenspace: None,
+            display_library: saved_territory.display_library,
+            resizable_edges: saved_territory.resizable_edges
+        });
+    }
+
+    if saved_layout.territories.iter().any(|saved_territory| !saved_territory.connections.is_empty()) {
+        world.insert_resource(PendingLayoutConnections {
+            window_entity,
+            territories: saved_layout.territories.iter()
+                .map(|saved_territory| (saved_territory.relative_worldspace(), saved_territory.connections.clone()))
+                .collect()
+        });
+    }
+
+    debug!("Requested {} Territories from a saved layout in {:?}.", saved_layout.territories.len(), window_entity);
+    Ok(saved_layout.territories.len())
+}
+
+/// Gives freshly loaded [`Territory`]s the [`CardinalConnections`] they were saved with.  
+/// \
+/// Loaded [`Territory`]s are found by their relative **worldspace** [`Rect`], since [`Entity`] IDs change on load. 
+/// Any saved [`Territory`] that didn't spawn is skipped, along with links to it. 
+/// Runs after [`rebuild_cardinal_connections`], so the rebuild for the new [`Territory`]s doesn't overwrite them.
+#[cfg(feature = "serde")]
+pub fn restore_layout_connections (
+    mut commands: Commands,
+    pending_connections: Res<PendingLayoutConnections>,
+    window_query: Query<&Children, With<TerritoryTabs>>,
+    territory_query: Query<(Entity, &Territory)>
 ) {
-    let Ok(
-        (mut drag_request, drag_parent_window)
-    ) = drag_request_territory_query.get_single_mut() else {
-        error!("Failed to find DragRequest entity with Window Parent!");  
+    commands.remove_resource::<PendingLayoutConnections>();
+
+    let Ok(window_children) = window_query.get(pending_connections.window_entity) else {
+        warn!("Saved layout connections were for {:?}, which has no Territories. Connections not restored.", pending_connections.window_entity);
         return;
     };
 
-    let Ok(window) = window_query.get(drag_parent_window.get()) else {
-        error!("Failed to find Window parent!");  
-        return;
+    let loaded_entities: Vec<Option<Entity>> = pending_connections.territories.iter()
+        .map(|(relative_worldspace, _)| territory_query.iter_many(window_children)
+            .find(|(_, territory)| {
+                let loaded_rect = territory.expanse().relative_worldspace();
+                loaded_rect.min.abs_diff_eq(relative_worldspace.min, LAYOUT_TOLERANCE) 
+                    && loaded_rect.max.abs_diff_eq(relative_worldspace.max, LAYOUT_TOLERANCE)
+            })
+            .map(|(territory_entity, _)| territory_entity))
+        .collect();
+
+    let to_entities = |indices: &[usize]| -> Vec<Entity> {
+        indices.iter().filter_map(|index| loaded_entities.get(*index).copied().flatten()).collect()
     };
-    let window_rect = Rect::from_corners(Vec2::ZERO, Vec2::new(window.width(), window.height()));
 
-    // Collection of screenspace rects to be checked. Optimize later.
-    let mut checked_screenspace_rects: Vec<Rect> = Vec::new();
-    checked_screenspace_rects.push(drag_request.proposed_expanse().screenspace());
+    for (loaded_entity, (_, saved_connections)) in loaded_entities.iter().zip(&pending_connections.territories) {
+        let Some(loaded_entity) = loaded_entity else {
+            warn!("A saved Territory never spawned, so its connections were not restored.");
+            continue;
+        };
+        commands.entity(*loaded_entity).insert(CardinalConnections {
+            northern: to_entities(&saved_connections.northern),
+            eastern: to_entities(&saved_connections.eastern),
+            southern: to_entities(&saved_connections.southern),
+            western: to_entities(&saved_connections.western)
+        });
+    }
+}
 
-    for connected_territory in & connected_territories_query {
-        let checked_rect = Rect::from_center_size(
-            connected_territory.expanse().screenspace().center() + drag_request.drag_delta(), 
-            connected_territory.expanse().screenspace().size()
-        );
-        checked_screenspace_rects.push(checked_rect);
+/// Run condition checking if a [`Territory`] spawned recently.
+pub fn territory_spawned (
+    added_query: Query<&Territory, Added<Territory>>
+) -> bool {
+    !added_query.is_empty()
+}
+
+/// Run condition checking if a [`Territory`] was removed recently.
+pub fn territory_removed (
+    removed_query: RemovedComponents<Territory>
+) -> bool {
+    !removed_query.is_empty()
+}
+
+/// Sent once a [`TerritoryTabsState`] transition has been applied, carrying both sides of it.  
+/// \
+/// Lets consumers react to every transition in one place, or pick out specific ones like 
+/// [`TerritoryTabsState::Natural`] -> [`TerritoryTabsState::Empty`], without an `OnEnter` system per variant.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct TerritoryTabsStateChanged {
+    /// State that was exited.
+    pub from: TerritoryTabsState,
+    /// State that was entered.
+    pub to: TerritoryTabsState
+}
+
+/// Forwards applied [`TerritoryTabsState`] transitions as [`TerritoryTabsStateChanged`] events.  
+/// \
+/// Reads Bevy's own transitions rather than the state machine systems' `NextState` calls, 
+/// so a state set twice in one frame is only announced once, as what actually happened. 
+/// The initial state and transitions back into the same state aren't announced. 
+/// Transitions that [`TerritoryTabsState::can_transition_to`] rules out are still announced, but logged as a warning.
+pub fn announce_territory_tabs_state_changes (
+    mut state_transition_events: EventReader<StateTransitionEvent<TerritoryTabsState>>,
+    mut state_changed_events: EventWriter<TerritoryTabsStateChanged>
+) {
+    for state_transition in state_transition_events.read() {
+        let (Some(from), Some(to)) = (&state_transition.exited, &state_transition.entered) else {
+            continue;
+        };
+        if from == to {
+            continue;
+        }
+        if from.can_transition_to(to) {
+            debug!("[MAIN STATE] {:?} -> {:?}", from, to);
+        }
+        else {
+            warn!("[MAIN STATE] Invalid transition: {:?} -> {:?}", from, to);
+        }
+        state_changed_events.send(TerritoryTabsStateChanged { from: from.clone(), to: to.clone() });
+    }
+}
+
+/// When a [`Territory`] component is removed, check to see if there are any left.
+/// Change [`TerritoryTabsState`] to [`TerritoryTabsState::Empty`] if so.
+/// Change it back to [`TerritoryTabsState::Natural`] when a new one is spawned.
+pub fn empty_if_no_territories (
+    territory_tabs_state: Res<State<TerritoryTabsState>>,
+    mut set_territory_tabs_state: ResMut<NextState<TerritoryTabsState>>,
+    territory_query: Query<&Territory>,
+) {
+    if territory_query.is_empty() {
+        match territory_tabs_state.get() {
+            TerritoryTabsState::Empty => { 
+                //warn!("Unexpected transition: Empty -> Empty"); 
+            }
+            TerritoryTabsState::Natural => { 
+                set_territory_tabs_state.set(TerritoryTabsState::Empty); 
+            }
+            // Loading a layout clears out the old Territories before spawning the new ones.
+            TerritoryTabsState::LoadingLayouts => {}
+            other_state => { 
+                set_territory_tabs_state.set(TerritoryTabsState::Empty);
+                warn!("Unexpected transition: {:?} -> Empty", other_state); 
+            }
+        }
+    }
+    else {
+        match territory_tabs_state.get() {
+            TerritoryTabsState::Empty => { set_territory_tabs_state.set(TerritoryTabsState::Natural); }
+            _ => {}
+        }
+    }
+}
+
+/// Moves [`TerritoryTabsState::Natural`] into [`TerritoryTabsState::DraggingTerritories`] or [`TerritoryTabsState::ResizingTerritories`] 
+/// when a drag or resize starts, and back once it ends.  
+/// \
+/// Follows [`MotionOrigin`]s rather than [`MoveRequest`]s, so a frame without a move in the middle of a drag doesn't drop back to Natural.
+pub fn track_territory_motion_state (
+    territory_tabs_state: Res<State<TerritoryTabsState>>,
+    mut set_territory_tabs_state: ResMut<NextState<TerritoryTabsState>>,
+    motion_origin_query: Query<&MotionOrigin>
+) {
+    let mut dragging = false;
+    let mut resizing = false;
+    for motion_origin in &motion_origin_query {
+        if motion_origin.resizing() {
+            resizing = true;
+        }
+        else {
+            dragging = true;
+        }
+    }
+
+    match territory_tabs_state.get() {
+        TerritoryTabsState::Natural if resizing => set_territory_tabs_state.set(TerritoryTabsState::ResizingTerritories),
+        TerritoryTabsState::Natural if dragging => set_territory_tabs_state.set(TerritoryTabsState::DraggingTerritories),
+        TerritoryTabsState::DraggingTerritories if !dragging => set_territory_tabs_state.set(TerritoryTabsState::Natural),
+        TerritoryTabsState::ResizingTerritories if !resizing => set_territory_tabs_state.set(TerritoryTabsState::Natural),
+        _ => {}
     }
+}
 
-    // Modify DragRequest's drag delta if one of the territories is found outside the window rect. 
-    for territory_rect in checked_screenspace_rects {
-        if window_rect.contains(territory_rect.min) && window_rect.contains(territory_rect.max) { continue; }
+/// Cancels every drag or resize with a [`MotionOrigin`], putting each [`Territory`] in its `Window` back where it started.  
+/// \
+/// Pending requests are dropped too, so neither this frame's move nor the pushes it would cause get applied.
+pub fn cancel_territory_motion (
+    mut commands: Commands,
+    mut motion_origin_query: Query<&mut MotionOrigin>,
+    mut territory_query: Query<&mut Territory>
+) {
+    for mut motion_origin in &mut motion_origin_query {
+        if motion_origin.canceled {
+            continue;
+        }
+
+        for (territory_entity, expanse) in &motion_origin.expanses {
+            let Ok(mut territory) = territory_query.get_mut(*territory_entity) else {
+                continue;
+            };
+            territory.expanse = *expanse;
+            commands.entity(*territory_entity).remove::<(MoveRequest, DragRequest, ResizeRequest, DeferredExpanse)>();
+        }
+        motion_origin.canceled = true;
+        debug!("Canceled a Territory drag or resize.");
+    }
+}
 
-        if territory_rect.min.x < 0.0 { drag_request.add_to_drag_delta_x(-1.0 * territory_rect.min.x); } // Left
-        if territory_rect.min.y < 0.0 { drag_request.add_to_drag_delta_y(-1.0 * territory_rect.min.y); } // Top
-        if territory_rect.max.x > window.width() { drag_request.add_to_drag_delta_x(window.width() - territory_rect.max.x); } // Right
-        if territory_rect.max.y > window.height() { drag_request.add_to_drag_delta_y(window.height() - territory_rect.max.y); } // Bottom
+/// Debug system Removes all entities with [`Territory`] when the dev key chord event is read..
+pub fn delete_all_territories (
+    mut remove_territories_key_pressed: EventReader<RemoveTerritoriesKeyPressed>,
+    mut despawn_territory_request:EventWriter<TerritoryDespawnRequest>,
+    window_query: Query<&Children, With<Window>>,
+    territory_query: Query<Entity, With<Territory>>
+) {
+    for _event in remove_territories_key_pressed.read() {
+        for window_children in & window_query {
+            let mut territories = territory_query.iter_many(window_children);
+            while let Some(territory_entity) =  territories.fetch_next(){
+                despawn_territory_request.send(TerritoryDespawnRequest { despawned_territory: territory_entity });
+            }
+        }
     }
 }
 
@@ -673,67 +1977,23 @@ pub fn territory_resize_request_check_minimums (
     // Apply to advancing if we're good. TODO
     
 }
-
-/// Handle [`ResizeRequest`]s that try to expand the [`Territory`] beyond the window edge.  
-///   
-/// This is better handled in **screenspace**.
-pub fn territory_resize_request_window_edge (
-    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
-    mut resizing_territories_query: Query<&ResizeRequest, With<Territory>>
-) {
-    for (window, window_children) in & window_query {
-
-        let mut resizing_territories = resizing_territories_query.iter_many_mut(window_children);
-
-        while let Some(resize_request) = resizing_territories.fetch_next() {
-
-            let (window_width, window_height) = (window.width(), window.height());
-
-            if resize_request.proposed_expanse().is_inside_screenspace_window(window_width, window_height) {
-                continue;
-            }
-
-            let window_rect = Rect::from_corners(Vec2::ZERO, Vec2::new(window_width, window_height));
-            let new_rect = window_rect.intersect(resize_request.proposed_expanse().screenspace());
-            resize_request.proposed_expanse().set_screenspace(new_rect, window_width, window_height);
-        }
-    }
-}
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
 
 /// Initial check of all [`Territory`]s who have a [`MoveRequest`] component and catch any odd requests.
-/// Any [`Locked`] [`Territory`]s will have their [`MoveRequest`] component removed.
+/// A [`MoveRequestType::Unknown`] gets its type worked out here, by comparing the proposed and current expanses.
+/// Any [`Locked`] [`Territory`]s will have their [`MoveRequest`] component removed, 
+/// as will any resizing a side their [`ResizableEdges`] disables. 
+/// [`Docked`] ones can't be dragged, and only their [`DockSide::inner_edge`] can be resized.
 pub fn territory_move_eval_type (
     mut commands: Commands,
     window_query: Query<&Children, (With<Window>, With<TerritoryTabs>)>,
-    mut moving_territories_query: Query<(Entity, &Territory, Option<&Locked>, &mut MoveRequest)>
+    mut moving_territories_query: Query<(Entity, &Territory, Option<&Locked>, &mut MoveRequest)>,
+    resizable_edges_query: Query<&ResizableEdges>,
+    docked_query: Query<&Docked>
 ) {
     for window_children in & window_query {
         let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
         while let Some(
-            (territory_entity, territory, territory_locked, move_request)
+            (territory_entity, territory, territory_locked, mut move_request)
         ) = moving_territories.fetch_next() {
 
             // A Locked Territory won't process any MoveRequest.
@@ -742,31 +2002,223 @@ pub fn territory_move_eval_type (
                 continue;
             }
 
-            if move_request.proposed_expanse.worldspace() == territory.expanse.worldspace() {
-                commands.entity(territory_entity).remove::<MoveRequest>();
-                debug!("MoveRequest found with identical rect to existing rect, and was removed!");
-                continue;
+            // Same size means it's only moving. Otherwise, figure out what sides moved.
+            if matches!(move_request.move_type, MoveRequestType::Unknown) {
+                let current_rect = territory.expanse.screenspace();
+                let proposed_rect = move_request.proposed_expanse.screenspace();
+
+                if current_rect.size().abs_diff_eq(proposed_rect.size(), f32::EPSILON * 100.0) {
+                    move_request.move_type_drag();
+                }
+                else if let Some(resize_direction) = ResizeDirection::from_screenspace_change(current_rect, proposed_rect) {
+                    move_request.move_type_resize(resize_direction);
+                }
+                else {
+                    warn!("Unknown-type MoveRequest changed opposite sides of a Territory without changing every side, and was removed!");
+                    commands.entity(territory_entity).remove::<MoveRequest>();
+                    continue;
+                }
+            }
+
+            // Neither will one being resized on a side that isn't resizable.
+            if let (MoveRequestType::Resize(resize_direction), Ok(resizable_edges)) 
+                = (&move_request.move_type, resizable_edges_query.get(territory_entity)) {
+                if !resizable_edges.allows(resize_direction) {
+                    commands.entity(territory_entity).remove::<MoveRequest>();
+                    debug!("MoveRequest found resizing a disabled edge, and was removed!");
+                    continue;
+                }
+            }
+
+            // Docked Territories are held to their side.
+            if let Ok(docked) = docked_query.get(territory_entity) {
+                let allowed = match &move_request.move_type {
+                    MoveRequestType::Resize(resize_direction) => ResizableEdges::from_direction(resize_direction) == docked.0.inner_edge(),
+                    _ => false
+                };
+                if !allowed {
+                    commands.entity(territory_entity).remove::<MoveRequest>();
+                    debug!("MoveRequest found moving a Docked Territory off its side, and was removed!");
+                    continue;
+                }
+            }
+
+            if move_request.proposed_expanse.worldspace() == territory.expanse.worldspace() {
+                commands.entity(territory_entity).remove::<MoveRequest>();
+                debug!("MoveRequest found with identical rect to existing rect, and was removed!");
+                continue;
+            }
+
+        }
+    }
+}
+
+/// Whether [`DevControls::IgnoreSnap`] is being held. Always `false` without the [`DevControls`].
+fn is_snap_ignored(dev_controls: Option<&ActionState<DevControls>>) -> bool {
+    dev_controls.is_some_and(|dev_controls| dev_controls.pressed(&DevControls::IgnoreSnap))
+}
+
+/// Snaps dragged [`Territory`]s up against the `Window` edge, one [`GlobalTerritorySettings::outer_margins`] away, 
+/// once one of their sides comes within [`GlobalTerritorySettings::edge_snap_distance`] of that, on either side.  
+/// \
+/// Each axis snaps on its own, to whichever edge is nearest if both are in range. 
+/// Drags already past the edge are left to [`territory_move_process_fringe`]. Nothing snaps while [`DevControls::IgnoreSnap`] is held.
+pub fn territory_move_snap_drag_to_window (
+    territory_settings: Res<GlobalTerritorySettings>,
+    dev_controls: Option<Res<ActionState<DevControls>>>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut moving_territories_query: Query<&mut MoveRequest, With<Territory>>
+) {
+    let snap_distance = territory_settings.edge_snap_distance;
+    if snap_distance <= 0.0 || is_snap_ignored(dev_controls.as_deref()) {
+        return;
+    }
+
+    for (window, window_children) in & window_query {
+        let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
+
+        let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
+        while let Some(mut move_request) = moving_territories.fetch_next() {
+            if !matches!(move_request.move_type, MoveRequestType::Drag) { continue; }
+            let proposed_rect = move_request.proposed_expanse.worldspace();
+
+            let mut snap_offset = Vec2::ZERO;
+            for axis in 0..2 {
+                // Gaps between each side and its window edge. Negative gaps are already past it.
+                let min_gap = proposed_rect.min[axis] - window_rect.min[axis];
+                let max_gap = window_rect.max[axis] - proposed_rect.max[axis];
+                let in_range = |gap: f32| gap > 0.0 && gap <= snap_distance;
+                snap_offset[axis] = match (in_range(min_gap), in_range(max_gap)) {
+                    (true, true) if max_gap < min_gap => max_gap,
+                    (true, _) => -min_gap,
+                    (false, true) => max_gap,
+                    (false, false) => 0.0
+                };
+            }
+
+            if snap_offset == Vec2::ZERO { continue; }
+            move_request.proposed_expanse.move_worldspace_pos(snap_offset.x, snap_offset.y, window.width(), window.height());
+        }
+    }
+}
+
+/// Snaps the moving sides of resizing [`Territory`]s to nearby sides of their neighbors, 
+/// within [`GlobalTerritorySettings::snap_distance`].  
+/// \
+/// A neighbor straight across from a moving side is snapped up against, leaving a [`GlobalTerritorySettings::outer_margins`] gap.
+/// A neighbor off to the side has its sides lined up with instead. Snaps that would overlap another [`Territory`] are skipped. 
+/// Nothing snaps while [`DevControls::IgnoreSnap`] is held.
+pub fn territory_move_snap_resize (
+    territory_settings: Res<GlobalTerritorySettings>,
+    dev_controls: Option<Res<ActionState<DevControls>>>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut moving_territories_query: Query<(Entity, &Territory, &mut MoveRequest), TiledTerritory>,
+    other_territories_query: Query<(Entity, &Territory), TiledTerritory>
+) {
+    if territory_settings.snap_distance <= 0.0 || is_snap_ignored(dev_controls.as_deref()) {
+        return;
+    }
+
+    // Moving side, axis it moves along, and whether it's the max side of the worldspace Rect on that axis.
+    let snap_sides = [
+        (ResizableEdges::NORTH, 1, true),
+        (ResizableEdges::EAST, 0, true),
+        (ResizableEdges::SOUTH, 1, false),
+        (ResizableEdges::WEST, 0, false)
+    ];
+
+    for (window, window_children) in & window_query {
+        let window_rects: Vec<(Entity, Rect)> = other_territories_query.iter_many(window_children)
+            .map(|(other_entity, other_territory)| (other_entity, other_territory.expanse.worldspace()))
+            .collect();
+
+        let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
+        while let Some((territory_entity, territory, mut move_request)) = moving_territories.fetch_next() {
+            let MoveRequestType::Resize(resize_direction) = move_request.move_type() else { continue; };
+            let resizing_edges = ResizableEdges::from_direction(&resize_direction);
+            let current_rect = territory.expanse.worldspace();
+            let mut snapped_rect = move_request.proposed_expanse.worldspace();
+            let neighbor_rects: Vec<Rect> = window_rects.iter()
+                .filter(|(other_entity, _)| *other_entity != territory_entity)
+                .map(|(_, other_rect)| *other_rect)
+                .collect();
+
+            for (side, axis, is_max_side) in snap_sides {
+                if !resizing_edges.contains(side) { continue; }
+                let cross_axis = 1 - axis;
+                let proposed_side = if is_max_side { snapped_rect.max[axis] } else { snapped_rect.min[axis] };
+
+                let mut closest_target: Option<f32> = None;
+                let mut closest_distance = territory_settings.snap_distance;
+                for neighbor_rect in &neighbor_rects {
+                    let is_across = snapped_rect.min[cross_axis] < neighbor_rect.max[cross_axis] 
+                        && neighbor_rect.min[cross_axis] < snapped_rect.max[cross_axis];
+                    let targets = if !is_across {
+                        [Some(neighbor_rect.min[axis]), Some(neighbor_rect.max[axis])]
+                    }
+                    else if is_max_side && neighbor_rect.min[axis] >= current_rect.max[axis] {
+                        [Some(neighbor_rect.min[axis] - territory_settings.outer_margins[axis]), None]
+                    }
+                    else if !is_max_side && neighbor_rect.max[axis] <= current_rect.min[axis] {
+                        [Some(neighbor_rect.max[axis] + territory_settings.outer_margins[axis]), None]
+                    }
+                    else {
+                        [None, None]
+                    };
+
+                    for target in targets.into_iter().flatten() {
+                        let distance = (target - proposed_side).abs();
+                        if distance <= closest_distance {
+                            closest_target = Some(target);
+                            closest_distance = distance;
+                        }
+                    }
+                }
+
+                let Some(target) = closest_target else { continue; };
+                let mut candidate_rect = snapped_rect;
+                if is_max_side { candidate_rect.max[axis] = target; } else { candidate_rect.min[axis] = target; }
+                if candidate_rect.size()[axis] > 0.0 
+                    && neighbor_rects.iter().all(|neighbor_rect| neighbor_rect.intersect(candidate_rect).is_empty()) {
+                    snapped_rect = candidate_rect;
+                }
             }
 
+            if snapped_rect == move_request.proposed_expanse.worldspace() { continue; }
+            move_request.proposed_expanse.set_worldspace(snapped_rect, window.width(), window.height());
+
+            // Keep the magnitudes in line with the snapped proposal.
+            if let Some(snapped_direction) = ResizeDirection::from_screenspace_change(
+                territory.expanse.screenspace(), 
+                move_request.proposed_expanse.screenspace()
+            ) {
+                move_request.move_type_resize(snapped_direction);
+            }
         }
     }
 }
 
 /// Process all [`Territory`] & [`MoveRequest`] interactions with the window edge.
-/// Clip off resizing proposals, move away dragging proposals.
+/// Clip off resizing proposals, move away dragging proposals.  
+/// \
+/// The window edge is inset by [`GlobalTerritorySettings::outer_margins`], so [`Territory`]s never sit flush against it.  
+/// \
+/// Resizing proposals are also held to [`GlobalTerritorySettings::min_size`], or the [`MinSizeOverride`]. Both axes are checked together 
+/// and grow back out from [`ResizeDirection::anchor_corner`], so a corner resize can't collapse either one. 
+/// A [`ResizeDirection::All`] resize grows back out evenly around its center.
+/// They're held to [`GlobalTerritorySettings::max_size`] the same way, shrinking back toward the anchored corner.
+/// A corner resize that can't change anything because both of its moving sides are pinned to the window becomes a drag instead.
 pub fn territory_move_process_fringe (
     mut commands: Commands,
+    territory_settings: Res<GlobalTerritorySettings>,
     window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
-    mut moving_territories_query: Query<(Entity, &mut MoveRequest), With<Territory>>
+    mut moving_territories_query: Query<(Entity, &Territory, &mut MoveRequest, Option<&MinSizeOverride>)>
 ) {
     for (window, window_children) in & window_query {
         let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
-        while let Some((territory_entity, mut move_request)) = moving_territories.fetch_next() {
+        while let Some((territory_entity, territory, mut move_request, min_size_override)) = moving_territories.fetch_next() {
             
-            let window_rect = Rect::from_center_size(
-                Vec2::ZERO, 
-                Vec2::new(window.width(),window.height())
-            );
+            let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
 
             match move_request.move_type() {
                 MoveRequestType::Unknown => {
@@ -774,48 +2226,66 @@ pub fn territory_move_process_fringe (
                     commands.entity(territory_entity).remove::<MoveRequest>(); // Get outta here!
                 },
                 MoveRequestType::Drag => {
-                    if window_rect.contains(move_request.proposed_expanse.worldspace().min)
-                    && window_rect.contains(move_request.proposed_expanse.worldspace().max) {continue;}
-    
-                    if move_request.proposed_expanse.worldspace().min.x < window_rect.min.x {
-                        let delta_x = window_rect.min.x - move_request.proposed_expanse.worldspace().min.x;
-                        move_request.proposed_expanse.move_worldspace_pos(
-                            delta_x,
-                            0.0,
-                            window.width(),
-                            window.height()
-                        );
-                    }
-                    if move_request.proposed_expanse.worldspace().min.y < window_rect.min.y {
-                        let delta_y = window_rect.min.y - move_request.proposed_expanse.worldspace().min.y;
-                        move_request.proposed_expanse.move_worldspace_pos(
-                            0.0,
-                            delta_y,
-                            window.width(),
-                            window.height()
-                        );
+                    move_request.proposed_expanse.fit_within_worldspace(window_rect, window.width(), window.height());
+                },
+                MoveRequestType::Resize(resize_direction) => {
+                    let mut inbounds_rect = window_rect.intersect(move_request.proposed_expanse.worldspace());
+
+                    // Only the moving sides give way, the anchored corner stays where it is.
+                    let anchor_corner = resize_direction.anchor_corner(inbounds_rect);
+                    let resizing_edges = ResizableEdges::from_direction(&resize_direction);
+                    let min_size = territory_settings.min_size_for(min_size_override, window_rect.size());
+                    if inbounds_rect.width() < min_size.x {
+                        match (resizing_edges.contains(ResizableEdges::WEST), resizing_edges.contains(ResizableEdges::EAST)) {
+                            (true, true) => {
+                                inbounds_rect.min.x = anchor_corner.x - min_size.x * 0.5;
+                                inbounds_rect.max.x = anchor_corner.x + min_size.x * 0.5;
+                            },
+                            (false, true) => inbounds_rect.max.x = anchor_corner.x + min_size.x,
+                            (true, false) => inbounds_rect.min.x = anchor_corner.x - min_size.x,
+                            (false, false) => {}
+                        }
                     }
-                    if move_request.proposed_expanse.worldspace().max.x > window_rect.max.x {
-                        let delta_x = window_rect.max.x - move_request.proposed_expanse.worldspace().max.x;
-                        move_request.proposed_expanse.move_worldspace_pos(
-                            delta_x,
-                            0.0,
-                            window.width(),
-                            window.height()
-                        );
+                    if inbounds_rect.height() < min_size.y {
+                        match (resizing_edges.contains(ResizableEdges::SOUTH), resizing_edges.contains(ResizableEdges::NORTH)) {
+                            (true, true) => {
+                                inbounds_rect.min.y = anchor_corner.y - min_size.y * 0.5;
+                                inbounds_rect.max.y = anchor_corner.y + min_size.y * 0.5;
+                            },
+                            (false, true) => inbounds_rect.max.y = anchor_corner.y + min_size.y,
+                            (true, false) => inbounds_rect.min.y = anchor_corner.y - min_size.y,
+                            (false, false) => {}
+                        }
                     }
-                    if move_request.proposed_expanse.worldspace().max.y > window_rect.max.y {
-                        let delta_y = window_rect.max.y - move_request.proposed_expanse.worldspace().max.y;
-                        move_request.proposed_expanse.move_worldspace_pos(
-                            0.0,
-                            delta_y,
-                            window.width(),
-                            window.height()
+                    let inbounds_rect = resizing_edges.clamp_to_max_size(inbounds_rect, territory_settings.max_size);
+
+                    // A corner with both of its moving sides pinned to the window would be a dead handle.
+                    // Pan toward the cursor with whatever the resize couldn't use instead.
+                    let current_rect = territory.expanse.worldspace();
+                    let pinned = |edge: ResizableEdges, territory_side: f32, window_side: f32| {
+                        !resizing_edges.contains(edge) || (territory_side - window_side).abs() < PINNED_TOLERANCE
+                    };
+                    if resizing_edges.bits().count_ones() == 2
+                        && pinned(ResizableEdges::NORTH, current_rect.max.y, window_rect.max.y)
+                        && pinned(ResizableEdges::EAST, current_rect.max.x, window_rect.max.x)
+                        && pinned(ResizableEdges::SOUTH, current_rect.min.y, window_rect.min.y)
+                        && pinned(ResizableEdges::WEST, current_rect.min.x, window_rect.min.x)
+                        && inbounds_rect.min.abs_diff_eq(current_rect.min, PINNED_TOLERANCE)
+                        && inbounds_rect.max.abs_diff_eq(current_rect.max, PINNED_TOLERANCE) {
+                        let proposed_rect = move_request.proposed_expanse.worldspace();
+                        let residual = Vec2::new(
+                            if resizing_edges.contains(ResizableEdges::EAST) { proposed_rect.max.x - current_rect.max.x } 
+                            else { proposed_rect.min.x - current_rect.min.x },
+                            if resizing_edges.contains(ResizableEdges::NORTH) { proposed_rect.max.y - current_rect.max.y } 
+                            else { proposed_rect.min.y - current_rect.min.y }
                         );
+                        move_request.proposed_expanse = territory.expanse;
+                        move_request.proposed_expanse.move_worldspace_pos(residual.x, residual.y, window.width(), window.height());
+                        move_request.move_type_drag();
+                        move_request.proposed_expanse.fit_within_worldspace(window_rect, window.width(), window.height());
+                        debug!("Pinned corner resize turned into a drag.");
+                        continue;
                     }
-                },
-                MoveRequestType::Resize(_) => {
-                    let inbounds_rect = window_rect.intersect(move_request.proposed_expanse.worldspace());
 
                     move_request.proposed_expanse.set_worldspace(
                         inbounds_rect, 
@@ -828,9 +2298,369 @@ pub fn territory_move_process_fringe (
     }
 }
 
+/// How close a [`Territory`] side has to be to the window edge to count as pinned against it.
+const PINNED_TOLERANCE: f32 = 0.01;
+
+/// Pre-pass for [`Territory`]s resizing into each other in the same frame.  
+/// \
+/// [`territory_move_check_others`] only checks a moving [`Territory`] against ones that aren't moving, 
+/// so two neighbors growing toward each other would never see each other and end up overlapping.
+/// Here the free space between every such pair, less [`GlobalTerritorySettings::outer_margins`], is split in proportion 
+/// to how far each one asked to grow, and both proposals are trimmed back to either side of that shared boundary. 
+/// Pairs are settled in [`Entity`] order, so the result doesn't depend on query order.
+pub fn territory_move_resolve_mutual_resize (
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut moving_territories_query: Query<(Entity, &Territory, &mut MoveRequest), TiledTerritory>
+) {
+    for (window, window_children) in & window_query {
+
+        // Current and proposed worldspace rects of every resizing Territory in this window.
+        let mut resizing: Vec<(Entity, Rect, Rect)> = moving_territories_query
+            .iter_many(window_children)
+            .filter(|(_, _, move_request)| matches!(move_request.move_type, MoveRequestType::Resize(_)))
+            .map(|(territory_entity, territory, move_request)| (
+                territory_entity, 
+                territory.expanse.worldspace(), 
+                move_request.proposed_expanse.worldspace()
+            ))
+            .collect();
+        if resizing.len() < 2 {
+            continue;
+        }
+        resizing.sort_by_key(|(territory_entity, _, _)| *territory_entity);
+
+        let mut any_resolved = false;
+        for first_index in 0..resizing.len() {
+            for second_index in (first_index + 1)..resizing.len() {
+                let (_, first_current, first_proposed) = resizing[first_index];
+                let (_, second_current, second_proposed) = resizing[second_index];
+
+                if territory_settings.with_outer_margins(first_proposed).intersect(second_proposed).is_empty() {
+                    continue;
+                }
+
+                // Find which side of each other they are on, worldspace so +y is up.
+                // Territories that already overlap are left for the other systems to sort out.
+                let Some((lower, upper, axis)) = [
+                    (first_current.max.x <= second_current.min.x, (first_index, second_index, 0)),
+                    (second_current.max.x <= first_current.min.x, (second_index, first_index, 0)),
+                    (first_current.max.y <= second_current.min.y, (first_index, second_index, 1)),
+                    (second_current.max.y <= first_current.min.y, (second_index, first_index, 1))
+                ]
+                    .into_iter()
+                    .find_map(|(is_separated, pair)| is_separated.then_some(pair)) else {
+                    continue;
+                };
+
+                let (_, lower_current, mut lower_proposed) = resizing[lower];
+                let (_, upper_current, mut upper_proposed) = resizing[upper];
+
+                let margin = territory_settings.outer_margins[axis];
+                let free_space = (upper_current.min[axis] - lower_current.max[axis] - margin).max(0.0);
+                let lower_growth = (lower_proposed.max[axis] - lower_current.max[axis]).max(0.0);
+                let upper_growth = (upper_current.min[axis] - upper_proposed.min[axis]).max(0.0);
+                if lower_growth + upper_growth <= free_space {
+                    continue;
+                }
+
+                let boundary = lower_current.max[axis] + free_space * lower_growth / (lower_growth + upper_growth);
+                lower_proposed.max[axis] = lower_proposed.max[axis].min(boundary);
+                upper_proposed.min[axis] = upper_proposed.min[axis].max(boundary + margin);
+
+                resizing[lower].2 = lower_proposed;
+                resizing[upper].2 = upper_proposed;
+                any_resolved = true;
+            }
+        }
+        if !any_resolved {
+            continue;
+        }
+
+        for (territory_entity, _, resolved_proposal) in resizing {
+            let Ok((_, _, mut move_request)) = moving_territories_query.get_mut(territory_entity) else {
+                continue;
+            };
+            if move_request.proposed_expanse.worldspace() != resolved_proposal {
+                move_request.proposed_expanse.set_worldspace(resolved_proposal, window.width(), window.height());
+            }
+        }
+    }
+}
+
+/// Carries every [`Territory`] linked to a dragged one, directly or further down the graph, along with the drag, 
+/// so they all move as one connected whole.  
+/// \
+/// Linked [`Territory`]s are found with a depth first traversal of [`CardinalConnections`] and marked with [`DragTerritoryGroup`], 
+/// then handed their own drag [`MoveRequest`] by the same distance. [`Locked`] ones stay put, and the traversal doesn't go through them.
+/// The distance is held back so the whole group stays inside the `Window`'s [`GlobalTerritorySettings::outer_margins`]. 
+/// A group drag that would come within a margin of any other tiled [`Territory`] is dropped for the frame, 
+/// rather than tearing the group apart.  
+/// \
+/// Only runs with [`TileMode`] on. Otherwise a drag moves just the dragged [`Territory`], and its links are rebuilt afterwards.
+#[allow(clippy::too_many_arguments)]
+pub fn territory_move_group_drags (
+    mut commands: Commands,
+    territory_settings: Res<GlobalTerritorySettings>,
+    tile_mode: Res<TileMode>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut moving_territories_query: Query<(Entity, &Territory, &mut MoveRequest), TiledTerritory>,
+    deferred_expanse_query: Query<&DeferredExpanse>,
+    connections_query: Query<&CardinalConnections>,
+    resting_territories_query: Query<(Entity, &Territory), (Without<MoveRequest>, TiledTerritory)>,
+    locked_query: Query<(), With<Locked>>
+) {
+    if !tile_mode.enabled {
+        return;
+    }
+
+    for (window, window_children) in & window_query {
+        let window_rect = territory_settings.margin_window_rect(window.width(), window.height());
+        // Drags are worked out from where each Territory will be, which is its deferred expanse if it has one.
+        let base_rect = |territory_entity: Entity, territory: &Territory| deferred_expanse_query.get(territory_entity)
+            .map_or(territory.expanse, DeferredExpanse::expanse)
+            .worldspace();
+
+        let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
+        while let Some((territory_entity, territory, mut move_request)) = moving_territories.fetch_next() {
+            if !matches!(move_request.move_type, MoveRequestType::Drag) { continue; }
+            let dragged_rect = base_rect(territory_entity, territory);
+            let mut drag_delta = move_request.proposed_expanse.worldspace().min - dragged_rect.min;
+            if drag_delta == Vec2::ZERO { continue; }
+
+            // Depth first traversal to collect everything linked to the dragged Territory.
+            let mut to_be_traversed_entities: Vec<Entity> = Vec::new();
+            let mut collected_entities: Vec<Entity> = vec![territory_entity];
+            let mut group: Vec<(Entity, Rect)> = Vec::new();
+            if let Ok(initial_connections) = connections_query.get(territory_entity) {
+                to_be_traversed_entities.extend(initial_connections.get_all_vec());
+            }
+            while let Some(current_entity) = to_be_traversed_entities.pop() {
+                if collected_entities.contains(&current_entity) {
+                    debug!("[DFS] Popped Territory neighbor already visited.");
+                    continue;
+                }
+                collected_entities.push(current_entity);
+
+                // Locked and already moving Territories aren't carried, and don't carry their own links either.
+                if locked_query.contains(current_entity) { continue; }
+                let Ok((_, current_territory)) = resting_territories_query.get(current_entity) else { continue; };
+                group.push((current_entity, base_rect(current_entity, current_territory)));
+
+                if let Ok(current_connections) = connections_query.get(current_entity) {
+                    to_be_traversed_entities.extend(current_connections.get_all_vec());
+                }
+            }
+            if group.is_empty() { continue; }
+
+            // Hold the drag back until the whole group fits in the Window.
+            let group_rect = group.iter().fold(dragged_rect, |group_rect, (_, member_rect)| group_rect.union(*member_rect));
+            let dragged_group_rect = Rect::from_corners(group_rect.min + drag_delta, group_rect.max + drag_delta);
+            for axis in 0..2 {
+                if dragged_group_rect.min[axis] < window_rect.min[axis] {
+                    drag_delta[axis] += window_rect.min[axis] - dragged_group_rect.min[axis];
+                }
+                else if dragged_group_rect.max[axis] > window_rect.max[axis] {
+                    drag_delta[axis] += window_rect.max[axis] - dragged_group_rect.max[axis];
+                }
+            }
+
+            // Anything else in the way would push the group apart, so leave it all where it is.
+            let moved = |rect: Rect| Rect::from_corners(rect.min + drag_delta, rect.max + drag_delta);
+            let is_blocked = resting_territories_query.iter_many(window_children)
+                .filter(|(other_entity, _)| group.iter().all(|(member_entity, _)| member_entity != other_entity))
+                .any(|(_, other_territory)| {
+                    let other_rect = territory_settings.with_outer_margins(other_territory.expanse.worldspace());
+                    !moved(dragged_rect).intersect(other_rect).is_empty() 
+                        || group.iter().any(|(_, member_rect)| !moved(*member_rect).intersect(other_rect).is_empty())
+                });
+            if is_blocked {
+                debug!("Group drag would run into another Territory, and was removed!");
+                commands.entity(territory_entity).remove::<MoveRequest>();
+                continue;
+            }
+
+            if moved(dragged_rect) != move_request.proposed_expanse.worldspace() {
+                move_request.proposed_expanse.set_worldspace(moved(dragged_rect), window.width(), window.height());
+            }
+            for (member_entity, member_rect) in group {
+                commands.entity(member_entity).insert((
+                    DragTerritoryGroup,
+                    MoveRequest::new(
+                        RectKit::from_worldspace(moved(member_rect), window.width(), window.height()), 
+                        MoveRequestType::Drag
+                    )
+                ));
+            }
+        }
+    }
+}
+
+/// Depth first traversal along the seam one side of a [`Territory`] is on, through [`CardinalConnections`].  
+/// \
+/// Gives every [`Entity`] along it, the starting one included, 
+/// paired with whether it's on the same side of the seam as the start, `true`, or facing it, `false`.
+fn seam_territories(
+    territory_entity: Entity, 
+    cardinal_direction: ResizeDirection, 
+    connections_query: &Query<&CardinalConnections>
+) -> Vec<(Entity, bool)> {
+    let mut to_be_traversed_entities: Vec<(Entity, bool)> = vec![(territory_entity, true)];
+    let mut collected_entities: Vec<(Entity, bool)> = Vec::new();
+
+    while let Some((current_entity, on_resizing_side)) = to_be_traversed_entities.pop() {
+        if collected_entities.contains(&(current_entity, on_resizing_side)) {
+            debug!("[DFS] Popped Territory side already visited.");
+            continue;
+        }
+        collected_entities.push((current_entity, on_resizing_side));
+
+        let Ok(current_connections) = connections_query.get(current_entity) else { continue; };
+
+        // The resizing side looks across the seam, and the side facing it looks back.
+        let facing_direction = if on_resizing_side { cardinal_direction } else { cardinal_direction.get_opposite() };
+        for next_entity in current_connections.get_resize_direction_vec(facing_direction) {
+            to_be_traversed_entities.push((next_entity, !on_resizing_side));
+        }
+    }
+    collected_entities
+}
+
+/// With [`TileMode`] on, resizing [`Territory`]s carry every [`Territory`] along the seams they're resizing.  
+/// \
+/// Each seam is found with a depth first traversal of [`CardinalConnections`] from a moving side: the neighbors linked across from it, 
+/// the [`Territory`]s linked back across from those, and so on. Every one of them moves its seam side by the same amount, 
+/// so growing one pane shrinks the panes across from it, and lines up the ones beside it. Those moving their side the same way as 
+/// the resizing side are marked with its [`ResizeMagnitude`]'s group, and those across from it with the other one, 
+/// [`AdvancingTerritoryGroup`] or [`RetreatingTerritoryGroup`], for the rest of the frame.  
+/// \
+/// The move is held to what every [`Territory`] it shrinks can give up before its minimum size, 
+/// and a [`Locked`] one anywhere along the seam stops that side entirely, so the resize stops instead of overlapping.
+/// Each [`Territory`] along the seam is handed its own resize [`MoveRequest`], so it's checked against the others 
+/// and applied, or deferred, along with the resizing one. Seams are worked out from the resting expanses every frame, 
+/// like everything else [`GlobalTerritorySettings::live_resize`] defers. 
+/// Only tiled [`Territory`]s are ever linked in [`CardinalConnections`], so [`Floating`] ones never follow.
+#[allow(clippy::too_many_arguments)]
+pub fn territory_move_tile_neighbors (
+    mut commands: Commands,
+    tile_mode: Res<TileMode>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
+    mut moving_territories_query: Query<(Entity, &Territory, &mut MoveRequest), TiledTerritory>,
+    connections_query: Query<&CardinalConnections>,
+    seam_query: Query<(&Territory, Option<&MinSizeOverride>), Without<MoveRequest>>,
+    locked_query: Query<(), With<Locked>>
+) {
+    if !tile_mode.enabled {
+        return;
+    }
+
+    for (window, window_children) in & window_query {
+        let window_size = Vec2::new(window.width(), window.height());
+
+        let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
+        while let Some((territory_entity, territory, mut move_request)) = moving_territories.fetch_next() {
+            let MoveRequestType::Resize(resize_direction) = move_request.move_type() else { continue; };
+            let current_rect = territory.expanse.worldspace();
+            let mut tiled_rect = move_request.proposed_expanse.worldspace();
+
+            for cardinal_direction in resize_direction.get_cardinal_directions() {
+                // Axis the side moves along, and whether it's the max side of the worldspace Rect on that axis.
+                let (axis, is_max_side) = match cardinal_direction {
+                    ResizeDirection::North {..} => (1, true),
+                    ResizeDirection::East {..} => (0, true),
+                    ResizeDirection::South {..} => (1, false),
+                    ResizeDirection::West {..} => (0, false),
+                    _ => {
+                        warn!("Resize direction should have been cardinal!");
+                        continue;
+                    }
+                };
+                let side_sign = if is_max_side { 1.0 } else { -1.0 };
+
+                // Positive when the side advances across the seam.
+                let proposed_side = if is_max_side { tiled_rect.max[axis] } else { tiled_rect.min[axis] };
+                let current_side = if is_max_side { current_rect.max[axis] } else { current_rect.min[axis] };
+                let mut advance = (proposed_side - current_side) * side_sign;
+                if advance == 0.0 { continue; }
+
+                // Hold the advance to what everything it shrinks along the seam can give up.
+                let seam = seam_territories(territory_entity, cardinal_direction, &connections_query);
+                for (seam_entity, on_resizing_side) in &seam {
+                    if *seam_entity == territory_entity { continue; }
+                    if locked_query.contains(*seam_entity) {
+                        advance = 0.0;
+                        break;
+                    }
+                    let Ok((seam_territory, min_size_override)) = seam_query.get(*seam_entity) else { continue; };
+                    let give = (seam_territory.expanse.worldspace().size()[axis] 
+                        - territory_settings.min_size_for(min_size_override, window_size)[axis]).max(0.0);
+                    advance = if *on_resizing_side { advance.max(-give) } else { advance.min(give) };
+                }
+                if is_max_side { tiled_rect.max[axis] = current_side + advance; } 
+                else { tiled_rect.min[axis] = current_side - advance; }
+                if advance == 0.0 { continue; }
+
+                // Seam sides all move the same worldspace distance, flipped to screenspace for the group markers.
+                let side_delta = advance * side_sign;
+                let mut screenspace_delta = Vec2::ZERO;
+                screenspace_delta[axis] = if axis == 1 { -side_delta } else { side_delta };
+
+                for (seam_entity, on_resizing_side) in seam {
+                    if seam_entity == territory_entity { continue; }
+                    let Ok((seam_territory, _)) = seam_query.get(seam_entity) else { continue; };
+
+                    // The resizing side's max side is across from the other side's min side.
+                    let mut seam_rect = seam_territory.expanse.worldspace();
+                    if on_resizing_side == is_max_side { seam_rect.max[axis] += side_delta; } else { seam_rect.min[axis] += side_delta; }
+
+                    let mut seam_direction = if on_resizing_side { cardinal_direction } else { cardinal_direction.get_opposite() };
+                    seam_direction.set_magnitudes_from_delta(screenspace_delta);
+                    match seam_direction.get_single_magnitude() {
+                        ResizeMagnitude::None => continue,
+                        ResizeMagnitude::Advancing(_) => {
+                            commands.entity(seam_entity).insert(AdvancingTerritoryGroup(seam_direction));
+                        },
+                        ResizeMagnitude::Retreating(_) => {
+                            commands.entity(seam_entity).insert(RetreatingTerritoryGroup(seam_direction));
+                        }
+                    }
+                    commands.entity(seam_entity).insert(MoveRequest::new(
+                        RectKit::from_worldspace(seam_rect, window.width(), window.height()), 
+                        MoveRequestType::Resize(seam_direction)
+                    ));
+                }
+            }
+
+            if tiled_rect == move_request.proposed_expanse.worldspace() { continue; }
+            if tiled_rect == current_rect {
+                debug!("Tiled resize MoveRequest was stopped along its seam, and was removed!");
+                commands.entity(territory_entity).remove::<MoveRequest>();
+                continue;
+            }
+            move_request.proposed_expanse.set_worldspace(tiled_rect, window.width(), window.height());
+
+            // Keep the magnitudes in line with the held back proposal.
+            if let Some(tiled_direction) = ResizeDirection::from_screenspace_change(
+                territory.expanse.screenspace(), 
+                move_request.proposed_expanse.screenspace()
+            ) {
+                move_request.move_type_resize(tiled_direction);
+            }
+        }
+    }
+}
+
 /// For all entities with [`Territory`] and a [`MoveRequest`], iterate through all conflicting [`Territory`]s.
 /// If we're resizing, see how much we can push away others. If dragging, move away from others.
-/// If there's still a conflict at the end, remove the [`MoveRequest`].
+/// If there's still a conflict at the end, remove the [`MoveRequest`].  
+/// \
+/// Pushed [`Territory`]s are handed their own resize [`MoveRequest`] instead of being moved here, 
+/// so they're applied, or deferred, along with the resizing one, the same as a [`TileMode`] seam.  
+/// \
+/// Every [`Territory`] is kept [`GlobalTerritorySettings::outer_margins`] away from the others, so pushes and shrinks leave that gap.  
+/// \
+/// [`Floating`] and [`Overlay`] [`Territory`]s sit outside the tiled layout, and are skipped on both sides.
 pub fn territory_move_check_others (
     mut commands: Commands,
     territory_settings: Res<GlobalTerritorySettings>,
@@ -838,13 +2668,19 @@ pub fn territory_move_check_others (
         (&Window, &Children), 
         With<TerritoryTabs>
         >,
-    mut moving_territories_query: Query<(Entity, &mut MoveRequest)>,
-    mut other_territories_query: Query<
-        (&mut Territory, Option<&Locked>), 
-        Without<MoveRequest>
+    mut moving_territories_query: Query<(Entity, &mut MoveRequest), TiledTerritory>,
+    other_territories_query: Query<
+        (Entity, &Territory, Option<&Locked>, Option<&MinSizeOverride>), 
+        (Without<MoveRequest>, TiledTerritory)
         >
 ) {
     for (window, window_children) in & window_query {
+        // Where each pushed Territory is pushed to so far, so later MoveRequests in the Window see it there.
+        let mut pushed_rects: HashMap<Entity, Rect> = HashMap::new();
+        let other_worldspace = |pushed_rects: &HashMap<Entity, Rect>, other_entity: Entity, other_territory: &Territory| {
+            pushed_rects.get(&other_entity).copied().unwrap_or(other_territory.expanse.worldspace())
+        };
+
         let mut moving_territories = moving_territories_query.iter_many_mut(window_children);
         while let Some(
             (territory_entity, mut move_request)
@@ -858,74 +2694,53 @@ pub fn territory_move_check_others (
                 },
 
                 MoveRequestType::Drag => {
-                    let mut other_territories = other_territories_query
-                        .iter_many_mut(window_children);
-                    while let Some(
-                        (other_territory, _is_locked)
-                    ) = other_territories.fetch_next() {
+                    for (other_entity, other_territory, _is_locked, _) in other_territories_query.iter_many(window_children) {
 
-                        let conflict_rect = move_request.proposed_expanse.worldspace()
-                            .intersect(other_territory.expanse.worldspace());
+                        // The other Territory's margins are part of what's in the way.
+                        let other_rect = territory_settings.with_outer_margins(other_worldspace(&pushed_rects, other_entity, other_territory));
+                        let conflict_rect = move_request.proposed_expanse.worldspace().intersect(other_rect);
                         if conflict_rect.is_empty() {continue;}
 
-                        // If the user goes nuts, they can drag Territories fast enough that the conflict rect
-                        // is entirely contained inside our Territory rect. Remaining space handles that case. Mostly.
-                        // TODO: Handle that case better than mostly.
-                        if conflict_rect.height() >= conflict_rect.width() {
-
-                            if move_request.proposed_expanse.worldspace().center().x 
-                            >= other_territory.expanse.worldspace().center().x {
-                                let remaining_space = other_territory.expanse.worldspace().max.x - conflict_rect.max.x;
-                                move_request.proposed_expanse.move_worldspace_pos(
-                                    conflict_rect.width() + remaining_space,
-                                    0.0,
-                                    window.width(),
-                                    window.height()
-                                );
-                            }
-                            else {
-                                let remaining_space = conflict_rect.min.x - other_territory.expanse.worldspace().min.x;
-                                move_request.proposed_expanse.move_worldspace_pos(
-                                    -1.0 * conflict_rect.width() - remaining_space,
-                                    0.0,
-                                    window.width(),
-                                    window.height()
-                                );
-                            }
-                        }
-                        else {
+                        // Push the proposal out past whichever side of the other Territory its center is toward. 
+                        // Going by centers instead of the conflict rect's shape still works when a fast drag 
+                        // swallows the other Territory whole. Offsets are scaled by the combined half sizes, 
+                        // so a wide or tall neighbor doesn't skew which side counts as nearest.
+                        let proposed_rect = move_request.proposed_expanse.worldspace();
+                        let combined_half_size = ((proposed_rect.size() + other_rect.size()) * 0.5).max(Vec2::splat(f32::EPSILON));
+                        let center_offset = (proposed_rect.center() - other_rect.center()) / combined_half_size;
+                        let conflict_angle = center_offset.y.atan2(center_offset.x);
 
-                            if move_request.proposed_expanse.worldspace().center().y 
-                            >= other_territory.expanse.worldspace().center().y {
-                                let remaining_space = other_territory.expanse.worldspace().max.y - conflict_rect.max.y;
-                                move_request.proposed_expanse.move_worldspace_pos(
-                                    0.0,
-                                    conflict_rect.height() + remaining_space,
-                                    window.width(),
-                                    window.height()
-                                );
-                            }
-                            else {
-                                let remaining_space = conflict_rect.min.y - other_territory.expanse.worldspace().min.y;
-                                move_request.proposed_expanse.move_worldspace_pos(
-                                    0.0,
-                                    -1.0 * conflict_rect.height() - remaining_space,
-                                    window.width(),
-                                    window.height()
-                                );
-                            } 
+                        // Right
+                        let push = if (-FRAC_PI_4..=FRAC_PI_4).contains(&conflict_angle) {
+                            Vec2::new(other_rect.max.x - proposed_rect.min.x, 0.0)
+                        }
+                        // Top
+                        else if (FRAC_PI_4..=3.0 * FRAC_PI_4).contains(&conflict_angle) {
+                            Vec2::new(0.0, other_rect.max.y - proposed_rect.min.y)
                         }
+                        // Down
+                        else if (-3.0 * FRAC_PI_4..=-FRAC_PI_4).contains(&conflict_angle) {
+                            Vec2::new(0.0, other_rect.min.y - proposed_rect.max.y)
+                        }
+                        // Left (atan2 is discontinuous at PI, as its range is -PI to PI)
+                        else {
+                            Vec2::new(other_rect.min.x - proposed_rect.max.x, 0.0)
+                        };
+                        move_request.proposed_expanse.move_worldspace_pos(
+                            push.x,
+                            push.y,
+                            window.width(),
+                            window.height()
+                        );
                     }
 
                     // Swing through again and verify no conflicts remain. If there are conflicts, remove MoveRequest.
-                    let mut other_territories = other_territories_query
-                        .iter_many_mut(window_children);
-                    while let Some(
-                        (other_territory, _is_locked)
-                    ) = other_territories.fetch_next() {
+                    // Pushes land right on the margin, so a hair of rounding inside it doesn't count.
+                    for (other_entity, other_territory, _is_locked, _) in other_territories_query.iter_many(window_children) {
 
-                        let conflict_rect = move_request.proposed_expanse.worldspace()
-                            .intersect(other_territory.expanse.worldspace());
+                        let other_rect = territory_settings.with_outer_margins(other_worldspace(&pushed_rects, other_entity, other_territory))
+                            .inflate(-PINNED_TOLERANCE);
+                        let conflict_rect = move_request.proposed_expanse.worldspace().intersect(other_rect);
                         if !conflict_rect.is_empty() {
                             warn!("Drag-type MoveRequest still found conflicts after processing. MoveRequest removed!");
                             commands.entity(territory_entity).remove::<MoveRequest>();
@@ -933,15 +2748,21 @@ pub fn territory_move_check_others (
                     }
                 },
 
-                MoveRequestType::Resize(_) => {
-                    let mut other_territories = other_territories_query
-                        .iter_many_mut(window_children);
-                    while let Some(
-                        (other_territory, is_locked)
-                    ) = other_territories.fetch_next() {
+                MoveRequestType::Resize(resize_direction) => {
+                    // Hold to max size before anything gets pushed around.
+                    let clamped_rect = ResizableEdges::from_direction(&resize_direction)
+                        .clamp_to_max_size(move_request.proposed_expanse.worldspace(), territory_settings.max_size);
+                    if clamped_rect != move_request.proposed_expanse.worldspace() {
+                        move_request.proposed_expanse.set_worldspace(clamped_rect, window.width(), window.height());
+                    }
+
+                    for (other_entity, other_territory, is_locked, min_size_override) in other_territories_query.iter_many(window_children) {
+                        let other_min_size = territory_settings.min_size_for(min_size_override, Vec2::new(window.width(), window.height()));
+                        let other_rect = other_worldspace(&pushed_rects, other_entity, other_territory);
                             
+                        // Conflicts include the other Territory's margins, so it's pared back or shrunk that much further.
                         let conflict_rect = move_request.proposed_expanse.worldspace()
-                            .intersect(other_territory.expanse.worldspace());
+                            .intersect(territory_settings.with_outer_margins(other_rect));
                         if conflict_rect.is_empty() {continue;}
 
                         // Find the conflict_rect's sector, which determines what direction we pared back proposed resize.
@@ -966,7 +2787,7 @@ pub fn territory_move_check_others (
                             }
 
                             let conflict_overreach = conflict_rect.width()
-                                - (other_territory.expanse.worldspace().width() - territory_settings.min_size.x);
+                                - (other_rect.width() - other_min_size.x);
 
                             if conflict_overreach > 0.0 {
                                 move_request.proposed_expanse.move_worldspace_corners(
@@ -990,7 +2811,7 @@ pub fn territory_move_check_others (
                             }
 
                             let conflict_overreach = conflict_rect.height()
-                                - (other_territory.expanse.worldspace().height() - territory_settings.min_size.y);
+                                - (other_rect.height() - other_min_size.y);
 
                             if conflict_overreach > 0.0 {
                                 move_request.proposed_expanse.move_worldspace_corners(
@@ -1015,7 +2836,7 @@ pub fn territory_move_check_others (
                             }
 
                             let conflict_overreach = conflict_rect.width()
-                                - (other_territory.expanse.worldspace().width() - territory_settings.min_size.x);
+                                - (other_rect.width() - other_min_size.x);
 
                             if conflict_overreach > 0.0 {
                                 move_request.proposed_expanse.move_worldspace_corners(
@@ -1039,7 +2860,7 @@ pub fn territory_move_check_others (
                             }
 
                             let conflict_overreach = conflict_rect.height()
-                                - (other_territory.expanse.worldspace().height() - territory_settings.min_size.y);
+                                - (other_rect.height() - other_min_size.y);
 
                             if conflict_overreach > 0.0 {
                                 move_request.proposed_expanse.move_worldspace_corners(
@@ -1053,21 +2874,18 @@ pub fn territory_move_check_others (
                     }
 
                     // Now that the MoveRequest knows what its final size can be, we push away other territories using this final size.
-                    let mut other_territories = other_territories_query
-                        .iter_many_mut(window_children);
-                    while let Some(
-                        (mut other_territory, _is_locked)
-                    ) = other_territories.fetch_next() {
+                    for (other_entity, other_territory, _is_locked, _) in other_territories_query.iter_many(window_children) {
+                        let mut other_rect = other_worldspace(&pushed_rects, other_entity, other_territory);
 
-                        let conflict_rect = move_request.proposed_expanse.worldspace()
-                            .intersect(other_territory.expanse.worldspace());
+                        let conflict_rect = territory_settings.with_outer_margins(move_request.proposed_expanse.worldspace())
+                            .intersect(other_rect);
                         if conflict_rect.is_empty() {continue;}
 
                         // Find the conflict_rect's sector, which determines what direction we resize the other Territory.
                         let conflict_angle = (
-                            other_territory.expanse.worldspace().center().y - conflict_rect.center().y)
+                            other_rect.center().y - conflict_rect.center().y)
                             .atan2(
-                            other_territory.expanse.worldspace().center().x - conflict_rect.center().x);
+                            other_rect.center().x - conflict_rect.center().x);
 
                         // Second run-through to push other Territories out of our, now valid, resize MoveRequest.
                         // Don't forget to invert the direction of resize, 
@@ -1075,77 +2893,2465 @@ pub fn territory_move_check_others (
 
                         // Right
                         if conflict_angle <= FRAC_PI_4 && conflict_angle >= -FRAC_PI_4 {
-                            other_territory.expanse.move_worldspace_corners(
-                                Vec2::new(1.0 * conflict_rect.width(), 0.0),
-                                Vec2::ZERO,
-                                window.width(),
-                                window.height()
-                            );
+                            other_rect.min.x += conflict_rect.width();
                         } 
                         // Top
                         else if conflict_angle >= FRAC_PI_4 && conflict_angle <= 3.0 * FRAC_PI_4 {
-                            other_territory.expanse.move_worldspace_corners(
-                                Vec2::new(0.0, 1.0 * conflict_rect.height()),
-                                Vec2::ZERO,
-                                window.width(),
-                                window.height()
-                            );
+                            other_rect.min.y += conflict_rect.height();
                         }
                         // Left (atan2 is discontinuous at PI, as its range is -PI to PI)
                         else if (conflict_angle >= 3.0 * FRAC_PI_4 && conflict_angle <= PI)
                             || (conflict_angle >= -PI && conflict_angle <= -3.0 * FRAC_PI_4) {
-                            other_territory.expanse.move_worldspace_corners(
-                                Vec2::ZERO,
-                                Vec2::new(-1.0 * conflict_rect.height(), 0.0),
-                                window.width(),
-                                window.height()
-                            );
+                            other_rect.max.x -= conflict_rect.width();
                         }
                         // Down
                         else if conflict_angle >= -3.0 * FRAC_PI_4 && conflict_angle <= -FRAC_PI_4 {
-                            other_territory.expanse.move_worldspace_corners(
-                                Vec2::ZERO,
-                                Vec2::new(0.0, -1.0 * conflict_rect.height()),
-                                window.width(),
-                                window.height()
-                            );
+                            other_rect.max.y -= conflict_rect.height();
                         }
+                        pushed_rects.insert(other_entity, other_rect);
                     }
                 }
             }
         }
+
+        for (other_entity, other_territory, _, _) in other_territories_query.iter_many(window_children) {
+            let Some(pushed_rect) = pushed_rects.get(&other_entity) else { continue; };
+            let pushed_expanse = RectKit::from_worldspace(*pushed_rect, window.width(), window.height());
+            let Some(push_direction) = ResizeDirection::from_screenspace_change(
+                other_territory.expanse.screenspace(), 
+                pushed_expanse.screenspace()
+            ) else {
+                warn!("Territory {:?} was pushed from opposite sides at once, and stays put!", other_entity);
+                continue;
+            };
+            commands.entity(other_entity).insert(MoveRequest::new(pushed_expanse, MoveRequestType::Resize(push_direction)));
+        }
     }
 }
 
-/// All [`MoveRequest`] processing done, now apply any surviving [`MoveRequest`]s.
-pub fn territory_move_apply_proposed (
+/// Moves a dragged [`Territory`] into another [`TerritoryTabs`] `Window` once the cursor has left its own 
+/// and entered that one.  
+/// \
+/// The [`Territory`] becomes a child of the new `Window`, and its base node a child of that `Window`'s root node. 
+/// Its expanse keeps the same relative **worldspace** [`Rect`], so it lands in the same spot at the same 
+/// proportions even if the two `Window`s are different sizes. The [`DragRequest`] is used up by the move, 
+/// links to neighbors left behind are cut, and the [`Territory`] takes the [`FocusedTerritory`] of its new `Window`.
+pub fn territory_drag_to_other_window (
     mut commands: Commands,
-    window_query: Query<(&Window, &Children), With<TerritoryTabs>>,
-    mut moving_territories_query: Query<(Entity, &mut Territory, &MoveRequest)>
+    window_root_index: Res<WindowRootIndex>,
+    window_query: Query<(Entity, &Window), With<TerritoryTabs>>,
+    mut drag_request_query: Query<(Entity, &mut Territory, &Parent), With<DragRequest>>,
+    mut connections_query: Query<&mut CardinalConnections>,
+    mut window_focus_query: Query<&mut FocusedTerritory>
 ) {
-    for (window, window_children) in &window_query {
-        let mut move_requests = moving_territories_query.iter_many_mut(window_children);
-        while let Some(
-            (territory_entity, mut territory, move_request)
-        ) = move_requests.fetch_next() {
+    for (territory_entity, mut territory, territory_parent) in &mut drag_request_query {
+        let source_window_entity = territory_parent.get();
 
-            match move_request.move_type {
+        // Still inside its own Window, or the source isn't one we know about.
+        let Ok((_, source_window)) = window_query.get(source_window_entity) else {
+            continue;
+        };
+        if source_window.cursor_position().is_some() {
+            continue;
+        }
 
-                MoveRequestType::Unknown => {
-                    warn!("Unknown-type MoveRequest found on Territory during application!");
-                    commands.entity(territory_entity).remove::<MoveRequest>();
-                },
+        let Some((destination_window_entity, destination_window)) = window_query.iter()
+            .find(|(window_entity, window)| *window_entity != source_window_entity && window.cursor_position().is_some()) else {
+            continue;
+        };
 
-                MoveRequestType::Drag | MoveRequestType::Resize(_) => {
-                    territory.expanse.set_worldspace(
-                        move_request.proposed_expanse.worldspace(), 
-                        window.width(), 
-                        window.height()
-                    );
-                    commands.entity(territory_entity).remove::<MoveRequest>();
-                }
-            }
+        // Territories with nodes need somewhere to put them.
+        let destination_root = window_root_index.root_of(destination_window_entity);
+        if territory.base_node().is_some() && destination_root.is_none() {
+            warn!("Territory dragged into {:?}, which has no root node yet. Territory stays put.", destination_window_entity);
+            continue;
         }
-    }
-}
 
+        let relative_worldspace = territory.expanse().relative_worldspace();
+        territory.expanse = RectKit::from_relative_worldspace(
+            relative_worldspace, 
+            destination_window.width(), 
+            destination_window.height()
+        );
+
+        commands.entity(destination_window_entity).add_child(territory_entity);
+        if let (Some(base_node_entity), Some(destination_root)) = (territory.base_node(), destination_root) {
+            commands.entity(destination_root).add_child(base_node_entity);
+        }
+        commands.entity(territory_entity).remove::<DragRequest>();
+
+        // Neighbors are in the other Window now.
+        for mut connections in &mut connections_query {
+            let connections = connections.as_mut();
+            for side in [&mut connections.northern, &mut connections.eastern, &mut connections.southern, &mut connections.western] {
+                side.retain(|neighbor| *neighbor != territory_entity);
+            }
+        }
+        if let Ok(mut connections) = connections_query.get_mut(territory_entity) {
+            *connections = CardinalConnections::default();
+        }
+
+        if let Ok(mut source_focus) = window_focus_query.get_mut(source_window_entity) {
+            if source_focus.get() == Some(territory_entity) {
+                source_focus.0 = None;
+            }
+        }
+        if let Ok(mut destination_focus) = window_focus_query.get_mut(destination_window_entity) {
+            destination_focus.0 = Some(territory_entity);
+        }
+
+        debug!("Territory {:?} dragged from {:?} into {:?}.", territory_entity, source_window_entity, destination_window_entity);
+    }
+}
+
+/// Hands every [`DragRequest`] and [`ResizeRequest`] over to the [`MoveRequest`] pipeline, 
+/// so requests inserted by apps are processed the same way as ones coming from display libraries.
+pub fn territory_hand_off_requests (
+    mut commands: Commands,
+    drag_request_query: Query<(Entity, &DragRequest), With<Territory>>,
+    resize_request_query: Query<(Entity, &ResizeRequest), With<Territory>>
+) {
+    for (territory_entity, drag_request) in & drag_request_query {
+        commands.entity(territory_entity)
+            .insert(MoveRequest::new(drag_request.proposed_expanse(), MoveRequestType::Drag))
+            .remove::<DragRequest>();
+    }
+    for (territory_entity, resize_request) in & resize_request_query {
+        if drag_request_query.contains(territory_entity) {
+            warn!("Territory had both a DragRequest and a ResizeRequest! Only the DragRequest was processed.");
+            commands.entity(territory_entity).remove::<ResizeRequest>();
+            continue;
+        }
+        commands.entity(territory_entity)
+            .insert(MoveRequest::new(
+                resize_request.proposed_expanse(), 
+                MoveRequestType::Resize(resize_request.resize_direction())
+            ))
+            .remove::<ResizeRequest>();
+    }
+}
+
+/// Rebuilds the [`CardinalConnections`] of every [`Territory`] in a `Window` whenever one of them spawns, moves, 
+/// or is removed, so stale links never stick around.  
+/// \
+/// Only tiled [`Territory`]s are linked. [`Floating`], [`Overlay`], and [`Maximized`] ones end up with no connections.
+pub fn rebuild_cardinal_connections (
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_query: Query<&Children, With<TerritoryTabs>>,
+    changed_territory_query: Query<(), Changed<Territory>>,
+    mut removed_territories: RemovedComponents<Territory>,
+    tiled_territory_query: Query<(Entity, &Territory), TiledTerritory>,
+    mut connections_query: Query<(Entity, &mut CardinalConnections)>
+) {
+    // A removed Territory could have been linked to anything, so every Window gets rebuilt. 
+    // Same goes for new outer margins, which change what counts as adjacent.
+    let rebuild_all = removed_territories.read().count() > 0 || territory_settings.is_changed();
+
+    for window_children in & window_query {
+        let window_changed = changed_territory_query.iter_many(window_children).next().is_some();
+        if !rebuild_all && !window_changed {
+            continue;
+        }
+
+        let tiled_rects: Vec<(Entity, Rect)> = tiled_territory_query.iter_many(window_children)
+            .map(|(territory_entity, territory)| (territory_entity, territory.expanse.worldspace()))
+            .collect();
+
+        let mut window_connections = connections_query.iter_many_mut(window_children);
+        while let Some((territory_entity, mut connections)) = window_connections.fetch_next() {
+            let new_connections = match tiled_rects.iter().find(|(tiled_entity, _)| *tiled_entity == territory_entity) {
+                Some((_, territory_rect)) => CardinalConnections::from_adjacency(
+                    *territory_rect,
+                    tiled_rects.iter().copied().filter(|(other_entity, _)| *other_entity != territory_entity),
+                    territory_settings.outer_margins
+                ),
+                None => CardinalConnections::default()
+            };
+
+            // Only touch the component if something changed, so we don't trigger change detection every move.
+            if *connections != new_connections {
+                *connections = new_connections;
+            }
+        }
+    }
+}
+
+/// All [`MoveRequest`] processing done, now apply any surviving [`MoveRequest`]s.  
+/// \
+/// With [`GridSnap`] on, the final **worldspace** edges are snapped to the grid here, after collisions are resolved. 
+/// A snap that would leave the `Window` or overlap a neighbor it wasn't already overlapping is skipped.  
+/// \
+/// With [`GlobalTerritorySettings::live_resize`] off, a `Window` with a drag or resize underway only gets [`DeferredExpanse`]s, 
+/// which [`commit_deferred_expanses`] applies once it ends. 
+/// A frame where the dragged [`Territory`]'s own [`MoveRequest`] was rejected leaves the previous [`DeferredExpanse`]s alone.
+#[allow(clippy::too_many_arguments)]
+pub fn territory_move_apply_proposed (
+    mut commands: Commands,
+    grid_snap: Res<GridSnap>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    mut territory_moved_events: EventWriter<TerritoryMoved>,
+    window_query: Query<(Entity, &Window, &Children), With<TerritoryTabs>>,
+    motion_origin_query: Query<&MotionOrigin>,
+    mut moving_territories_query: Query<(Entity, &mut Territory, &MoveRequest)>,
+    resting_territories_query: Query<(Entity, &Territory, Has<DeferredExpanse>), Without<MoveRequest>>
+) {
+    for (window_entity, window, window_children) in &window_query {
+        let window_rect = Rect::from_center_size(Vec2::ZERO, Vec2::new(window.width(), window.height()));
+
+        let deferring = !territory_settings.live_resize 
+            && motion_origin_query.iter_many(window_children).any(|motion_origin| !motion_origin.canceled());
+        if deferring {
+            // A rejected or missing MoveRequest for whatever is being dragged keeps the last good DeferredExpanses, 
+            // and anything it would have pushed stays where it was previewed.
+            let motion_request_survived = moving_territories_query.iter_many(window_children)
+                .any(|(territory_entity, _, _)| motion_origin_query.contains(territory_entity));
+            if !motion_request_survived {
+                for (territory_entity, _, _) in moving_territories_query.iter_many(window_children) {
+                    commands.entity(territory_entity).remove::<MoveRequest>();
+                }
+                continue;
+            }
+
+            // Every deferred move, pushed and tiled neighbors included, is worked out again from the resting expanses. 
+            // Neighbors this frame's moves no longer push lose their DeferredExpanse, and stay where they are.
+            for (territory_entity, _, has_deferred_expanse) in resting_territories_query.iter_many(window_children) {
+                if has_deferred_expanse {
+                    commands.entity(territory_entity).remove::<DeferredExpanse>();
+                }
+            }
+        }
+
+        // Where everything in the Window will end up, so snapped edges can be checked against it.
+        let mut settled_rects: Vec<(Entity, Rect)> = Vec::new();
+        if grid_snap.enabled {
+            settled_rects.extend(resting_territories_query.iter_many(window_children)
+                .map(|(territory_entity, territory, _)| (territory_entity, territory.expanse.worldspace())));
+            settled_rects.extend(moving_territories_query.iter_many(window_children)
+                .map(|(territory_entity, _, move_request)| (territory_entity, move_request.proposed_expanse.worldspace())));
+        }
+
+        let mut move_requests = moving_territories_query.iter_many_mut(window_children);
+        while let Some(
+            (territory_entity, mut territory, move_request)
+        ) = move_requests.fetch_next() {
+
+            match move_request.move_type {
+
+                MoveRequestType::Unknown => {
+                    warn!("Unknown-type MoveRequest found on Territory during application!");
+                    commands.entity(territory_entity).remove::<MoveRequest>();
+                },
+
+                MoveRequestType::Drag | MoveRequestType::Resize(_) => {
+                    let mut final_rect = move_request.proposed_expanse.worldspace();
+                    if grid_snap.enabled {
+                        let snapped_rect = grid_snap.snap_rect(final_rect, territory_settings.min_size);
+                        let overlaps_new_neighbor = settled_rects.iter()
+                            .filter(|(other_entity, _)| *other_entity != territory_entity)
+                            .any(|(_, other_rect)| {
+                                !snapped_rect.intersect(*other_rect).is_empty() && final_rect.intersect(*other_rect).is_empty()
+                            });
+                        let inside_window = window_rect.contains(snapped_rect.min) && window_rect.contains(snapped_rect.max);
+
+                        if inside_window && !overlaps_new_neighbor {
+                            final_rect = snapped_rect;
+                            if let Some((_, settled_rect)) = settled_rects.iter_mut().find(|(other_entity, _)| *other_entity == territory_entity) {
+                                *settled_rect = snapped_rect;
+                            }
+                        }
+                        else {
+                            debug!("Grid snap for {:?} would leave the Window or overlap a neighbor. Not snapped.", territory_entity);
+                        }
+                    }
+
+                    if deferring {
+                        commands.entity(territory_entity)
+                            .insert(DeferredExpanse { expanse: RectKit::from_worldspace(final_rect, window.width(), window.height()) })
+                            .remove::<MoveRequest>();
+                        continue;
+                    }
+
+                    let old_expanse = territory.expanse;
+                    territory.expanse.set_worldspace(final_rect, window.width(), window.height());
+                    commands.entity(territory_entity).remove::<MoveRequest>();
+
+                    if old_expanse.worldspace() != territory.expanse.worldspace() {
+                        territory_moved_events.send(TerritoryMoved {
+                            territory: territory_entity,
+                            window: window_entity,
+                            old: old_expanse,
+                            new: territory.expanse
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Applies each [`DeferredExpanse`] to its [`Territory`] once the drag or resize in its `Window` ends, 
+/// or right away if [`GlobalTerritorySettings::live_resize`] was turned back on. 
+/// Sends a [`TerritoryMoved`] for every [`Territory`] that actually moved.
+pub fn commit_deferred_expanses (
+    mut commands: Commands,
+    territory_settings: Res<GlobalTerritorySettings>,
+    mut territory_moved_events: EventWriter<TerritoryMoved>,
+    window_query: Query<(Entity, &Window, &Children), With<TerritoryTabs>>,
+    motion_origin_query: Query<&MotionOrigin>,
+    mut deferred_territory_query: Query<(Entity, &mut Territory, &DeferredExpanse)>
+) {
+    for (window_entity, window, window_children) in &window_query {
+        let motion_underway = motion_origin_query.iter_many(window_children).any(|motion_origin| !motion_origin.canceled());
+        if motion_underway && !territory_settings.live_resize {
+            continue;
+        }
+
+        let mut deferred_territories = deferred_territory_query.iter_many_mut(window_children);
+        while let Some((territory_entity, mut territory, deferred_expanse)) = deferred_territories.fetch_next() {
+            let old_expanse = territory.expanse;
+            territory.expanse.set_worldspace(deferred_expanse.expanse().worldspace(), window.width(), window.height());
+            commands.entity(territory_entity).remove::<DeferredExpanse>();
+
+            if old_expanse.worldspace() != territory.expanse.worldspace() {
+                territory_moved_events.send(TerritoryMoved {
+                    territory: territory_entity,
+                    window: window_entity,
+                    old: old_expanse,
+                    new: territory.expanse
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_windows_get_the_default_display_library_unless_they_have_one() {
+        let mut app = App::new();
+        app
+            .insert_resource(DefaultDisplayLibrary(DisplayLibrary::BevyUi))
+            .add_event::<WindowCreated>()
+            .add_systems(Update, configure_os_window.run_if(on_event::<WindowCreated>()));
+
+        let plain_window = app.world_mut().spawn(Window::default()).id();
+        let egui_window = app.world_mut().spawn((Window::default(), DisplayLibrary::BevyEgui)).id();
+        app.world_mut().send_event(WindowCreated { window: plain_window });
+        app.world_mut().send_event(WindowCreated { window: egui_window });
+        app.update();
+
+        assert_eq!(app.world().get::<DisplayLibrary>(plain_window), Some(&DisplayLibrary::BevyUi), "New Window did not get the default DisplayLibrary.");
+        assert_eq!(app.world().get::<DisplayLibrary>(egui_window), Some(&DisplayLibrary::BevyEgui), "Window's own DisplayLibrary was overwritten.");
+    }
+
+    #[test]
+    fn window_root_index_follows_window_ui_lifetime() {
+        let mut app = App::new();
+        app
+            .init_resource::<WindowRootIndex>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowClosed>()
+            .add_systems(Update, (
+                configure_os_window
+                    .run_if(on_event::<WindowCreated>()),
+                despawn_closed_window_roots
+                    .run_if(on_event::<WindowClosed>()),
+                index_added_ui_roots,
+                unindex_removed_ui_roots
+            ).chain());
+
+        let window_entity = app.world_mut().spawn(Window::default()).id();
+        app.world_mut().send_event(WindowCreated { window: window_entity });
+        app.update();
+
+        let root_node_entity = app.world().resource::<WindowRootIndex>().root_of(window_entity)
+            .expect("Window root node was not indexed after configuring the window.");
+        assert_eq!(
+            app.world().resource::<WindowRootIndex>().window_of(root_node_entity), 
+            Some(window_entity),
+            "Window root index is not bidirectional."
+        );
+
+        app.world_mut().send_event(WindowClosed { window: window_entity });
+        app.update();
+
+        assert!(app.world().get_entity(root_node_entity).is_none(), "Closed window's root node was not despawned.");
+        assert!(app.world().resource::<WindowRootIndex>().is_empty(), "Closed window's root node is still indexed.");
+    }
+
+    fn tab_app() -> App {
+        let mut app = App::new();
+        app
+            .add_event::<AddTabRequest>()
+            .add_event::<RemoveTabRequest>()
+            .add_event::<SelectTab>()
+            .add_event::<MoveTabRequest>()
+            .add_systems(Update, (
+                add_requested_tabs
+                    .run_if(on_event::<AddTabRequest>()),
+                remove_requested_tabs
+                    .run_if(on_event::<RemoveTabRequest>()),
+                select_requested_tabs
+                    .run_if(on_event::<SelectTab>()),
+                move_requested_tabs
+                    .run_if(on_event::<MoveTabRequest>())
+            ).chain());
+        app
+    }
+
+    #[test]
+    fn adding_tab_parents_it_and_updates_territory() {
+        let mut app = tab_app();
+        let territory_entity = app.world_mut().spawn(Territory::empty()).id();
+
+        app.world_mut().send_event(AddTabRequest { 
+            territory: territory_entity, 
+            tab: Tab::build_from_type(TabType::ECS) 
+        });
+        app.update();
+
+        let territory_tabs = app.world().get::<Territory>(territory_entity).unwrap().tabs().to_vec();
+        assert_eq!(territory_tabs.len(), 1, "Territory tab list was not updated.");
+        let tab_entity = territory_tabs[0];
+        assert_eq!(
+            app.world().get::<Parent>(tab_entity).map(|parent| parent.get()), 
+            Some(territory_entity), 
+            "Tab was not parented to its Territory."
+        );
+        assert!(app.world().get::<Tab>(tab_entity).unwrap().active, "Only tab in a Territory should be active.");
+    }
+
+    #[test]
+    fn removing_active_tab_activates_a_neighbor() {
+        let mut app = tab_app();
+        let territory_entity = app.world_mut().spawn(Territory::empty()).id();
+
+        for tab_type in [TabType::FileSystem, TabType::DevBox, TabType::Glossary] {
+            app.world_mut().send_event(AddTabRequest { 
+                territory: territory_entity, 
+                tab: Tab { active: tab_type == TabType::DevBox, ..Tab::build_from_type(tab_type) } 
+            });
+        }
+        app.update();
+
+        let territory_tabs = app.world().get::<Territory>(territory_entity).unwrap().tabs().to_vec();
+        let is_active = |app: &App, tab_entity: Entity| app.world().get::<Tab>(tab_entity).unwrap().active;
+        assert_eq!(
+            territory_tabs.iter().map(|tab_entity| is_active(&app, *tab_entity)).collect::<Vec<bool>>(), 
+            vec![false, true, false],
+            "Requested active tab was not the only active tab."
+        );
+
+        app.world_mut().send_event(RemoveTabRequest { tab: territory_tabs[1] });
+        app.update();
+
+        assert!(app.world().get_entity(territory_tabs[1]).is_none(), "Removed tab was not despawned.");
+        assert_eq!(
+            app.world().get::<Territory>(territory_entity).unwrap().tabs(), 
+            &[territory_tabs[0], territory_tabs[2]],
+            "Removed tab is still in the Territory tab list."
+        );
+        assert!(is_active(&app, territory_tabs[2]), "Neighbor of the removed active tab did not become active.");
+        assert!(!is_active(&app, territory_tabs[0]), "More than one tab became active.");
+    }
+
+    /// App running the request hand off and [`MoveRequest`] pipeline, with one 1000 x 1000 [`TerritoryTabs`] window.
+    fn motion_app() -> (App, Entity) {
+        let mut app = App::new();
+        app
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<GridSnap>()
+            .init_resource::<TileMode>()
+            .add_event::<TerritoryMoved>()
+            .add_systems(Update, (
+                territory_hand_off_requests
+                    .in_set(TerritoryApply)
+                    .before(TerritoryUpdateMotion),
+                (
+                    territory_move_eval_type,
+                    territory_move_snap_resize,
+                    territory_move_snap_drag_to_window,
+                    territory_move_process_fringe,
+                    territory_move_group_drags,
+                    territory_move_resolve_mutual_resize,
+                    territory_move_tile_neighbors,
+                    territory_move_check_others,
+                    territory_move_apply_proposed
+                )
+                    .chain()
+                    .in_set(TerritoryUpdateMotion)
+                    .run_if(any_with_component::<MoveRequest>)
+            ))
+            .configure_sets(Update, (
+                TerritoryInput.before(TerritoryApply),
+                TerritoryUpdateMotion.in_set(TerritoryApply)
+            ));
+
+        let window_entity = spawn_test_window(&mut app, 1000.0, 1000.0);
+        (app, window_entity)
+    }
+
+    /// Spawns a stand-in [`TerritoryTabs`] `Window` with a fixed size. Nothing is opened, so this works headless.
+    fn spawn_test_window(app: &mut App, width: f32, height: f32) -> Entity {
+        let mut window = Window::default();
+        window.resolution.set(width, height);
+        app.world_mut().spawn((window, TerritoryTabs)).id()
+    }
+
+    /// Spawns a [`Territory`] in the window with the given screenspace [`Rect`].
+    fn spawn_motion_territory(app: &mut App, window_entity: Entity, screenspace: Rect) -> Entity {
+        let window = app.world().get::<Window>(window_entity).unwrap();
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(screenspace, window.width(), window.height());
+        let territory_entity = app.world_mut().spawn(territory).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+        territory_entity
+    }
+
+    /// [`motion_app`] along with the systems keeping [`CardinalConnections`] up to date.
+    fn connected_motion_app() -> (App, Entity) {
+        let (mut app, window_entity) = motion_app();
+        add_cardinal_connection_systems(&mut app);
+        (app, window_entity)
+    }
+
+    /// Spawns a [`Territory`] like [`spawn_motion_territory`], with [`CardinalConnections`] to its neighbors.
+    fn spawn_connected_territory(app: &mut App, window_entity: Entity, screenspace: Rect) -> Entity {
+        let territory_entity = spawn_motion_territory(app, window_entity, screenspace);
+        app.world_mut().entity_mut(territory_entity).insert(CardinalConnections::default());
+        territory_entity
+    }
+
+    #[test]
+    fn drag_request_from_input_set_is_applied_same_frame() {
+        let (mut app, window_entity) = motion_app();
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 200.0));
+
+        let proposed_expanse = RectKit::from_screenspace(Rect::new(150.0, 120.0, 350.0, 220.0), 1000.0, 1000.0);
+
+        // Stand-in for an app's own input handling.
+        let user_input = move |mut commands: Commands, territory_query: Query<Entity, With<Territory>>| {
+            for territory_entity in & territory_query {
+                commands.entity(territory_entity).insert(DragRequest::new(proposed_expanse, Vec2::new(50.0, 20.0)));
+            }
+        };
+        app.add_systems(Update, user_input.in_set(TerritoryInput));
+        app.update();
+
+        let territory = app.world().get::<Territory>(territory_entity).unwrap();
+        assert_eq!(
+            territory.expanse().screenspace(), 
+            proposed_expanse.screenspace(), 
+            "DragRequest inserted in TerritoryInput was not applied in the same frame."
+        );
+        assert!(app.world().get::<DragRequest>(territory_entity).is_none(), "DragRequest was not cleaned up.");
+        assert!(app.world().get::<MoveRequest>(territory_entity).is_none(), "MoveRequest was not cleaned up.");
+    }
+
+    #[test]
+    fn motion_in_a_wide_window_keeps_every_coordinate_space_in_agreement() {
+        let (mut app, _) = motion_app();
+        let (width, height) = (1600.0, 900.0);
+        let window_entity = spawn_test_window(&mut app, width, height);
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(300.0, 100.0, 500.0, 300.0));
+        let far_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(1200.0, 500.0, 1500.0, 800.0));
+
+        let resized_expanse = RectKit::from_screenspace(Rect::new(100.0, 100.0, 350.0, 300.0), width, height);
+        app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(resized_expanse, MoveRequestType::Unknown));
+        let dragged_expanse = RectKit::from_screenspace(Rect::new(1100.0, 550.0, 1400.0, 850.0), width, height);
+        app.world_mut().entity_mut(far_entity).insert(MoveRequest::new(dragged_expanse, MoveRequestType::Drag));
+        app.update();
+
+        let expanse_of = |app: &App, entity: Entity| app.world().get::<Territory>(entity).unwrap().expanse;
+        assert_eq!(expanse_of(&app, dragged_entity).screenspace(), resized_expanse.screenspace(), "Resize into a neighbor was not applied.");
+        assert_eq!(expanse_of(&app, neighbor_entity).screenspace(), Rect::new(352.5, 100.0, 500.0, 300.0), "Neighbor did not give way.");
+        assert_eq!(expanse_of(&app, far_entity).screenspace(), dragged_expanse.screenspace(), "Drag in open space was not applied.");
+
+        // Every coordinate space has to describe the same Rect, or the display systems drift from the collision ones.
+        for entity in [dragged_entity, neighbor_entity, far_entity] {
+            let expanse = expanse_of(&app, entity);
+            let screenspace = expanse.screenspace();
+            let relative_rebuilt = RectKit::from_relative_screenspace(expanse.relative_screenspace(), width, height).screenspace();
+            let world_rebuilt = RectKit::from_worldspace(expanse.worldspace(), width, height).screenspace();
+            assert!(
+                relative_rebuilt.min.abs_diff_eq(screenspace.min, 0.001) && relative_rebuilt.max.abs_diff_eq(screenspace.max, 0.001), 
+                "Relative screenspace drifted from screenspace: {:?} vs {:?}", relative_rebuilt, screenspace
+            );
+            assert_eq!(world_rebuilt, screenspace, "Worldspace drifted from screenspace.");
+        }
+    }
+
+    #[test]
+    fn floating_territory_overlaps_tiled_territory_without_pushing() {
+        let (mut app, window_entity) = motion_app();
+        let floating_rect = Rect::new(400.0, 400.0, 600.0, 600.0);
+        let floating_entity = spawn_motion_territory(&mut app, window_entity, floating_rect);
+        app.world_mut().entity_mut(floating_entity).insert(Floating);
+        let tiled_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+
+        // Drag the tiled Territory right under the floating one.
+        let proposed_expanse = RectKit::from_screenspace(Rect::new(450.0, 450.0, 650.0, 650.0), 1000.0, 1000.0);
+        app.world_mut().entity_mut(tiled_entity).insert(DragRequest::new(proposed_expanse, Vec2::new(350.0, 350.0)));
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Territory>(tiled_entity).unwrap().expanse().screenspace(), 
+            proposed_expanse.screenspace(), 
+            "Tiled Territory was blocked by a Floating Territory."
+        );
+        assert_eq!(
+            app.world().get::<Territory>(floating_entity).unwrap().expanse().screenspace(), 
+            floating_rect, 
+            "Floating Territory was pushed by a tiled Territory."
+        );
+    }
+
+    #[test]
+    fn mutual_resize_splits_free_space_deterministically() {
+        // Spawn order shouldn't matter, so try both.
+        for swap_spawn_order in [false, true] {
+            let (mut app, window_entity) = motion_app();
+            let left_rect = Rect::new(100.0, 100.0, 250.0, 300.0);
+            let right_rect = Rect::new(300.0, 100.0, 500.0, 300.0);
+            let (left_entity, right_entity) = if swap_spawn_order {
+                let right_entity = spawn_motion_territory(&mut app, window_entity, right_rect);
+                (spawn_motion_territory(&mut app, window_entity, left_rect), right_entity)
+            } else {
+                let left_entity = spawn_motion_territory(&mut app, window_entity, left_rect);
+                (left_entity, spawn_motion_territory(&mut app, window_entity, right_rect))
+            };
+
+            // Left wants to grow 150 east, right wants to grow 100 west, with only 50 between them.
+            let east_resize = ResizeDirection::East { eastward_magnitude: ResizeMagnitude::Advancing(150.0) };
+            let west_resize = ResizeDirection::West { westward_magnitude: ResizeMagnitude::Advancing(100.0) };
+            app.world_mut().entity_mut(left_entity).insert(ResizeRequest::new(
+                RectKit::from_screenspace(Rect::new(100.0, 100.0, 400.0, 300.0), 1000.0, 1000.0),
+                east_resize
+            ));
+            app.world_mut().entity_mut(right_entity).insert(ResizeRequest::new(
+                RectKit::from_screenspace(Rect::new(200.0, 100.0, 500.0, 300.0), 1000.0, 1000.0),
+                west_resize
+            ));
+            app.update();
+
+            let left_screenspace = app.world().get::<Territory>(left_entity).unwrap().expanse().screenspace();
+            let right_screenspace = app.world().get::<Territory>(right_entity).unwrap().expanse().screenspace();
+
+            // 50 between them, less the 2.5 margin, split 150:100 puts the boundary 28.5 past the left Territory's old edge.
+            assert!((left_screenspace.max.x - 278.5).abs() < 0.001, "Left Territory edge not at boundary: {:?}", left_screenspace);
+            assert!((right_screenspace.min.x - 281.0).abs() < 0.001, "Right Territory edge not a margin past the boundary: {:?}", right_screenspace);
+            assert!(
+                left_screenspace.intersect(right_screenspace).width() <= 0.001, 
+                "Mutually resized Territories overlap: {:?} and {:?}", left_screenspace, right_screenspace
+            );
+        }
+    }
+
+    #[test]
+    fn each_window_tracks_its_own_focused_territory() {
+        let mut app = App::new();
+        app.add_systems(Update, focus_interacted_territory);
+
+        // Two windows, each with two Territories that only have a drag node.
+        let spawn_window_with_territories = |app: &mut App| {
+            let window_entity = app.world_mut().spawn((Window::default(), TerritoryTabs, FocusedTerritory::default())).id();
+            let territories: Vec<(Entity, Entity)> = (0..2).map(|_| {
+                let drag_node_entity = app.world_mut().spawn(Interaction::None).id();
+                let territory = Territory { drag_node: Some(drag_node_entity), ..Territory::empty() };
+                let territory_entity = app.world_mut().spawn(territory).id();
+                app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+                (territory_entity, drag_node_entity)
+            }).collect();
+            (window_entity, territories)
+        };
+        let (first_window, first_territories) = spawn_window_with_territories(&mut app);
+        let (second_window, second_territories) = spawn_window_with_territories(&mut app);
+        app.update();
+
+        let press = |app: &mut App, drag_node_entity: Entity| {
+            *app.world_mut().get_mut::<Interaction>(drag_node_entity).unwrap() = Interaction::Pressed;
+            app.update();
+            *app.world_mut().get_mut::<Interaction>(drag_node_entity).unwrap() = Interaction::None;
+        };
+        let focus_of = |app: &App, window_entity: Entity| app.world().get::<FocusedTerritory>(window_entity).unwrap().get();
+
+        press(&mut app, first_territories[1].1);
+        press(&mut app, second_territories[0].1);
+
+        assert_eq!(focus_of(&app, first_window), Some(first_territories[1].0), "First window lost its focus.");
+        assert_eq!(focus_of(&app, second_window), Some(second_territories[0].0), "Second window has the wrong focus.");
+
+        press(&mut app, first_territories[0].1);
+
+        assert_eq!(focus_of(&app, first_window), Some(first_territories[0].0), "First window did not change focus.");
+        assert_eq!(focus_of(&app, second_window), Some(second_territories[0].0), "Focus leaked into the second window.");
+    }
+
+    #[test]
+    fn only_the_last_pressed_territory_is_focused_and_on_top() {
+        let mut app = App::new();
+        app.add_systems(Update, (focus_interacted_territory, update_floating_base_node).chain());
+
+        let window_entity = app.world_mut().spawn((Window::default(), TerritoryTabs, FocusedTerritory::default())).id();
+        let territories: Vec<(Entity, Entity, Entity)> = (0..3).map(|_| {
+            let drag_node_entity = app.world_mut().spawn(Interaction::None).id();
+            let base_node_entity = app.world_mut().spawn((ZIndex::Local(0), TerritoryBaseNode)).id();
+            let territory = Territory { drag_node: Some(drag_node_entity), base_node: Some(base_node_entity), ..Territory::empty() };
+            let territory_entity = app.world_mut().spawn(territory).id();
+            app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+            (territory_entity, drag_node_entity, base_node_entity)
+        }).collect();
+        app.world_mut().entity_mut(territories[2].0).insert(Floating);
+
+        let set_interaction = |app: &mut App, drag_node_entity: Entity, interaction: Interaction| {
+            *app.world_mut().get_mut::<Interaction>(drag_node_entity).unwrap() = interaction;
+            app.update();
+        };
+        let focused = |app: &mut App| -> Vec<Entity> {
+            app.world_mut().query_filtered::<Entity, With<Focused>>().iter(app.world()).collect()
+        };
+        let z_index_of = |app: &App, base_node_entity: Entity| match app.world().get::<ZIndex>(base_node_entity).unwrap() {
+            ZIndex::Local(z_index) | ZIndex::Global(z_index) => *z_index
+        };
+
+        // Pressed, held through a drag, and released again.
+        set_interaction(&mut app, territories[0].1, Interaction::Pressed);
+        for _ in 0..3 {
+            app.world_mut().get_mut::<Territory>(territories[0].0).unwrap().expanse.screenspace.min.x += 10.0;
+            app.update();
+        }
+        set_interaction(&mut app, territories[0].1, Interaction::Hovered);
+        assert_eq!(focused(&mut app), vec![territories[0].0], "Focus did not survive the drag.");
+        assert!(
+            z_index_of(&app, territories[0].2) > z_index_of(&app, territories[2].2), 
+            "Focused Territory is not drawn above the Floating one."
+        );
+
+        set_interaction(&mut app, territories[1].1, Interaction::Pressed);
+        assert_eq!(focused(&mut app), vec![territories[1].0], "Focused did not move to the pressed Territory.");
+        assert_eq!(z_index_of(&app, territories[0].2), 0, "Unfocused Territory stayed on top.");
+        assert_eq!(z_index_of(&app, territories[2].2), 1, "Floating Territory lost its place above the tiled ones.");
+
+        // Two presses in the same frame still leave a single Focused.
+        *app.world_mut().get_mut::<Interaction>(territories[0].1).unwrap() = Interaction::Pressed;
+        set_interaction(&mut app, territories[2].1, Interaction::Pressed);
+        assert_eq!(focused(&mut app).len(), 1, "More than one Territory is Focused.");
+    }
+
+    #[test]
+    fn north_only_territory_spawns_one_handle_and_only_resizes_north() {
+        let (mut app, window_entity) = motion_app();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut window_root_index = WindowRootIndex::default();
+        window_root_index.insert(window_entity, root_node_entity);
+        app
+            .insert_resource(window_root_index)
+            .init_resource::<TabTextConfig>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_systems(Update, spawn_territory.before(TerritoryInput));
+
+        let start_rect = Rect::new(100.0, 300.0, 300.0, 500.0);
+        app.world_mut().send_event(TerritorySpawnRequest {
+            window_entity,
+            expanse: RectKit::from_screenspace(start_rect, 1000.0, 1000.0),
+            relative_screenspace: None,
+            display_library: DisplayLibrary::BevyUi,
+            resizable_edges: ResizableEdges::NORTH
+        });
+        app.update();
+
+        let mut resize_button_query = app.world_mut().query_filtered::<&ResizeDirection, With<TerritoryResizeButtonNode>>();
+        let resize_buttons: Vec<ResizeDirection> = resize_button_query.iter(app.world()).copied().collect();
+        assert_eq!(resize_buttons.len(), 1, "Only the north handle should have spawned.");
+        assert!(matches!(resize_buttons[0], ResizeDirection::North { .. }), "Spawned handle was not the north one.");
+
+        let mut territory_query = app.world_mut().query_filtered::<Entity, With<Territory>>();
+        let territory_entity = territory_query.single(app.world());
+        let screenspace_of = |app: &App| app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace();
+
+        // Eastward resize is ignored.
+        app.world_mut().entity_mut(territory_entity).insert(ResizeRequest::new(
+            RectKit::from_screenspace(Rect::new(100.0, 300.0, 350.0, 500.0), 1000.0, 1000.0),
+            ResizeDirection::East { eastward_magnitude: ResizeMagnitude::Advancing(50.0) }
+        ));
+        app.update();
+        assert_eq!(screenspace_of(&app), start_rect, "Eastward resize was accepted on a north only Territory.");
+
+        // Northward resize goes through.
+        let north_rect = Rect::new(100.0, 250.0, 300.0, 500.0);
+        app.world_mut().entity_mut(territory_entity).insert(ResizeRequest::new(
+            RectKit::from_screenspace(north_rect, 1000.0, 1000.0),
+            ResizeDirection::North { northward_magnitude: ResizeMagnitude::Advancing(50.0) }
+        ));
+        app.update();
+        assert_eq!(screenspace_of(&app), north_rect, "Northward resize was not accepted.");
+    }
+
+    /// Spawns a [`Territory`] with a FileSystem, DevBox, and Glossary tab, in that order, with the first one active.
+    fn spawn_territory_with_tabs(app: &mut App) -> (Entity, Vec<Entity>) {
+        let territory_entity = app.world_mut().spawn(Territory::empty()).id();
+        for tab_type in [TabType::FileSystem, TabType::DevBox, TabType::Glossary] {
+            app.world_mut().send_event(AddTabRequest { territory: territory_entity, tab: Tab::build_from_type(tab_type) });
+        }
+        app.update();
+        let territory_tabs = app.world().get::<Territory>(territory_entity).unwrap().tabs().to_vec();
+        (territory_entity, territory_tabs)
+    }
+
+    fn active_tabs(app: &App, territory_tabs: &[Entity]) -> Vec<bool> {
+        territory_tabs.iter().map(|tab_entity| app.world().get::<Tab>(*tab_entity).unwrap().active).collect()
+    }
+
+    #[test]
+    fn select_tab_by_index_activates_only_that_tab() {
+        let mut app = tab_app();
+        let (territory_entity, territory_tabs) = spawn_territory_with_tabs(&mut app);
+
+        app.world_mut().send_event(SelectTab { territory: territory_entity, selector: TabSelector::Index(2) });
+        app.update();
+
+        assert_eq!(active_tabs(&app, &territory_tabs), vec![false, false, true], "Tab at index 2 was not the only active tab.");
+    }
+
+    #[test]
+    fn select_tab_by_type_activates_only_that_tab() {
+        let mut app = tab_app();
+        let (territory_entity, territory_tabs) = spawn_territory_with_tabs(&mut app);
+
+        app.world_mut().send_event(SelectTab { territory: territory_entity, selector: TabSelector::Type(TabType::DevBox) });
+        app.update();
+
+        assert_eq!(active_tabs(&app, &territory_tabs), vec![false, true, false], "DevBox tab was not the only active tab.");
+
+        // Selecting something that isn't there changes nothing.
+        app.world_mut().send_event(SelectTab { territory: territory_entity, selector: TabSelector::Type(TabType::SiteView) });
+        app.update();
+
+        assert_eq!(active_tabs(&app, &territory_tabs), vec![false, true, false], "Missing tab selection changed the active tab.");
+    }
+
+    #[test]
+    fn moved_tab_becomes_active_in_its_new_territory() {
+        let mut app = tab_app();
+        let (origin_entity, origin_tabs) = spawn_territory_with_tabs(&mut app);
+        let (destination_entity, destination_tabs) = spawn_territory_with_tabs(&mut app);
+
+        app.world_mut().send_event(MoveTabRequest { tab: origin_tabs[0], territory: destination_entity });
+        app.update();
+
+        assert_eq!(app.world().get::<Territory>(origin_entity).unwrap().tabs(), &origin_tabs[1..], "Moved tab is still in its old Territory.");
+        assert_eq!(active_tabs(&app, &origin_tabs[1..]), vec![true, false], "Old Territory did not pick a new active tab.");
+        let mut expected_tabs = destination_tabs.clone();
+        expected_tabs.push(origin_tabs[0]);
+        assert_eq!(app.world().get::<Territory>(destination_entity).unwrap().tabs(), expected_tabs.as_slice());
+        assert_eq!(active_tabs(&app, &expected_tabs), vec![false, false, false, true], "Moved tab is not the only active tab.");
+        assert_eq!(app.world().get::<Parent>(origin_tabs[0]).unwrap().get(), destination_entity, "Moved tab was not reparented.");
+    }
+
+    #[test]
+    fn custom_tabs_are_told_apart_by_id() {
+        let mut app = tab_app();
+        let territory_entity = app.world_mut().spawn(Territory::empty()).id();
+        for id in [7, 42] {
+            app.world_mut().send_event(AddTabRequest { territory: territory_entity, tab: Tab::build_from_type(TabType::Custom { id }) });
+        }
+        app.update();
+        let territory_tabs = app.world().get::<Territory>(territory_entity).unwrap().tabs().to_vec();
+        assert_eq!(app.world().get::<Tab>(territory_tabs[1]).unwrap().name, "Custom 42");
+
+        app.world_mut().send_event(SelectTab { territory: territory_entity, selector: TabSelector::Type(TabType::Custom { id: 42 }) });
+        app.update();
+
+        assert_eq!(active_tabs(&app, &territory_tabs), vec![false, true], "Custom tab with id 42 was not the only active tab.");
+    }
+
+    #[test]
+    fn unknown_resize_request_is_inferred_and_applied() {
+        let start_rect = Rect::new(100.0, 100.0, 300.0, 300.0);
+        let proposed_rect = Rect::new(100.0, 100.0, 340.0, 300.0);
+        assert_eq!(
+            ResizeDirection::from_screenspace_change(start_rect, proposed_rect), 
+            Some(ResizeDirection::East { eastward_magnitude: ResizeMagnitude::Advancing(40.0) }),
+            "Eastern side growing was not inferred as an advancing East resize."
+        );
+
+        let (mut app, window_entity) = motion_app();
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, start_rect);
+        // Only east resizes allowed, so this only goes through if it's recognized as one.
+        app.world_mut().entity_mut(territory_entity).insert(ResizableEdges::EAST);
+        app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(proposed_rect, 1000.0, 1000.0),
+            MoveRequestType::Unknown
+        ));
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace(), 
+            proposed_rect, 
+            "Unknown-type resize was dropped instead of applied."
+        );
+    }
+
+    /// Records every [`Territory`] in the app as a [`TerritoryLayout`].
+    fn capture_layout(app: &mut App) -> TerritoryLayout {
+        let mut territory_query = app.world_mut()
+            .query::<(Entity, &Parent, &Territory, &DisplayLibrary, &ResizableEdges)>();
+        territory_query.iter(app.world())
+            .map(|(territory_entity, window_parent, territory, display_library, resizable_edges)| (
+                territory_entity, 
+                TerritoryLayoutEntry::new(window_parent.get(), territory, *display_library, *resizable_edges)
+            ))
+            .collect()
+    }
+
+    #[test]
+    fn applying_layout_diff_turns_first_layout_into_second() {
+        let (mut app, window_entity) = motion_app();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut window_root_index = WindowRootIndex::default();
+        window_root_index.insert(window_entity, root_node_entity);
+        app
+            .insert_resource(window_root_index)
+            .init_resource::<TabTextConfig>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_event::<ApplyLayoutDiff>()
+            .add_systems(Update, (
+                apply_layout_diff
+                    .in_set(TerritoryInput),
+                (spawn_territory, despawn_territory)
+                    .after(TerritoryApply)
+            ));
+
+        let staying_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let moving_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(500.0, 100.0, 700.0, 300.0));
+        for territory_entity in [staying_entity, moving_entity] {
+            app.world_mut().entity_mut(territory_entity).insert((DisplayLibrary::BevyUi, ResizableEdges::default()));
+        }
+        let first_layout = capture_layout(&mut app);
+
+        // Second layout moves one Territory down and adds another under the first.
+        let mut second_layout = first_layout.clone();
+        let moved_rect = RectKit::from_screenspace(Rect::new(500.0, 400.0, 700.0, 600.0), 1000.0, 1000.0);
+        let added_rect = RectKit::from_screenspace(Rect::new(100.0, 700.0, 300.0, 900.0), 1000.0, 1000.0);
+        second_layout.territories.get_mut(&moving_entity).unwrap().relative_worldspace = moved_rect.relative_worldspace();
+        let added_key = Entity::from_raw(9999);
+        second_layout.insert(added_key, TerritoryLayoutEntry { 
+            relative_worldspace: added_rect.relative_worldspace(), 
+            ..*first_layout.get(staying_entity).unwrap() 
+        });
+
+        let layout_diff = LayoutDiff::between(&first_layout, &second_layout);
+        assert_eq!(layout_diff.moved, vec![(moving_entity, moved_rect.relative_worldspace())], "Moved Territory not in diff.");
+        assert_eq!(layout_diff.added.len(), 1, "Added Territory not in diff.");
+        assert!(layout_diff.removed.is_empty() && layout_diff.resized.is_empty(), "Diff has changes that weren't made.");
+
+        app.world_mut().send_event(ApplyLayoutDiff { diff: layout_diff });
+        app.update();
+        app.update();
+
+        // The spawned Territory gets a new Entity, so swap it in for the key it had in the second layout.
+        let mut result_layout = capture_layout(&mut app);
+        let spawned_entity = result_layout.iter()
+            .map(|(territory_entity, _)| territory_entity)
+            .find(|territory_entity| ![staying_entity, moving_entity].contains(territory_entity))
+            .expect("Added Territory was not spawned.");
+        let spawned_entry = result_layout.territories.remove(&spawned_entity).unwrap();
+        result_layout.insert(added_key, spawned_entry);
+
+        assert!(LayoutDiff::between(&result_layout, &second_layout).is_empty(), "Applied diff did not reach the second layout.");
+    }
+
+    #[test]
+    fn panning_moves_territories_together_until_the_edge() {
+        let (mut app, window_entity) = motion_app();
+        app
+            .add_event::<PanWindowLayout>()
+            .add_systems(Update, pan_window_layout.in_set(TerritoryApply));
+        let west_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let east_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(400.0, 100.0, 600.0, 300.0));
+        // Both stay put below the group, in the way of a pan south.
+        let locked_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 700.0, 300.0, 900.0));
+        let anchored_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(400.0, 750.0, 600.0, 900.0));
+        app.world_mut().entity_mut(locked_entity).insert(Locked);
+        app.world_mut().entity_mut(anchored_entity).insert(RelativeAnchored);
+        let worldspace_of = |app: &App, territory_entity: Entity| 
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+        let west_start = worldspace_of(&app, west_entity);
+        let east_start = worldspace_of(&app, east_entity);
+        let locked_start = worldspace_of(&app, locked_entity);
+        let anchored_start = worldspace_of(&app, anchored_entity);
+
+        app.world_mut().send_event(PanWindowLayout { window: window_entity, delta: Vec2::new(50.0, -20.0) });
+        app.update();
+
+        assert!(
+            worldspace_of(&app, west_entity).min.abs_diff_eq(west_start.min + Vec2::new(50.0, -20.0), 0.001), 
+            "West Territory did not pan by the delta."
+        );
+        assert!(
+            worldspace_of(&app, east_entity).min.abs_diff_eq(east_start.min + Vec2::new(50.0, -20.0), 0.001), 
+            "East Territory did not pan by the delta."
+        );
+
+        // Way too far south, the group should stop a margin short of the Locked Territory, the first one in its way.
+        app.world_mut().send_event(PanWindowLayout { window: window_entity, delta: Vec2::new(0.0, -5000.0) });
+        app.update();
+
+        let margin = GlobalTerritorySettings::default().outer_margins.y;
+        assert_eq!(worldspace_of(&app, locked_entity), locked_start, "Locked Territory was panned.");
+        assert_eq!(worldspace_of(&app, anchored_entity), anchored_start, "RelativeAnchored Territory was panned.");
+        assert!(
+            (worldspace_of(&app, west_entity).min.y - (locked_start.max.y + margin)).abs() < 0.1, 
+            "Group did not stop a margin short of the Locked Territory: {:?}", worldspace_of(&app, west_entity)
+        );
+        assert!(
+            (worldspace_of(&app, east_entity).min.x - worldspace_of(&app, west_entity).min.x - 300.0).abs() < 0.001, 
+            "Territories did not stay together."
+        );
+        for panned_entity in [west_entity, east_entity] {
+            for fixed_entity in [locked_entity, anchored_entity] {
+                assert!(
+                    worldspace_of(&app, panned_entity).intersect(worldspace_of(&app, fixed_entity)).is_empty(), 
+                    "Panned Territory {:?} overlapped {:?}.", panned_entity, fixed_entity
+                );
+            }
+        }
+
+        // Way too far east, the group should stop with only a sliver left on screen.
+        app.world_mut().send_event(PanWindowLayout { window: window_entity, delta: Vec2::new(5000.0, 0.0) });
+        app.update();
+
+        let min_size = GlobalTerritorySettings::default().min_size;
+        let west_rect = worldspace_of(&app, west_entity);
+        let east_rect = worldspace_of(&app, east_entity);
+        assert!((west_rect.min.x - (500.0 - min_size.x)).abs() < 0.001, "Group did not stop at the window edge: {:?}", west_rect);
+        assert!((east_rect.min.x - west_rect.min.x - 300.0).abs() < 0.001, "Territories did not stay together.");
+        assert_eq!(worldspace_of(&app, locked_entity), locked_start, "Locked Territory was panned.");
+        assert_eq!(worldspace_of(&app, anchored_entity), anchored_start, "RelativeAnchored Territory was panned.");
+    }
+
+    #[test]
+    fn corner_resize_below_min_size_keeps_opposite_corner_fixed() {
+        let (mut app, window_entity) = motion_app();
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(400.0, 400.0, 600.0, 600.0));
+        let start_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+
+        // North west corner dragged nearly all the way into the south east one.
+        let proposed_expanse = RectKit::from_worldspace(Rect::new(95.0, -100.0, 100.0, -95.0), 1000.0, 1000.0);
+        let resize_direction = ResizeDirection::from_screenspace_change(
+            RectKit::from_worldspace(start_rect, 1000.0, 1000.0).screenspace(), 
+            proposed_expanse.screenspace()
+        ).unwrap();
+        assert!(matches!(resize_direction, ResizeDirection::NorthWest { .. }), "Test resize is not a north west one.");
+        app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+            proposed_expanse, 
+            MoveRequestType::Resize(resize_direction)
+        ));
+        app.update();
+
+        let min_size = GlobalTerritorySettings::default().min_size;
+        let end_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+        assert!(end_rect.size().abs_diff_eq(min_size, 0.001), "Territory was not held to min size: {:?}", end_rect.size());
+        assert!(
+            Vec2::new(end_rect.max.x, end_rect.min.y).abs_diff_eq(Vec2::new(start_rect.max.x, start_rect.min.y), 0.001), 
+            "South east corner moved: {:?}", end_rect
+        );
+    }
+
+    #[test]
+    fn min_size_override_refuses_to_shrink_below_larger_minimum() {
+        let (mut app, window_entity) = motion_app();
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(200.0, 200.0, 600.0, 600.0));
+        app.world_mut().entity_mut(territory_entity).insert(MinSizeOverride(Vec2::splat(300.0)));
+        let start_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+
+        // East edge dragged far enough west that the global min size alone would allow it.
+        let proposed_expanse = RectKit::from_worldspace(
+            Rect::new(start_rect.min.x, start_rect.min.y, start_rect.min.x + 150.0, start_rect.max.y), 
+            1000.0, 1000.0
+        );
+        let resize_direction = ResizeDirection::from_screenspace_change(
+            RectKit::from_worldspace(start_rect, 1000.0, 1000.0).screenspace(), 
+            proposed_expanse.screenspace()
+        ).unwrap();
+        app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+            proposed_expanse, 
+            MoveRequestType::Resize(resize_direction)
+        ));
+        app.update();
+
+        let end_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+        assert!(150.0 > GlobalTerritorySettings::default().min_size.x, "Test resize is already blocked by the global min size.");
+        assert!((end_rect.width() - 300.0).abs() < 0.001, "Territory shrank past its override: {:?}", end_rect.size());
+        assert!((end_rect.min.x - start_rect.min.x).abs() < 0.001, "West edge moved: {:?}", end_rect);
+    }
+
+    #[test]
+    fn pinned_corner_resize_moves_territory_instead() {
+        let (mut app, window_entity) = motion_app();
+        // Minimum size, tucked into the north east corner of the window's margins.
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(977.5, 2.5, 997.5, 22.5));
+        let start_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+
+        // North east corner dragged inward, which can't shrink it any further.
+        let proposed_worldspace = Rect::from_corners(start_rect.min, start_rect.max - Vec2::new(10.0, 10.0));
+        let proposed_expanse = RectKit::from_worldspace(proposed_worldspace, 1000.0, 1000.0);
+        let resize_direction = ResizeDirection::from_screenspace_change(
+            RectKit::from_worldspace(start_rect, 1000.0, 1000.0).screenspace(), 
+            proposed_expanse.screenspace()
+        ).unwrap();
+        assert!(matches!(resize_direction, ResizeDirection::NorthEast { .. }), "Test resize is not a north east one.");
+        app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+            proposed_expanse, 
+            MoveRequestType::Resize(resize_direction)
+        ));
+        app.update();
+
+        let end_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+        assert!(end_rect.size().abs_diff_eq(start_rect.size(), 0.001), "Pinned Territory changed size: {:?}", end_rect.size());
+        assert!(
+            end_rect.center().abs_diff_eq(start_rect.center() - Vec2::new(10.0, 10.0), 0.001), 
+            "Pinned corner did not pan toward the cursor: {:?}", end_rect
+        );
+    }
+
+    #[test]
+    fn north_resize_stops_growing_at_max_size() {
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().max_size = Vec2::new(400.0, 150.0);
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(400.0, 400.0, 600.0, 500.0));
+        let start_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+
+        let proposed_expanse = RectKit::from_worldspace(
+            Rect::from_corners(start_rect.min, start_rect.max + Vec2::new(0.0, 200.0)), 
+            1000.0, 
+            1000.0
+        );
+        let resize_direction = ResizeDirection::from_screenspace_change(
+            RectKit::from_worldspace(start_rect, 1000.0, 1000.0).screenspace(), 
+            proposed_expanse.screenspace()
+        ).unwrap();
+        app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+            proposed_expanse, 
+            MoveRequestType::Resize(resize_direction)
+        ));
+        app.update();
+
+        let end_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+        assert!((end_rect.height() - 150.0).abs() < 0.001, "North resize grew past max size: {:?}", end_rect);
+        assert!((end_rect.min.y - start_rect.min.y).abs() < 0.001, "Southern edge moved during a north resize: {:?}", end_rect);
+    }
+
+    /// Counts log events at or above a level.
+    struct LogCounter(bevy::log::Level, std::sync::Arc<std::sync::atomic::AtomicUsize>);
+    impl<S: bevy::utils::tracing::Subscriber> bevy::log::tracing_subscriber::Layer<S> for LogCounter {
+        fn on_event(&self, event: &bevy::utils::tracing::Event<'_>, _ctx: bevy::log::tracing_subscriber::layer::Context<'_, S>) {
+            if *event.metadata().level() <= self.0 {
+                self.1.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[test]
+    fn move_groups_without_connections_log_no_errors() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        app
+            // Keep every system on this thread, so the error counter sees them.
+            .edit_schedule(Update, |schedule| { 
+                schedule.set_executor_kind(bevy::ecs::schedule::ExecutorKind::SingleThreaded); 
+            });
+        // Neither has any CardinalConnections, which group building has to be fine with.
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let resized_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(500.0, 500.0, 700.0, 700.0));
+        app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(120.0, 100.0, 320.0, 300.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.world_mut().entity_mut(resized_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(500.0, 500.0, 750.0, 700.0), 1000.0, 1000.0), 
+            MoveRequestType::Unknown
+        ));
+
+        let errors = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let subscriber = bevy::log::tracing_subscriber::registry().with(LogCounter(bevy::log::Level::ERROR, errors.clone()));
+        bevy::utils::tracing::subscriber::with_default(subscriber, || {
+            app.update();
+        });
+
+        assert_eq!(errors.load(std::sync::atomic::Ordering::Relaxed), 0, "Group building logged errors for unlinked Territories.");
+        assert_eq!(
+            app.world().get::<Territory>(dragged_entity).unwrap().expanse().screenspace(), 
+            Rect::new(120.0, 100.0, 320.0, 300.0), 
+            "Unlinked drag didn't go through."
+        );
+    }
+
+    #[test]
+    fn dragging_a_territory_carries_its_linked_group_along() {
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        app.add_systems(Update, remove_all_components_of_type::<DragTerritoryGroup>
+            .run_if(any_component_removed::<DragRequest>().or_else(any_component_removed::<MoveRequest>()))
+            .after(TerritoryUpdateMotion));
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let linked_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 300.0));
+        // Linked to the linked one, but not to the dragged one.
+        let far_linked_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 302.5, 502.5, 402.5));
+        app.world_mut().entity_mut(dragged_entity).insert(CardinalConnections { eastern: vec![linked_entity], ..default() });
+        app.world_mut().entity_mut(linked_entity).insert(CardinalConnections { 
+            western: vec![dragged_entity], 
+            southern: vec![far_linked_entity], 
+            ..default() 
+        });
+        app.world_mut().entity_mut(far_linked_entity).insert(CardinalConnections { northern: vec![linked_entity], ..default() });
+
+        let drag_by = |app: &mut App, delta: Vec2| {
+            let dragged_rect = app.world().get::<Territory>(dragged_entity).unwrap().expanse().screenspace();
+            app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(Rect::from_corners(dragged_rect.min + delta, dragged_rect.max + delta), 1000.0, 1000.0), 
+                MoveRequestType::Drag
+            ));
+            app.update();
+        };
+        let screenspace = |app: &App, territory_entity: Entity| {
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace()
+        };
+
+        drag_by(&mut app, Vec2::new(0.0, 50.0));
+        assert_eq!(screenspace(&app, dragged_entity), Rect::new(100.0, 150.0, 300.0, 350.0), "Dragged Territory didn't move.");
+        assert_eq!(screenspace(&app, linked_entity), Rect::new(302.5, 150.0, 502.5, 350.0), "Linked Territory was left behind.");
+        assert_eq!(screenspace(&app, far_linked_entity), Rect::new(302.5, 352.5, 502.5, 452.5), "Territory linked further down was left behind.");
+        assert!(
+            app.world_mut().query_filtered::<(), With<DragTerritoryGroup>>().iter(app.world()).next().is_none(), 
+            "DragTerritoryGroup markers outlived the drag."
+        );
+
+        // The whole group is held inside the Window, not just the dragged Territory.
+        drag_by(&mut app, Vec2::new(600.0, 0.0));
+        assert_eq!(screenspace(&app, linked_entity), Rect::new(797.5, 150.0, 997.5, 350.0), "Group was dragged past the Window's margin.");
+        assert_eq!(screenspace(&app, dragged_entity), Rect::new(595.0, 150.0, 795.0, 350.0), "Dragged Territory split from its group.");
+    }
+
+    #[test]
+    fn group_drag_into_another_territory_leaves_the_group_in_place() {
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let linked_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 300.0));
+        spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 350.0, 502.5, 550.0));
+        app.world_mut().entity_mut(dragged_entity).insert(CardinalConnections { eastern: vec![linked_entity], ..default() });
+        app.world_mut().entity_mut(linked_entity).insert(CardinalConnections { western: vec![dragged_entity], ..default() });
+
+        app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(100.0, 200.0, 300.0, 400.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Territory>(dragged_entity).unwrap().expanse().screenspace(), 
+            Rect::new(100.0, 100.0, 300.0, 300.0), 
+            "Dragged Territory moved without its blocked group."
+        );
+        assert_eq!(
+            app.world().get::<Territory>(linked_entity).unwrap().expanse().screenspace(), 
+            Rect::new(302.5, 100.0, 502.5, 300.0), 
+            "Linked Territory was pushed into another Territory."
+        );
+        assert!(app.world().get::<MoveRequest>(linked_entity).is_none(), "Blocked group drag left a MoveRequest behind.");
+    }
+
+    #[test]
+    fn tile_mode_resizes_move_the_whole_seam() {
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        let resizing_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let below_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 302.5, 300.0, 500.0));
+        // Across the seam from both of them.
+        let across_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 500.0));
+        app.world_mut().entity_mut(resizing_entity).insert(CardinalConnections { 
+            eastern: vec![across_entity], 
+            southern: vec![below_entity], 
+            ..default() 
+        });
+        app.world_mut().entity_mut(below_entity).insert(CardinalConnections { 
+            eastern: vec![across_entity], 
+            northern: vec![resizing_entity], 
+            ..default() 
+        });
+        app.world_mut().entity_mut(across_entity).insert(CardinalConnections { 
+            western: vec![resizing_entity, below_entity], 
+            ..default() 
+        });
+
+        app.world_mut().entity_mut(resizing_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(100.0, 100.0, 350.0, 300.0), 1000.0, 1000.0), 
+            MoveRequestType::Unknown
+        ));
+        app.update();
+
+        let screenspace = |territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace();
+        assert_eq!(screenspace(resizing_entity), Rect::new(100.0, 100.0, 350.0, 300.0), "Resize didn't go through.");
+        assert_eq!(screenspace(below_entity), Rect::new(100.0, 302.5, 350.0, 500.0), "Territory beside the resize didn't line up.");
+        assert_eq!(screenspace(across_entity), Rect::new(352.5, 100.0, 502.5, 500.0), "Territory across the seam wasn't shrunk.");
+    }
+
+    #[test]
+    fn applied_grid_layout_replaces_existing_territories() {
+        let (mut app, window_entity) = motion_app();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut window_root_index = WindowRootIndex::default();
+        window_root_index.insert(window_entity, root_node_entity);
+        let old_territory_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+
+        // The top right quarter overlaps the top left one, and gets cut back to make room for it.
+        let layout = [
+            (Rect::new(0.0, 0.0, 0.5, 0.5), DisplayLibrary::BevyUi),
+            (Rect::new(0.4, 0.0, 1.0, 0.5), DisplayLibrary::BevyUi),
+            (Rect::new(0.0, 0.5, 0.5, 1.0), DisplayLibrary::BevyUi),
+            (Rect::new(0.5, 0.5, 1.0, 1.0), DisplayLibrary::BevyUi)
+        ];
+        app
+            .insert_resource(window_root_index)
+            .init_resource::<TabTextConfig>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_systems(Update, (
+                (move |mut commands: Commands| apply_layout(&mut commands, window_entity, &layout)).run_if(run_once()),
+                despawn_territory,
+                spawn_territory
+            ).chain());
+        app.update();
+
+        assert!(app.world().get_entity(old_territory_entity).is_none(), "Existing Territory was not cleared.");
+        let mut territory_query = app.world_mut().query::<(&Territory, &Parent)>();
+        let territory_rects: Vec<Rect> = territory_query.iter(app.world())
+            .filter(|(_, territory_parent)| territory_parent.get() == window_entity)
+            .map(|(territory, _)| territory.expanse().screenspace())
+            .collect();
+        assert_eq!(territory_rects.len(), 4, "Grid layout did not spawn four Territories.");
+        for (index, rect_a) in territory_rects.iter().enumerate() {
+            for rect_b in &territory_rects[index + 1..] {
+                assert!(rect_a.intersect(*rect_b).is_empty(), "Layout Territories overlap: {:?} {:?}", rect_a, rect_b);
+            }
+        }
+        assert!(territory_rects.contains(&Rect::new(500.0, 0.0, 1000.0, 500.0)), "Overlapping entry was not cut back to its quarter.");
+    }
+
+    #[test]
+    fn duplicate_despawn_requests_despawn_once_without_warnings() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let mut app = App::new();
+        app
+            .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_systems(Update, despawn_territory)
+            .edit_schedule(Update, |schedule| { 
+                schedule.set_executor_kind(bevy::ecs::schedule::ExecutorKind::SingleThreaded); 
+            });
+        let base_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut territory = Territory::empty();
+        territory.base_node = Some(base_node_entity);
+        let territory_entity = app.world_mut().spawn(territory).id();
+
+        for _ in 0..2 {
+            app.world_mut().send_event(TerritoryDespawnRequest { despawned_territory: territory_entity });
+        }
+
+        let warnings = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let subscriber = bevy::log::tracing_subscriber::registry().with(LogCounter(bevy::log::Level::WARN, warnings.clone()));
+        bevy::utils::tracing::subscriber::with_default(subscriber, || {
+            app.update();
+        });
+
+        assert!(app.world().get_entity(territory_entity).is_none(), "Territory was not despawned.");
+        assert!(app.world().get_entity(base_node_entity).is_none(), "Territory base node was not despawned.");
+        assert_eq!(warnings.load(std::sync::atomic::Ordering::Relaxed), 0, "Duplicate despawn requests logged warnings.");
+    }
+
+    #[test]
+    fn territory_tabs_state_follows_territory_lifecycle() {
+        let mut app = App::new();
+        app
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(TerritoryTabsState::Empty)
+            .add_systems(Update, (empty_if_no_territories, track_territory_motion_state).chain());
+        let state = |app: &App| app.world().resource::<State<TerritoryTabsState>>().get().clone();
+
+        let territory_entity = app.world_mut().spawn(Territory::empty()).id();
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::Natural, "Spawning a Territory did not leave Empty.");
+
+        app.world_mut().entity_mut(territory_entity).insert((
+            MotionOrigin::default(), 
+            MoveRequest::new(RectKit::empty(), MoveRequestType::Drag)
+        ));
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::DraggingTerritories, "Starting a drag did not enter DraggingTerritories.");
+
+        // A throttled or rejected frame has no MoveRequest, but the drag is still going.
+        app.world_mut().entity_mut(territory_entity).remove::<MoveRequest>();
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::DraggingTerritories, "A frame without a MoveRequest ended the drag.");
+
+        app.world_mut().entity_mut(territory_entity).remove::<MotionOrigin>();
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::Natural, "Ending the drag did not return to Natural.");
+
+        app.world_mut().entity_mut(territory_entity).insert(MotionOrigin { resizing: true, ..default() });
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::ResizingTerritories, "Starting a resize did not enter ResizingTerritories.");
+
+        app.world_mut().entity_mut(territory_entity).remove::<MotionOrigin>();
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::Natural, "Ending the resize did not return to Natural.");
+
+        app.world_mut().entity_mut(territory_entity).despawn();
+        app.update();
+        app.update();
+        assert_eq!(state(&app), TerritoryTabsState::Empty, "Removing the last Territory did not return to Empty.");
+    }
+
+    #[test]
+    fn territory_tabs_state_changes_are_announced_with_both_sides() {
+        let mut app = App::new();
+        app
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(TerritoryTabsState::Empty)
+            .add_event::<TerritoryTabsStateChanged>()
+            .add_systems(Update, (
+                empty_if_no_territories, 
+                announce_territory_tabs_state_changes
+            ).chain());
+        let drain_changes = |app: &mut App| -> Vec<TerritoryTabsStateChanged> {
+            app.world_mut().resource_mut::<Events<TerritoryTabsStateChanged>>().drain().collect()
+        };
+
+        app.update();
+        assert!(drain_changes(&mut app).is_empty(), "Entering the initial state was announced.");
+
+        let territory_entity = app.world_mut().spawn(Territory::empty()).id();
+        app.update();
+        app.update();
+        assert_eq!(
+            drain_changes(&mut app), 
+            vec![TerritoryTabsStateChanged { from: TerritoryTabsState::Empty, to: TerritoryTabsState::Natural }]
+        );
+
+        app.world_mut().entity_mut(territory_entity).despawn();
+        app.update();
+        app.update();
+        assert_eq!(
+            drain_changes(&mut app), 
+            vec![TerritoryTabsStateChanged { from: TerritoryTabsState::Natural, to: TerritoryTabsState::Empty }],
+            "Natural -> Empty was not announced."
+        );
+    }
+
+    #[test]
+    fn only_the_state_machine_transitions_are_valid() {
+        use TerritoryTabsState::*;
+        assert!(Empty.can_transition_to(&Natural));
+        assert!(Natural.can_transition_to(&DraggingTerritories));
+        assert!(ResizingTerritories.can_transition_to(&Natural));
+        assert!(!DraggingTerritories.can_transition_to(&ResizingTerritories), "Motion states should go back through Natural.");
+        assert!(!MovingTabs.can_transition_to(&Empty), "Losing every Territory mid Tab move should be flagged.");
+    }
+
+    #[test]
+    fn resized_side_snaps_against_neighbor_with_margin() {
+        let (mut app, window_entity) = motion_app();
+        let resizing_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(400.0, 100.0, 600.0, 300.0));
+
+        // Eastern side dragged to just short of the neighbor, inside the snap distance.
+        let start_rect = Rect::new(100.0, 100.0, 300.0, 300.0);
+        let proposed_rect = Rect::new(100.0, 100.0, 396.0, 300.0);
+        app.world_mut().entity_mut(resizing_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(proposed_rect, 1000.0, 1000.0),
+            MoveRequestType::Resize(ResizeDirection::from_screenspace_change(start_rect, proposed_rect).unwrap())
+        ));
+        app.update();
+
+        let settings = GlobalTerritorySettings::default();
+        let resized_rect = app.world().get::<Territory>(resizing_entity).unwrap().expanse().worldspace();
+        let neighbor_rect = app.world().get::<Territory>(neighbor_entity).unwrap().expanse().worldspace();
+        assert!(
+            (neighbor_rect.min.x - resized_rect.max.x - settings.outer_margins.x).abs() < 0.001, 
+            "Resized side did not snap against the neighbor: {:?} next to {:?}", resized_rect, neighbor_rect
+        );
+        assert_eq!(neighbor_rect, RectKit::from_screenspace(Rect::new(400.0, 100.0, 600.0, 300.0), 1000.0, 1000.0).worldspace());
+    }
+
+    #[test]
+    fn each_window_camera_only_renders_its_own_debug_gizmos() {
+        let mut app = App::new();
+        app
+            .init_resource::<WindowRootIndex>()
+            .add_event::<WindowCreated>()
+            .add_systems(Startup, (configure_debug_gizmos::<0>, configure_debug_gizmos::<1>))
+            .add_systems(Update, configure_os_window.run_if(on_event::<WindowCreated>()));
+        // Gizmo groups without the rest of the gizmo rendering plugin.
+        let mut gizmo_store = GizmoConfigStore::default();
+        gizmo_store.insert(GizmoConfig::default(), TerritoryDebugGizmos::<0>);
+        gizmo_store.insert(GizmoConfig::default(), TerritoryDebugGizmos::<1>);
+        app.insert_resource(gizmo_store);
+
+        let window_a = app.world_mut().spawn(Window::default()).id();
+        let window_b = app.world_mut().spawn(Window::default()).id();
+        app.world_mut().send_event(WindowCreated { window: window_a });
+        app.world_mut().send_event(WindowCreated { window: window_b });
+        app.update();
+
+        let render_layer_a = *app.world().get::<WindowRenderLayer>(window_a).unwrap();
+        let render_layer_b = *app.world().get::<WindowRenderLayer>(window_b).unwrap();
+        assert_eq!((render_layer_a.slot(), render_layer_b.slot()), (0, 1), "Windows did not get their own render layer slots.");
+
+        let mut camera_query = app.world_mut().query_filtered::<(&Parent, &RenderLayers), With<TerritoryTabsCamera>>();
+        let mut camera_layers_of = |app: &App, window_entity: Entity| camera_query.iter(app.world())
+            .find(|(camera_parent, _)| camera_parent.get() == window_entity)
+            .map(|(_, camera_layers)| camera_layers.clone())
+            .unwrap();
+        let camera_layers_a = camera_layers_of(&app, window_a);
+        let camera_layers_b = camera_layers_of(&app, window_b);
+
+        let gizmo_store = app.world().resource::<GizmoConfigStore>();
+        let gizmo_layers_a = &gizmo_store.config::<TerritoryDebugGizmos<0>>().0.render_layers;
+        let gizmo_layers_b = &gizmo_store.config::<TerritoryDebugGizmos<1>>().0.render_layers;
+        assert!(camera_layers_a.intersects(gizmo_layers_a), "Window A's camera does not render its own gizmos.");
+        assert!(camera_layers_b.intersects(gizmo_layers_b), "Window B's camera does not render its own gizmos.");
+        assert!(!camera_layers_b.intersects(gizmo_layers_a), "Window B's camera renders Window A's gizmos.");
+        assert!(!camera_layers_a.intersects(gizmo_layers_b), "Window A's camera renders Window B's gizmos.");
+    }
+
+    #[test]
+    fn windows_past_the_debug_gizmo_slots_still_get_their_own_render_layer() {
+        let mut app = App::new();
+        app
+            .init_resource::<WindowRootIndex>()
+            .add_event::<WindowCreated>()
+            .add_systems(Update, configure_os_window.run_if(on_event::<WindowCreated>()));
+
+        let window_entities: Vec<Entity> = (0..DEBUG_GIZMO_SLOTS + 2)
+            .map(|_| app.world_mut().spawn(Window::default()).id())
+            .collect();
+        for window_entity in &window_entities {
+            app.world_mut().send_event(WindowCreated { window: *window_entity });
+        }
+        app.update();
+
+        let mut camera_query = app.world_mut().query_filtered::<(&Parent, &RenderLayers), With<TerritoryTabsCamera>>();
+        let window_layers: Vec<(WindowRenderLayer, RenderLayers)> = window_entities.iter()
+            .map(|window_entity| {
+                let camera_layers = camera_query.iter(app.world())
+                    .find(|(camera_parent, _)| camera_parent.get() == *window_entity)
+                    .map(|(_, camera_layers)| camera_layers.clone())
+                    .unwrap();
+                (*app.world().get::<WindowRenderLayer>(*window_entity).unwrap(), camera_layers)
+            })
+            .collect();
+
+        for (index, (render_layer, camera_layers)) in window_layers.iter().enumerate() {
+            assert!(camera_layers.intersects(&RenderLayers::layer(render_layer.gizmo_layer())), "Window camera does not render its own layer.");
+            for (other_index, (other_render_layer, _)) in window_layers.iter().enumerate() {
+                if other_index == index { continue; }
+                assert_ne!(render_layer.slot(), other_render_layer.slot(), "Two Windows share a render layer slot.");
+                assert!(
+                    !camera_layers.intersects(&RenderLayers::layer(other_render_layer.gizmo_layer())), 
+                    "Window camera renders another Window's layer."
+                );
+            }
+        }
+        assert!(!window_layers.last().unwrap().0.has_debug_gizmos(), "Window past the debug gizmo slots got a gizmo group.");
+    }
+
+    #[test]
+    fn debug_gizmo_color_follows_territory_state() {
+        use bevy::color::palettes::css::{BLUE, GREEN, RED, YELLOW};
+        assert_eq!(debug_gizmo_color(false, false, false), BLUE);
+        assert_eq!(debug_gizmo_color(false, true, false), GREEN, "Focused Territory was not green.");
+        assert_eq!(debug_gizmo_color(false, true, true), YELLOW, "Moving Territory was not yellow over focus.");
+        assert_eq!(debug_gizmo_color(true, true, true), RED, "Locked Territory was not red over everything else.");
+    }
+
+    #[test]
+    fn scroll_resize_grows_every_side_through_the_motion_pipeline() {
+        use bevy::input::mouse::MouseScrollUnit;
+        use crate::resources_ui::WorldMousePosition;
+        use crate::systems_ui::scroll_resize_hovered_territory;
+
+        let (mut app, window_entity) = motion_app();
+        app
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<ActionState<DevControls>>()
+            .add_event::<MouseWheel>()
+            .add_systems(Update, scroll_resize_hovered_territory.in_set(TerritoryInput));
+        app.world_mut().resource_mut::<ActionState<DevControls>>().press(&DevControls::ScrollResize);
+        let margin = GlobalTerritorySettings::default().outer_margins.x;
+
+        let scrolled_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(300.0, 300.0, 500.0, 500.0));
+        let west_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 300.0, 300.0 - margin, 500.0));
+        let south_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(300.0, 500.0 + margin, 500.0, 700.0));
+        let mut mouse_location = app.world_mut().resource_mut::<WorldMousePosition>();
+        mouse_location.window = Some(window_entity);
+        mouse_location.territory = Some(scrolled_entity);
+
+        let scroll = |app: &mut App, lines: f32| {
+            app.world_mut().send_event(MouseWheel { unit: MouseScrollUnit::Line, x: 0.0, y: lines, window: window_entity });
+            app.update();
+        };
+        let screenspace = |app: &App, territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace();
+        let assert_near = |actual: Rect, expected: Rect, message: &str| {
+            assert!(
+                actual.min.abs_diff_eq(expected.min, 0.01) && actual.max.abs_diff_eq(expected.max, 0.01), 
+                "{} {:?} is not {:?}", message, actual, expected
+            );
+        };
+
+        scroll(&mut app, 2.0);
+        assert_near(screenspace(&app, scrolled_entity), Rect::new(280.0, 280.0, 520.0, 520.0), "Scrolled Territory did not grow every side.");
+        assert_near(screenspace(&app, west_entity), Rect::new(100.0, 300.0, 280.0 - margin, 500.0), "Western neighbor was not pushed back.");
+        assert_near(screenspace(&app, south_entity), Rect::new(300.0, 520.0 + margin, 500.0, 700.0), "Southern neighbor was not pushed back.");
+
+        // With the western edge disabled, nothing about an all sides resize goes through.
+        app.world_mut().entity_mut(scrolled_entity).insert(ResizableEdges::NORTH | ResizableEdges::EAST | ResizableEdges::SOUTH);
+        scroll(&mut app, 2.0);
+        assert_near(screenspace(&app, scrolled_entity), Rect::new(280.0, 280.0, 520.0, 520.0), "Scroll resize moved a disabled edge.");
+        assert_near(screenspace(&app, west_entity), Rect::new(100.0, 300.0, 280.0 - margin, 500.0), "Rejected scroll resize still pushed a neighbor.");
+    }
+
+    #[test]
+    fn grid_snap_rounds_drags_but_never_into_a_neighbor() {
+        let (mut app, window_entity) = motion_app();
+        *app.world_mut().resource_mut::<GridSnap>() = GridSnap { enabled: true, cell: Vec2::splat(50.0) };
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        // Off the grid, just east of where the dragged Territory ends up.
+        spawn_motion_territory(&mut app, window_entity, Rect::new(385.0, 100.0, 585.0, 300.0));
+
+        let drag_to = |app: &mut App, screenspace: Rect| {
+            app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(screenspace, 1000.0, 1000.0), 
+                MoveRequestType::Drag
+            ));
+            app.update();
+            app.world().get::<Territory>(dragged_entity).unwrap().expanse().screenspace()
+        };
+
+        assert_eq!(
+            drag_to(&mut app, Rect::new(130.0, 100.0, 330.0, 300.0)), 
+            Rect::new(150.0, 100.0, 350.0, 300.0), 
+            "Drag was not snapped to the grid."
+        );
+        assert_eq!(
+            drag_to(&mut app, Rect::new(180.0, 100.0, 380.0, 300.0)), 
+            Rect::new(180.0, 100.0, 380.0, 300.0), 
+            "Snap pushed the Territory into its neighbor instead of being skipped."
+        );
+    }
+
+    #[test]
+    fn tile_mode_resizes_carry_the_linked_neighbor_along() {
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        let resizing_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        // Linked to the east, one outer margin away.
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 300.0));
+        app.world_mut().entity_mut(resizing_entity).insert(CardinalConnections { eastern: vec![neighbor_entity], ..default() });
+        app.world_mut().entity_mut(neighbor_entity).insert(CardinalConnections { western: vec![resizing_entity], ..default() });
+
+        let resize_east_to = |app: &mut App, east_side: f32| {
+            app.world_mut().entity_mut(resizing_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(Rect::new(100.0, 100.0, east_side, 300.0), 1000.0, 1000.0), 
+                MoveRequestType::Unknown
+            ));
+            app.update();
+            (
+                app.world().get::<Territory>(resizing_entity).unwrap().expanse().screenspace(),
+                app.world().get::<Territory>(neighbor_entity).unwrap().expanse().screenspace()
+            )
+        };
+
+        let (resized_rect, neighbor_rect) = resize_east_to(&mut app, 350.0);
+        assert_eq!(resized_rect, Rect::new(100.0, 100.0, 350.0, 300.0), "Advancing resize didn't go through.");
+        assert_eq!(neighbor_rect, Rect::new(352.5, 100.0, 502.5, 300.0), "Neighbor wasn't shrunk by the same amount.");
+
+        let (resized_rect, neighbor_rect) = resize_east_to(&mut app, 250.0);
+        assert_eq!(resized_rect, Rect::new(100.0, 100.0, 250.0, 300.0), "Retreating resize didn't go through.");
+        assert_eq!(neighbor_rect, Rect::new(252.5, 100.0, 502.5, 300.0), "Neighbor didn't grow to follow the retreat.");
+    }
+
+    #[test]
+    fn tile_mode_seam_is_deferred_along_with_the_resize() {
+        let (mut app, window_entity) = motion_app();
+        app.add_systems(Update, commit_deferred_expanses
+            .run_if(any_with_component::<DeferredExpanse>)
+            .in_set(TerritoryApply)
+            .before(TerritoryUpdateMotion));
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().live_resize = false;
+        let resizing_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 300.0));
+        app.world_mut().entity_mut(resizing_entity).insert((
+            MotionOrigin { resizing: true, ..default() },
+            CardinalConnections { eastern: vec![neighbor_entity], ..default() }
+        ));
+        app.world_mut().entity_mut(neighbor_entity).insert(CardinalConnections { western: vec![resizing_entity], ..default() });
+        let screenspace = |app: &App, territory_entity: Entity| {
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace()
+        };
+
+        app.world_mut().entity_mut(resizing_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(100.0, 100.0, 350.0, 300.0), 1000.0, 1000.0), 
+            MoveRequestType::Unknown
+        ));
+        app.update();
+        assert_eq!(screenspace(&app, neighbor_entity), Rect::new(302.5, 100.0, 502.5, 300.0), "Neighbor moved before the resize was applied.");
+        let deferred_expanse = app.world().get::<DeferredExpanse>(neighbor_entity).expect("Neighbor did not get a DeferredExpanse.");
+        assert_eq!(deferred_expanse.expanse().screenspace(), Rect::new(352.5, 100.0, 502.5, 300.0));
+
+        app.world_mut().entity_mut(resizing_entity).remove::<MotionOrigin>();
+        app.update();
+        assert_eq!(screenspace(&app, resizing_entity), Rect::new(100.0, 100.0, 350.0, 300.0));
+        assert_eq!(screenspace(&app, neighbor_entity), Rect::new(352.5, 100.0, 502.5, 300.0), "Neighbor did not follow once the resize ended.");
+    }
+
+    #[test]
+    fn pushed_neighbor_is_deferred_along_with_the_resize() {
+        let (mut app, window_entity) = motion_app();
+        app.add_systems(Update, commit_deferred_expanses
+            .run_if(any_with_component::<DeferredExpanse>)
+            .in_set(TerritoryApply)
+            .before(TerritoryUpdateMotion));
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().live_resize = false;
+        let resizing_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 300.0));
+        app.world_mut().entity_mut(resizing_entity).insert(MotionOrigin { resizing: true, ..default() });
+        let screenspace = |app: &App, territory_entity: Entity| {
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace()
+        };
+        let resize_east_to = |app: &mut App, east_side: f32| {
+            app.world_mut().entity_mut(resizing_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(Rect::new(100.0, 100.0, east_side, 300.0), 1000.0, 1000.0), 
+                MoveRequestType::Unknown
+            ));
+            app.update();
+        };
+
+        resize_east_to(&mut app, 350.0);
+        assert_eq!(screenspace(&app, neighbor_entity), Rect::new(302.5, 100.0, 502.5, 300.0), "Pushed neighbor moved before the resize ended.");
+        let deferred_expanse = app.world().get::<DeferredExpanse>(neighbor_entity).expect("Pushed neighbor did not get a DeferredExpanse.");
+        assert_eq!(deferred_expanse.expanse().screenspace(), Rect::new(352.5, 100.0, 502.5, 300.0));
+
+        // Pushes are worked out from where the neighbor rests, not from last frame's push.
+        resize_east_to(&mut app, 320.0);
+        assert_eq!(screenspace(&app, neighbor_entity), Rect::new(302.5, 100.0, 502.5, 300.0), "Pushed neighbor moved before the resize ended.");
+        let deferred_expanse = app.world().get::<DeferredExpanse>(neighbor_entity).expect("Pushed neighbor lost its DeferredExpanse.");
+        assert_eq!(deferred_expanse.expanse().screenspace(), Rect::new(322.5, 100.0, 502.5, 300.0), "Push was not worked out again.");
+
+        app.world_mut().entity_mut(resizing_entity).remove::<MotionOrigin>();
+        app.update();
+        assert_eq!(screenspace(&app, resizing_entity), Rect::new(100.0, 100.0, 320.0, 300.0));
+        assert_eq!(screenspace(&app, neighbor_entity), Rect::new(322.5, 100.0, 502.5, 300.0), "Pushed neighbor did not move once the resize ended.");
+    }
+
+    #[test]
+    fn tile_mode_resize_stops_at_the_neighbors_min_size() {
+        let (mut app, window_entity) = motion_app();
+        app.world_mut().resource_mut::<TileMode>().enabled = true;
+        let resizing_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(302.5, 100.0, 502.5, 300.0));
+        app.world_mut().entity_mut(resizing_entity).insert(CardinalConnections { eastern: vec![neighbor_entity], ..default() });
+        app.world_mut().entity_mut(neighbor_entity).insert(CardinalConnections { western: vec![resizing_entity], ..default() });
+        let min_width = app.world().resource::<GlobalTerritorySettings>().min_size.x;
+
+        app.world_mut().entity_mut(resizing_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(100.0, 100.0, 700.0, 300.0), 1000.0, 1000.0), 
+            MoveRequestType::Unknown
+        ));
+        app.update();
+
+        let resized_rect = app.world().get::<Territory>(resizing_entity).unwrap().expanse().screenspace();
+        let neighbor_rect = app.world().get::<Territory>(neighbor_entity).unwrap().expanse().screenspace();
+        assert!((neighbor_rect.width() - min_width).abs() < 0.001, "Neighbor wasn't held at its min size: {:?}", neighbor_rect);
+        assert!((neighbor_rect.max.x - 502.5).abs() < 0.001, "Neighbor was pushed instead of shrunk: {:?}", neighbor_rect);
+        assert!(resized_rect.max.x <= neighbor_rect.min.x, "Resize overlapped its neighbor: {:?} {:?}", resized_rect, neighbor_rect);
+        assert!((neighbor_rect.min.x - resized_rect.max.x - 2.5).abs() < 0.001, "Resize didn't stop flush with its neighbor.");
+    }
+
+    #[test]
+    fn moves_without_live_resize_wait_for_the_motion_to_end() {
+        let (mut app, window_entity) = motion_app();
+        app
+            .init_resource::<ActionState<DevControls>>()
+            .add_systems(Update, commit_deferred_expanses
+                .run_if(any_with_component::<DeferredExpanse>)
+                .in_set(TerritoryApply)
+                .before(TerritoryUpdateMotion));
+        app.world_mut().resource_mut::<GlobalTerritorySettings>().live_resize = false;
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        app.world_mut().entity_mut(dragged_entity).insert(MotionOrigin::default());
+
+        app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(400.0, 400.0, 600.0, 600.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.update();
+        let expanse_of = |app: &App| app.world().get::<Territory>(dragged_entity).unwrap().expanse().screenspace();
+        assert_eq!(expanse_of(&app), Rect::new(100.0, 100.0, 300.0, 300.0), "Territory moved before the drag ended.");
+        let deferred_expanse = app.world().get::<DeferredExpanse>(dragged_entity).expect("Drag did not leave a DeferredExpanse.");
+        assert_eq!(deferred_expanse.expanse().screenspace(), Rect::new(400.0, 400.0, 600.0, 600.0));
+        assert!(app.world().resource::<Events<TerritoryMoved>>().is_empty(), "TerritoryMoved sent for a deferred move.");
+
+        // Unknown-type requests are rejected during processing. The last good DeferredExpanse has to survive it.
+        app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(400.0, 400.0, 600.0, 600.0), 1000.0, 1000.0), 
+            MoveRequestType::Unknown
+        ));
+        app.update();
+        let deferred_expanse = app.world().get::<DeferredExpanse>(dragged_entity).expect("Rejected MoveRequest dropped the DeferredExpanse.");
+        assert_eq!(deferred_expanse.expanse().screenspace(), Rect::new(400.0, 400.0, 600.0, 600.0));
+
+        app.world_mut().entity_mut(dragged_entity).remove::<MotionOrigin>();
+        app.update();
+        assert_eq!(expanse_of(&app), Rect::new(400.0, 400.0, 600.0, 600.0), "Deferred move was not committed when the drag ended.");
+        assert!(app.world().get::<DeferredExpanse>(dragged_entity).is_none(), "DeferredExpanse stuck around after being committed.");
+        assert!(!app.world().resource::<Events<TerritoryMoved>>().is_empty(), "Committed move did not send TerritoryMoved.");
+    }
+
+    #[test]
+    fn drags_near_the_window_edge_snap_to_the_margin_unless_ignored() {
+        let (mut app, window_entity) = motion_app();
+        app.init_resource::<ActionState<DevControls>>();
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+
+        let drag_to = |app: &mut App, screenspace: Rect| {
+            app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(screenspace, 1000.0, 1000.0), 
+                MoveRequestType::Drag
+            ));
+            app.update();
+            app.world().get::<Territory>(dragged_entity).unwrap().expanse().screenspace()
+        };
+
+        assert_eq!(
+            drag_to(&mut app, Rect::new(4.0, 100.0, 204.0, 300.0)), 
+            Rect::new(2.5, 100.0, 202.5, 300.0), 
+            "Drag near the western edge was not snapped to the margin."
+        );
+        assert_eq!(
+            drag_to(&mut app, Rect::new(795.0, 797.0, 995.0, 997.0)), 
+            Rect::new(797.5, 797.5, 997.5, 997.5), 
+            "Drag near a corner did not snap on both axes."
+        );
+        assert_eq!(
+            drag_to(&mut app, Rect::new(400.0, 400.0, 600.0, 600.0)), 
+            Rect::new(400.0, 400.0, 600.0, 600.0), 
+            "Drag out of range was snapped."
+        );
+
+        // Nearly as wide as the Window, so both sides are in range. The nearer one wins.
+        let wide_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(0.0, 100.0, 992.0, 200.0));
+        app.world_mut().entity_mut(wide_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(5.0, 100.0, 997.0, 200.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.update();
+        assert_eq!(
+            app.world().get::<Territory>(wide_entity).unwrap().expanse().screenspace(), 
+            Rect::new(5.5, 100.0, 997.5, 200.0), 
+            "Snap did not prefer the nearest edge."
+        );
+
+        app.world_mut().resource_mut::<ActionState<DevControls>>().press(&DevControls::IgnoreSnap);
+        assert_eq!(
+            drag_to(&mut app, Rect::new(4.0, 300.0, 204.0, 500.0)), 
+            Rect::new(4.0, 300.0, 204.0, 500.0), 
+            "Drag snapped while IgnoreSnap was held."
+        );
+    }
+
+    #[test]
+    fn resized_window_keeps_anchored_fractions_and_pulls_the_rest_in_bounds() {
+        let (mut app, window_entity) = motion_app();
+        app
+            .add_event::<WindowResized>()
+            .add_systems(Update, refit_territories_to_resized_window.run_if(on_event::<WindowResized>()).in_set(TerritoryApply));
+        let anchored_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        app.world_mut().entity_mut(anchored_entity).insert(RelativeAnchored);
+        let loose_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(700.0, 700.0, 900.0, 900.0));
+        let loose_worldspace = app.world().get::<Territory>(loose_entity).unwrap().expanse().worldspace();
+        let maximized_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(2.5, 2.5, 997.5, 997.5));
+        app.world_mut().entity_mut(maximized_entity).insert(Maximized { previous_expanse: RectKit::empty() });
+        // Would hang off the edge of the resized Window, but that's not the Window it's in.
+        let other_window_entity = spawn_test_window(&mut app, 1000.0, 1000.0);
+        let other_entity = spawn_motion_territory(&mut app, other_window_entity, Rect::new(700.0, 700.0, 900.0, 900.0));
+        app.update();
+
+        app.world_mut().get_mut::<Window>(window_entity).unwrap().resolution.set(500.0, 500.0);
+        app.world_mut().send_event(WindowResized { window: window_entity, width: 500.0, height: 500.0 });
+        app.update();
+
+        let expanse_of = |app: &App, territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse();
+        assert_eq!(
+            expanse_of(&app, anchored_entity).screenspace(), 
+            Rect::new(50.0, 50.0, 150.0, 150.0), 
+            "Anchored Territory did not keep its fraction of the Window."
+        );
+        let loose_expanse = expanse_of(&app, loose_entity);
+        assert_eq!(loose_expanse.worldspace().size(), loose_worldspace.size(), "Loose Territory changed size even though it fits.");
+        assert_eq!(
+            loose_expanse.screenspace(), 
+            Rect::new(297.5, 297.5, 497.5, 497.5), 
+            "Loose Territory was not pulled back inside the Window's margins."
+        );
+        assert_eq!(expanse_of(&app, maximized_entity).screenspace(), Rect::new(2.5, 2.5, 497.5, 497.5), "Maximized Territory did not refill the Window.");
+        assert_eq!(
+            expanse_of(&app, other_entity).screenspace(), 
+            Rect::new(700.0, 700.0, 900.0, 900.0), 
+            "Territory in another Window was refit."
+        );
+    }
+
+    #[test]
+    fn dragging_over_a_whole_territory_stops_short_of_it() {
+        let (mut app, window_entity) = motion_app();
+        let large_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 500.0, 500.0));
+        let small_rect = Rect::new(600.0, 250.0, 700.0, 350.0);
+        let small_entity = spawn_motion_territory(&mut app, window_entity, small_rect);
+
+        // One big jump that swallows the small Territory whole, with the large one's center still west of it.
+        app.world_mut().entity_mut(large_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(350.0, 100.0, 750.0, 500.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.update();
+
+        let expanse_of = |app: &App, territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse();
+        assert_eq!(expanse_of(&app, small_entity).screenspace(), small_rect, "Dragging moved the other Territory.");
+        assert!(
+            territories_overlap(app.world().get::<Territory>(large_entity).unwrap(), app.world().get::<Territory>(small_entity).unwrap()).is_none(), 
+            "Small Territory was swallowed by the drag."
+        );
+        assert_eq!(
+            expanse_of(&app, large_entity).screenspace(), 
+            Rect::new(197.5, 100.0, 597.5, 500.0), 
+            "Drag was not stopped a margin short of the side its center was toward."
+        );
+    }
+
+    #[test]
+    fn side_by_side_territories_keep_outer_margins_apart() {
+        let (mut app, window_entity) = motion_app();
+        let outer_margins = app.world().resource::<GlobalTerritorySettings>().outer_margins;
+        let dragged_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let resting_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(400.0, 100.0, 600.0, 300.0));
+
+        // Dragged until flush with the resting Territory, which would leave no gap at all.
+        app.world_mut().entity_mut(dragged_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(200.0, 100.0, 400.0, 300.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.update();
+
+        let screenspace = |app: &App, territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace();
+        let gap = screenspace(&app, resting_entity).min.x - screenspace(&app, dragged_entity).max.x;
+        assert!((gap - outer_margins.x).abs() < 0.001, "Side by side Territories are {} apart, not {}.", gap, outer_margins.x);
+    }
+
+    #[test]
+    fn docked_territories_span_their_side_stack_and_push_others_out() {
+        let (mut app, window_entity) = motion_app();
+        app
+            .init_resource::<ActionState<DevControls>>()
+            .add_event::<WindowResized>()
+            .add_systems(Update, (
+                toggle_dock_focused_territory.in_set(TerritoryInput),
+                (refit_territories_to_resized_window, dock_territories)
+                    .chain()
+                    .in_set(TerritoryApply)
+                    .before(TerritoryUpdateMotion)
+            ));
+        let outer_margins = app.world().resource::<GlobalTerritorySettings>().outer_margins;
+        let panel_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(60.0, 100.0, 260.0, 300.0));
+        let stacked_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(700.0, 100.0, 800.0, 300.0));
+        let neighbor_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(150.0, 400.0, 600.0, 600.0));
+        let screenspace = |app: &App, territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace();
+
+        // Closest to the West edge, so that's where it docks.
+        app.world_mut().entity_mut(panel_entity).insert(Focused);
+        app.world_mut().resource_mut::<ActionState<DevControls>>().press(&DevControls::ToggleDock);
+        app.update();
+        app.world_mut().resource_mut::<ActionState<DevControls>>().release(&DevControls::ToggleDock);
+        app.update();
+        assert_eq!(app.world().get::<Docked>(panel_entity), Some(&Docked(DockSide::West)), "Focused Territory was not docked to its nearest edge.");
+        assert_eq!(screenspace(&app, panel_entity), Rect::new(2.5, 2.5, 202.5, 997.5), "Docked Territory does not span the West side.");
+        assert_eq!(screenspace(&app, neighbor_entity), Rect::new(202.5 + outer_margins.x, 400.0, 600.0, 600.0), "Neighbor was not pulled back out of the dock.");
+
+        // A second West panel stacks inside the first.
+        app.world_mut().entity_mut(stacked_entity).insert(Docked(DockSide::West));
+        app.update();
+        app.update();
+        assert_eq!(screenspace(&app, panel_entity), Rect::new(2.5, 2.5, 202.5, 997.5), "First panel moved when a second docked.");
+        assert_eq!(screenspace(&app, stacked_entity), Rect::new(205.0, 2.5, 305.0, 997.5), "Second panel overlaps the first instead of stacking.");
+        assert_eq!(screenspace(&app, neighbor_entity).min.x, 305.0 + outer_margins.x, "Neighbor was not pushed past the stacked panel.");
+
+        // Both panels follow the Window, keeping their order.
+        app.world_mut().get_mut::<Window>(window_entity).unwrap().resolution.set(1000.0, 600.0);
+        app.world_mut().send_event(WindowResized { window: window_entity, width: 1000.0, height: 600.0 });
+        app.update();
+        assert_eq!(screenspace(&app, panel_entity), Rect::new(2.5, 2.5, 202.5, 597.5), "Docked Territory did not re-dock after a Window resize.");
+        assert_eq!(screenspace(&app, stacked_entity), Rect::new(205.0, 2.5, 305.0, 597.5), "Stacked panel did not re-dock after a Window resize.");
+
+        // Docked panels can't be dragged off their side, or resized anywhere but their inner edge.
+        let request_move = |app: &mut App, territory_entity: Entity, screenspace: Rect| {
+            app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(screenspace, 1000.0, 600.0),
+                MoveRequestType::Unknown
+            ));
+            app.update();
+        };
+        request_move(&mut app, stacked_entity, Rect::new(255.0, 2.5, 355.0, 597.5));
+        assert_eq!(screenspace(&app, stacked_entity), Rect::new(205.0, 2.5, 305.0, 597.5), "Docked Territory was dragged.");
+        request_move(&mut app, stacked_entity, Rect::new(205.0, 100.0, 305.0, 597.5));
+        assert_eq!(screenspace(&app, stacked_entity), Rect::new(205.0, 2.5, 305.0, 597.5), "Docked Territory was resized along its side.");
+        request_move(&mut app, stacked_entity, Rect::new(205.0, 2.5, 335.0, 597.5));
+        assert_eq!(screenspace(&app, stacked_entity), Rect::new(205.0, 2.5, 335.0, 597.5), "Docked Territory's inner edge was not resized.");
+
+        // Undocking the outer panel moves the inner one out to the edge, and the undocked one is free to move again.
+        app.world_mut().resource_mut::<ActionState<DevControls>>().press(&DevControls::ToggleDock);
+        app.update();
+        app.world_mut().resource_mut::<ActionState<DevControls>>().release(&DevControls::ToggleDock);
+        assert!(app.world().get::<Docked>(panel_entity).is_none(), "ToggleDock did not undock the docked Territory.");
+        app.update();
+        assert_eq!(screenspace(&app, stacked_entity), Rect::new(2.5, 2.5, 132.5, 597.5), "Inner panel did not restack after the outer one undocked.");
+        let undocked_rect = screenspace(&app, panel_entity);
+        let dragged_rect = Rect::from_center_size(Vec2::new(800.0, 300.0), undocked_rect.size());
+        request_move(&mut app, panel_entity, dragged_rect);
+        assert_eq!(screenspace(&app, panel_entity), dragged_rect, "Undocked Territory could not be dragged.");
+    }
+
+    #[test]
+    fn applied_move_sends_territory_moved_only_on_change() {
+        let (mut app, window_entity) = motion_app();
+        let start_rect = Rect::new(100.0, 100.0, 300.0, 300.0);
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, start_rect);
+
+        let mut move_to = |app: &mut App, screenspace: Rect| {
+            app.world_mut().entity_mut(territory_entity).insert(MoveRequest::new(
+                RectKit::from_screenspace(screenspace, 1000.0, 1000.0), 
+                MoveRequestType::Drag
+            ));
+            app.update();
+            app.world_mut().resource_mut::<Events<TerritoryMoved>>().drain().collect::<Vec<_>>()
+        };
+
+        let moved_rect = Rect::new(150.0, 100.0, 350.0, 300.0);
+        let moved_events = move_to(&mut app, moved_rect);
+        assert_eq!(moved_events.len(), 1, "Applied move did not send exactly one TerritoryMoved.");
+        assert_eq!(moved_events[0].territory, territory_entity);
+        assert_eq!(moved_events[0].window, window_entity, "TerritoryMoved carried the wrong Window.");
+        assert_eq!(moved_events[0].old.screenspace(), start_rect, "TerritoryMoved carried the wrong old expanse.");
+        assert_eq!(moved_events[0].new.screenspace(), moved_rect, "TerritoryMoved carried the wrong new expanse.");
+
+        assert!(move_to(&mut app, moved_rect).is_empty(), "TerritoryMoved was sent for a move that changed nothing.");
+    }
+
+    #[test]
+    fn double_click_maximizes_and_restore_stays_in_resized_window() {
+        let (mut app, window_entity) = motion_app();
+        app
+            .init_resource::<Time>()
+            .add_event::<ToggleMaximizeTerritory>()
+            .add_systems(Update, (maximize_on_drag_node_double_click, toggle_maximized_territories).chain().in_set(TerritoryInput));
+        let start_rect = Rect::new(500.0, 500.0, 900.0, 900.0);
+        let territory_entity = spawn_motion_territory(&mut app, window_entity, start_rect);
+        let other_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let drag_node_entity = app.world_mut().spawn((Interaction::None, TerritoryDragNode)).id();
+        app.world_mut().get_mut::<Territory>(territory_entity).unwrap().drag_node = Some(drag_node_entity);
+
+        let click = |app: &mut App| {
+            *app.world_mut().get_mut::<Interaction>(drag_node_entity).unwrap() = Interaction::Pressed;
+            app.update();
+            *app.world_mut().get_mut::<Interaction>(drag_node_entity).unwrap() = Interaction::None;
+            app.update();
+        };
+
+        // Too slow to be a double-click.
+        click(&mut app);
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs(1));
+        click(&mut app);
+        assert!(app.world().get::<Maximized>(territory_entity).is_none(), "Two slow clicks maximized the Territory.");
+
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(200));
+        click(&mut app);
+        let outer_margins = GlobalTerritorySettings::default().outer_margins;
+        assert_eq!(
+            app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace(), 
+            Rect::from_corners(outer_margins, Vec2::splat(1000.0) - outer_margins), 
+            "Double-click did not maximize the Territory to fill the window."
+        );
+
+        // Others move freely over a maximized Territory, and don't push it.
+        let other_proposed = RectKit::from_screenspace(Rect::new(150.0, 100.0, 350.0, 300.0), 1000.0, 1000.0);
+        app.world_mut().entity_mut(other_entity).insert(MoveRequest::new(other_proposed, MoveRequestType::Drag));
+        app.update();
+        assert_eq!(app.world().get::<Territory>(other_entity).unwrap().expanse().screenspace(), other_proposed.screenspace());
+
+        // Window shrinks while maximized, so the old spot is now off screen.
+        app.world_mut().get_mut::<Window>(window_entity).unwrap().resolution.set(600.0, 600.0);
+        app.world_mut().send_event(ToggleMaximizeTerritory { territory: territory_entity });
+        app.update();
+        assert!(app.world().get::<Maximized>(territory_entity).is_none(), "Territory was not restored.");
+        let restored_rect = app.world().get::<Territory>(territory_entity).unwrap().expanse().worldspace();
+        let window_rect = Rect::from_center_size(Vec2::ZERO, Vec2::splat(600.0) - outer_margins * 2.0);
+        assert!(
+            window_rect.contains(restored_rect.min) && window_rect.contains(restored_rect.max), 
+            "Restored Territory {:?} is outside the resized window.", restored_rect
+        );
+        assert_eq!(restored_rect.size(), start_rect.size(), "Restored Territory changed size even though it fits.");
+    }
+
+    #[test]
+    fn connections_follow_adjacent_territories() {
+        let (mut app, window_entity) = connected_motion_app();
+        let margin = GlobalTerritorySettings::default().outer_margins.x;
+
+        let west_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        // Half a pixel off from exactly one margin away, which still counts.
+        let east_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(300.0 + margin + 0.5, 150.0, 500.0, 290.0));
+        let south_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(100.0, 300.0 + margin, 300.0, 500.0));
+        // Only touches the western Territory's corner.
+        let corner_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(300.0 + margin, 300.0 + margin + 100.0, 500.0, 700.0));
+        app.update();
+
+        let connections = |app: &App, territory_entity: Entity| app.world().get::<CardinalConnections>(territory_entity).unwrap().clone();
+        let west = connections(&app, west_entity);
+        assert_eq!(west.eastern(), vec![east_entity], "Side by side Territories were not linked east to west.");
+        assert_eq!(west.southern(), vec![south_entity], "Stacked Territories were not linked north to south.");
+        assert!(west.northern().is_empty() && west.western().is_empty());
+        assert_eq!(connections(&app, east_entity).western(), vec![west_entity]);
+        assert_eq!(connections(&app, south_entity).northern(), vec![west_entity]);
+        assert!(!connections(&app, corner_entity).get_all_vec().contains(&west_entity), "Corner touching Territories were linked.");
+
+        // Moving the eastern Territory away clears the stale link.
+        app.world_mut().entity_mut(east_entity).insert(MoveRequest::new(
+            RectKit::from_screenspace(Rect::new(600.0, 150.0, 800.0, 290.0), 1000.0, 1000.0), 
+            MoveRequestType::Drag
+        ));
+        app.update();
+        assert!(connections(&app, west_entity).eastern().is_empty(), "Stale link was kept after the neighbor moved away.");
+        assert!(connections(&app, east_entity).western().is_empty());
+    }
+
+    #[test]
+    fn focus_moves_to_neighbor_in_pressed_direction() {
+        let (mut app, window_entity) = connected_motion_app();
+        app
+            .init_resource::<ActionState<DevControls>>()
+            .add_systems(Update, focus_neighbor_territory.in_set(TerritoryInput));
+        app.world_mut().entity_mut(window_entity).insert(FocusedTerritory::default());
+        let margin = GlobalTerritorySettings::default().outer_margins.x;
+
+        let west_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let east_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(300.0 + margin, 100.0, 500.0, 300.0));
+        // Far below, with nothing connecting it.
+        let far_south_entity = spawn_motion_territory(&mut app, window_entity, Rect::new(100.0, 600.0, 300.0, 800.0));
+        app.world_mut().entity_mut(west_entity).insert(Focused);
+        app.update();
+
+        let press = |app: &mut App, action: DevControls| {
+            app.world_mut().resource_mut::<ActionState<DevControls>>().press(&action);
+            app.update();
+            app.world_mut().resource_mut::<ActionState<DevControls>>().release(&action);
+        };
+        let focused = |app: &mut App| {
+            let focused: Vec<Entity> = app.world_mut().query_filtered::<Entity, With<Focused>>().iter(app.world()).collect();
+            assert_eq!(focused.len(), 1, "Focused marker was not unique.");
+            assert_eq!(app.world().get::<FocusedTerritory>(window_entity).unwrap().get(), Some(focused[0]), "Window focus disagrees with the marker.");
+            focused[0]
+        };
+
+        press(&mut app, DevControls::FocusEast);
+        assert_eq!(focused(&mut app), east_entity, "Focus did not move to the connected eastern neighbor.");
+        press(&mut app, DevControls::FocusEast);
+        assert_eq!(focused(&mut app), east_entity, "Focus moved with no neighbor in that direction.");
+        press(&mut app, DevControls::FocusWest);
+        assert_eq!(focused(&mut app), west_entity);
+        press(&mut app, DevControls::FocusSouth);
+        assert_eq!(focused(&mut app), far_south_entity, "Focus did not fall back to the nearest Territory past the side.");
+        press(&mut app, DevControls::FocusNorth);
+        assert_eq!(focused(&mut app), west_entity, "Focus did not find its way back without connections.");
+    }
+
+    #[test]
+    fn territory_graph_answers_neighbors_by_direction() {
+        let (mut app, window_entity) = connected_motion_app();
+        let margin = GlobalTerritorySettings::default().outer_margins.x;
+
+        let west_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(100.0, 100.0, 300.0, 500.0));
+        // Two Territories stacked along the western one's eastern side. The lower one is closer to its center.
+        let upper_east_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(300.0 + margin, 100.0, 500.0, 200.0));
+        let lower_east_entity = spawn_connected_territory(&mut app, window_entity, Rect::new(300.0 + margin, 200.0 + margin, 500.0, 400.0));
+        app.update();
+
+        let east = ResizeDirection::East { eastward_magnitude: ResizeMagnitude::None };
+        let west = ResizeDirection::West { westward_magnitude: ResizeMagnitude::None };
+        let mut system_state: bevy::ecs::system::SystemState<TerritoryGraph> = bevy::ecs::system::SystemState::new(app.world_mut());
+        let graph = system_state.get(app.world());
+
+        let mut eastern = graph.neighbors(west_entity, east);
+        eastern.sort();
+        let mut expected = vec![upper_east_entity, lower_east_entity];
+        expected.sort();
+        assert_eq!(eastern, expected, "Both eastern neighbors were not found.");
+        assert_eq!(graph.nearest_neighbor(west_entity, east), Some(lower_east_entity), "Nearest eastern neighbor was not picked.");
+        assert_eq!(graph.nearest_neighbor(upper_east_entity, west), Some(west_entity));
+        assert!(graph.neighbors(west_entity, west).is_empty(), "Found a neighbor where there are none.");
+        assert!(graph.are_neighbors(lower_east_entity, west_entity));
+        assert!(!graph.are_neighbors(upper_east_entity, window_entity), "A Window counted as a neighbor.");
+    }
+
+    #[test]
+    fn territory_stack_goes_front_to_back_per_window() {
+        let (mut app, window_entity) = motion_app();
+        let spawn_layered = |app: &mut App, window_entity: Entity, z_index: Option<ZIndex>| {
+            let territory_entity = spawn_motion_territory(app, window_entity, Rect::new(100.0, 100.0, 300.0, 300.0));
+            if let Some(z_index) = z_index {
+                let base_node_entity = app.world_mut().spawn((z_index, TerritoryBaseNode)).id();
+                app.world_mut().get_mut::<Territory>(territory_entity).unwrap().base_node = Some(base_node_entity);
+            }
+            territory_entity
+        };
+        let bottom_entity = spawn_layered(&mut app, window_entity, Some(ZIndex::Local(0)));
+        let no_base_node_entity = spawn_layered(&mut app, window_entity, None);
+        let focused_entity = spawn_layered(&mut app, window_entity, Some(ZIndex::Local(2)));
+        let floating_entity = spawn_layered(&mut app, window_entity, Some(ZIndex::Local(1)));
+        let other_window_entity = spawn_test_window(&mut app, 1000.0, 1000.0);
+        let other_window_territory = spawn_layered(&mut app, other_window_entity, Some(ZIndex::Local(5)));
+
+        let mut system_state: bevy::ecs::system::SystemState<TerritoryStack> = bevy::ecs::system::SystemState::new(app.world_mut());
+        let territory_stack = system_state.get(app.world());
+        let in_window = |window_entity: Entity| territory_stack.territories_in_window(window_entity)
+            .map(|(territory_entity, _)| territory_entity)
+            .collect::<Vec<Entity>>();
+
+        assert_eq!(
+            in_window(window_entity), 
+            vec![focused_entity, floating_entity, no_base_node_entity, bottom_entity], 
+            "Territories were not front to back, with ties latest first."
+        );
+        assert_eq!(in_window(other_window_entity), vec![other_window_territory], "Another Window's Territories leaked in.");
+        assert!(in_window(focused_entity).is_empty(), "Something that isn't a Window had Territories.");
+    }
+
+    #[test]
+    fn combined_territories_merge_tabs_clamp_and_relink() {
+        let (mut app, window_entity) = motion_app();
+        app
+            .add_event::<CombineTerritoriesRequest>()
+            .add_event::<TerritoryDespawnRequest>()
+            .add_event::<TerritoryDespawnRequested>()
+            .add_systems(Update, (combine_requested_territories, despawn_territory, rebuild_cardinal_connections).chain());
+        let margin = GlobalTerritorySettings::default().outer_margins.x;
+
+        let spawn_with_tab = |app: &mut App, screenspace: Rect| {
+            let territory_entity = spawn_motion_territory(app, window_entity, screenspace);
+            let tab_entity = app.world_mut().spawn(Tab { active: true, ..Tab::build_from_type(TabType::ECS) }).id();
+            app.world_mut().entity_mut(territory_entity).add_child(tab_entity).insert(CardinalConnections::default());
+            app.world_mut().get_mut::<Territory>(territory_entity).unwrap().tabs = vec![tab_entity];
+            (territory_entity, tab_entity)
+        };
+        let (west_entity, west_tab) = spawn_with_tab(&mut app, Rect::new(100.0, 100.0, 300.0, 300.0));
+        // Pokes out past the eastern edge of the Window.
+        let (east_entity, east_tab) = spawn_with_tab(&mut app, Rect::new(300.0 + margin, 100.0, 1100.0, 300.0));
+        let (south_entity, _) = spawn_with_tab(&mut app, Rect::new(100.0, 300.0 + margin, 1000.0, 500.0));
+        app.update();
+
+        let combine = |app: &mut App, surviving: Entity, absorbed: Entity| {
+            app.world_mut().send_event(CombineTerritoriesRequest { surviving, absorbed });
+            app.update();
+            app.update();
+        };
+        let screenspace = |app: &App, territory_entity: Entity| app.world().get::<Territory>(territory_entity).unwrap().expanse().screenspace();
+
+        let drain_moved = |app: &mut App| {
+            app.world_mut().resource_mut::<Events<TerritoryMoved>>().drain().collect::<Vec<_>>()
+        };
+        drain_moved(&mut app);
+
+        // Covering both would swallow the eastern Territory.
+        combine(&mut app, west_entity, south_entity);
+        assert!(app.world().get_entity(south_entity).is_some(), "Combine that overlapped another Territory went through.");
+        assert_eq!(screenspace(&app, west_entity), Rect::new(100.0, 100.0, 300.0, 300.0));
+        assert!(drain_moved(&mut app).is_empty(), "TerritoryMoved sent for a combine that was thrown out.");
+
+        combine(&mut app, west_entity, east_entity);
+        assert!(app.world().get_entity(east_entity).is_none(), "Emptied Territory was not despawned.");
+        assert_eq!(screenspace(&app, west_entity), Rect::new(100.0, 100.0, 1000.0, 300.0), "Combined rect was not clamped to the Window.");
+        let moved_events = drain_moved(&mut app);
+        assert_eq!(moved_events.len(), 1, "Combine did not send exactly one TerritoryMoved.");
+        assert_eq!(moved_events[0].territory, west_entity, "TerritoryMoved was not sent for the surviving Territory.");
+        assert_eq!(moved_events[0].window, window_entity, "TerritoryMoved carried the wrong Window.");
+        assert_eq!(moved_events[0].old.screenspace(), Rect::new(100.0, 100.0, 300.0, 300.0), "TerritoryMoved carried the wrong old expanse.");
+        assert_eq!(moved_events[0].new.screenspace(), Rect::new(100.0, 100.0, 1000.0, 300.0), "TerritoryMoved carried the wrong new expanse.");
+        let west = app.world().get::<Territory>(west_entity).unwrap();
+        assert_eq!(west.tabs(), &vec![west_tab, east_tab], "Tabs were not moved into the surviving Territory.");
+        assert_eq!(app.world().get::<Parent>(east_tab).map(Parent::get), Some(west_entity), "Moved Tab was not reparented.");
+        assert!(!app.world().get::<Tab>(east_tab).unwrap().active, "Two tabs were left active after combining.");
+        assert_eq!(
+            app.world().get::<CardinalConnections>(south_entity).unwrap().northern(), 
+            vec![west_entity], 
+            "Connections were not rebuilt after combining."
+        );
+    }
+
+    #[test]
+    fn territory_dragged_into_smaller_window_keeps_relative_rect() {
+        let mut app = App::new();
+        app
+            .init_resource::<WindowRootIndex>()
+            .add_systems(Update, (territory_drag_to_other_window, territory_hand_off_requests).chain());
+
+        let spawn_window = |app: &mut App, width: f32, height: f32| {
+            let mut window = Window::default();
+            window.resolution.set(width, height);
+            let window_entity = app.world_mut().spawn((window, TerritoryTabs, FocusedTerritory::default())).id();
+            let root_node_entity = app.world_mut().spawn(Node::default()).id();
+            app.world_mut().resource_mut::<WindowRootIndex>().insert(window_entity, root_node_entity);
+            (window_entity, root_node_entity)
+        };
+        let (source_window, source_root) = spawn_window(&mut app, 1000.0, 1000.0);
+        let (destination_window, destination_root) = spawn_window(&mut app, 500.0, 400.0);
+
+        let base_node_entity = app.world_mut().spawn(Node::default()).id();
+        app.world_mut().entity_mut(source_root).add_child(base_node_entity);
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(Rect::new(100.0, 200.0, 500.0, 600.0), 1000.0, 1000.0);
+        territory.base_node = Some(base_node_entity);
+        let start_relative = territory.expanse().relative_worldspace();
+        let territory_entity = app.world_mut().spawn((territory, CardinalConnections::default())).id();
+        app.world_mut().entity_mut(source_window).add_child(territory_entity);
+        app.world_mut().get_mut::<FocusedTerritory>(source_window).unwrap().0 = Some(territory_entity);
+
+        let drag_request = DragRequest::new(RectKit::from_screenspace(Rect::new(110.0, 200.0, 510.0, 600.0), 1000.0, 1000.0), Vec2::new(10.0, 0.0));
+
+        // Cursor still in the source window, so this is a normal drag.
+        app.world_mut().get_mut::<Window>(source_window).unwrap().set_cursor_position(Some(Vec2::new(300.0, 300.0)));
+        app.world_mut().entity_mut(territory_entity).insert(drag_request.clone());
+        app.update();
+        assert_eq!(app.world().get::<Parent>(territory_entity).unwrap().get(), source_window, "Territory left a window the cursor was still in.");
+        app.world_mut().entity_mut(territory_entity).remove::<MoveRequest>();
+
+        // Cursor crosses over into the destination window.
+        app.world_mut().get_mut::<Window>(source_window).unwrap().set_cursor_position(None);
+        app.world_mut().get_mut::<Window>(destination_window).unwrap().set_cursor_position(Some(Vec2::new(20.0, 20.0)));
+        app.world_mut().entity_mut(territory_entity).insert(drag_request);
+        app.update();
+
+        assert_eq!(app.world().get::<Parent>(territory_entity).unwrap().get(), destination_window, "Territory was not moved to the new window.");
+        assert_eq!(app.world().get::<Parent>(base_node_entity).unwrap().get(), destination_root, "Base node was not moved to the new root node.");
+        assert!(app.world().get::<MoveRequest>(territory_entity).is_none(), "Drag from the old window was still applied.");
+
+        let moved_expanse = app.world().get::<Territory>(territory_entity).unwrap().expanse();
+        assert!(moved_expanse.relative_worldspace().min.abs_diff_eq(start_relative.min, 0.0001));
+        assert!(moved_expanse.relative_worldspace().max.abs_diff_eq(start_relative.max, 0.0001));
+        assert!(moved_expanse.screenspace().min.abs_diff_eq(Vec2::new(50.0, 80.0), 0.01), "Placement used absolute screenspace: {:?}", moved_expanse.screenspace());
+        assert!(moved_expanse.screenspace().max.abs_diff_eq(Vec2::new(250.0, 240.0), 0.01), "Placement used absolute screenspace: {:?}", moved_expanse.screenspace());
+
+        assert_eq!(app.world().get::<FocusedTerritory>(source_window).unwrap().get(), None);
+        assert_eq!(app.world().get::<FocusedTerritory>(destination_window).unwrap().get(), Some(territory_entity));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saved_layout_round_trips_through_ron() {
+        let mut app = App::new();
+        app
+            .init_resource::<WindowRootIndex>()
+            .init_resource::<TabTextConfig>()
+            .init_resource::<GlobalTerritorySettings>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<TerritorySpawnRejected>()
+            .add_systems(Update, spawn_territory.run_if(on_event::<TerritorySpawnRequest>()).in_set(TerritoryDisplay));
+        // Same order as the plugin, so the rebuild gets a chance to overwrite restored connections.
+        configure_territory_sets(&mut app);
+        add_cardinal_connection_systems(&mut app);
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        let window_entity = app.world_mut().spawn((window, TerritoryTabs)).id();
+        let root_node_entity = app.world_mut().spawn(Node::default()).id();
+        app.world_mut().resource_mut::<WindowRootIndex>().insert(window_entity, root_node_entity);
+
+        let screenspace_rects = [
+            Rect::new(0.0, 0.0, 400.0, 300.0),
+            Rect::new(420.0, 0.0, 1000.0, 300.0),
+            Rect::new(0.0, 320.0, 1000.0, 1000.0)
+        ];
+        let saved_entities: Vec<Entity> = screenspace_rects.iter()
+            .map(|screenspace_rect| {
+                let mut territory = Territory::empty();
+                territory.expanse = RectKit::from_screenspace(*screenspace_rect, 1000.0, 1000.0);
+                let territory_entity = app.world_mut().spawn((territory, DisplayLibrary::BevyEgui, ResizableEdges::default())).id();
+                app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+                territory_entity
+            })
+            .collect();
+        app.world_mut().entity_mut(saved_entities[0]).insert(CardinalConnections { eastern: vec![saved_entities[1]], ..default() });
+        let saved_rects: Vec<Rect> = saved_entities.iter()
+            .map(|territory_entity| app.world().get::<Territory>(*territory_entity).unwrap().expanse().relative_worldspace())
+            .collect();
+
+        let layout_path = std::env::temp_dir().join(format!("megalith_layout_round_trip_{}.ron", std::process::id()));
+        save_layout(app.world_mut(), window_entity, &layout_path).unwrap();
+
+        for territory_entity in saved_entities {
+            app.world_mut().entity_mut(territory_entity).despawn_recursive();
+        }
+        assert_eq!(load_layout(app.world_mut(), window_entity, &layout_path).unwrap(), 3);
+        std::fs::remove_file(&layout_path).unwrap();
+        app.update();
+
+        let loaded: Vec<(Entity, Rect, DisplayLibrary)> = app.world_mut()
+            .query::<(Entity, &Territory, &DisplayLibrary)>()
+            .iter(app.world())
+            .map(|(territory_entity, territory, display_library)| (territory_entity, territory.expanse().relative_worldspace(), *display_library))
+            .collect();
+        assert_eq!(loaded.len(), 3, "Loading did not spawn every saved Territory.");
+        let loaded_entity_at = |saved_rect: Rect| loaded.iter()
+            .find(|(_, loaded_rect, _)| loaded_rect.min.abs_diff_eq(saved_rect.min, 0.0001) && loaded_rect.max.abs_diff_eq(saved_rect.max, 0.0001))
+            .map(|(territory_entity, _, display_library)| {
+                assert_eq!(*display_library, DisplayLibrary::BevyEgui);
+                *territory_entity
+            })
+            .unwrap_or_else(|| panic!("No loaded Territory at {:?}", saved_rect));
+        let loaded_entities: Vec<Entity> = saved_rects.iter().map(|saved_rect| loaded_entity_at(*saved_rect)).collect();
+
+        let restored_connections = app.world().get::<CardinalConnections>(loaded_entities[0]).unwrap();
+        assert_eq!(restored_connections.eastern(), vec![loaded_entities[1]], "Saved connection was not restored to the reloaded neighbor.");
+
+        // The gap between them is wider than the outer margins, so a rebuild would have dropped the link.
+        app.update();
+        let restored_connections = app.world().get::<CardinalConnections>(loaded_entities[0]).unwrap();
+        assert_eq!(restored_connections.eastern(), vec![loaded_entities[1]], "Restored connection did not survive the next frame.");
+    }
+}
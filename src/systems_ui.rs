@@ -1,8 +1,9 @@
 //! Old code to be refactored later.
 
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
-use bevy::window::*;
-use bevy::render::camera::*;
+use bevy::utils::HashSet;
+use leafwing_input_manager::prelude::ActionState;
 
 use crate::components_ui::*;
 use crate::input_manager::*;
@@ -22,60 +23,175 @@ pub fn initialize_ui_resources (mut commands: Commands) {
     commands.init_resource::<WorldMousePosition>();
 }
 
-// Debug system displaying all the gizmos
-pub fn display_debug_gizmos (
-    mut gizmos: Gizmos,
-    territory_query: Query<&Territory>
-) {
-    for territory in & territory_query {
-        gizmos.rect_2d(
-            territory.expanse.worldspace().center(), 
-            0.0,
-            territory.expanse.worldspace().size(),
-            bevy::color::palettes::css::BLUE
-        );
+/// How a hovered [`Territory`] stacks up against others under the cursor.
+#[derive(Clone, Copy)]
+struct HoverRank {
+    /// Global, then local, [`ZIndex`] of the base node. Territories without one sit at zero.
+    z_index: (i32, i32),
+    /// **Worldspace** area, so a smaller Territory sitting inside a bigger one still wins.
+    area: f32
+}
+impl HoverRank {
+    fn of(territory: &Territory, base_node_z_index: Option<&ZIndex>) -> Self {
+        let z_index = match base_node_z_index {
+            Some(ZIndex::Global(z_index)) => (*z_index, 0),
+            Some(ZIndex::Local(z_index)) => (0, *z_index),
+            None => (0, 0)
+        };
+        let size = territory.expanse.worldspace().size();
+        HoverRank { z_index, area: size.x * size.y }
+    }
+
+    /// Drawn higher, or at the same height and no bigger. Ties go to the later Territory.
+    fn is_above(&self, other: &HoverRank) -> bool {
+        self.z_index > other.z_index || (self.z_index == other.z_index && self.area <= other.area)
     }
 }
 
 // Get the Screenspace / Worldspace coordinates of the mouse, 
 // and optionally the window / territory / tab it is in.
 // Runs all of the time. Why does everything need different coordinate systems??
+// Without a cursor, the first touch in the window stands in for it.
+#[allow(clippy::too_many_arguments)]
 pub fn get_mouse_location(
     mut mouse_location_resource: ResMut<WorldMousePosition>,
-    cameras_query: Query<(&Camera, &GlobalTransform), With<MouseSeekingCamera>>,
-    windows_query: Query<&Window>,
-    territories_query: Query<(Entity, &Parent, &Territory)>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    window_root_index: Res<WindowRootIndex>,
+    window_touches: Option<Res<WindowTouches>>,
+    mut windows_query: Query<(Entity, &Window, Option<&Children>, &mut WindowCursor)>,
+    hit_grid_query: Query<Ref<TerritoryHitGrid>>,
+    root_node_query: Query<&TargetCamera, With<TerritoryTabsUIRoot>>,
+    cameras_query: Query<(&Camera, &GlobalTransform), With<TerritoryTabsCamera>>,
+    territories_query: Query<(Entity, &Territory)>,
+    base_node_query: Query<&ZIndex, With<TerritoryBaseNode>>,
     // TODO: Tab query here later!
+    mut windows_missing_camera: Local<HashSet<Entity>>
 ) {
     // Reset mouse info so we don't keep around old data.
     // TODO: Move mouse info from resource to events
     mouse_location_resource.window = None;
     mouse_location_resource.territory = None;
+    mouse_location_resource.content_territory = None;
     mouse_location_resource.tab = None;
 
-    for (camera, camera_transform) in & cameras_query {    
-        for window in & windows_query {
-            match camera.target {
-                RenderTarget::Window(WindowRef::Entity(entity)) => {
-                    if let Some(camera_mouse_position) = window.cursor_position()
-                        .and_then(|cursor| camera.viewport_to_world_2d(
+    for (window_entity, window, window_children, mut window_cursor) in &mut windows_query {
+        let mut new_cursor = WindowCursor::default();
+        let hit_grid = hit_grid_query.get(window_entity).ok();
+
+        let pointer_position = match &window_touches {
+            Some(window_touches) => window_touches.pointer(window_entity, window).map(|(_, position)| position),
+            None => window.cursor_position()
+        };
+
+        // Each window only trusts the camera its own root node is rendered by.
+        if let Some(screenspace_pos) = pointer_position {
+            let window_camera = window_root_index.root_of(window_entity)
+                .and_then(|root_node_entity| root_node_query.get(root_node_entity).ok())
+                .and_then(|target_camera| cameras_query.get(target_camera.entity()).ok());
+
+            match window_camera {
+                Some((camera, camera_transform)) => {
+                    windows_missing_camera.remove(&window_entity);
+                    new_cursor.screenspace_pos = Some(screenspace_pos);
+
+                    // The cursor can be in the window but outside the camera's viewport. 
+                    // That's normal, so there's no world position and nothing to warn about.
+                    new_cursor.worldspace_pos = camera.logical_viewport_rect()
+                        .filter(|viewport_rect| viewport_rect.contains(screenspace_pos))
+                        .and_then(|viewport_rect| camera.viewport_to_world_2d(
                             camera_transform, 
-                            cursor))
-                        .map(|ray| ray) {
-
-                        mouse_location_resource.screenspace_pos = window.cursor_position().unwrap();
-                        mouse_location_resource.worldspace_pos = camera_mouse_position;
-                        mouse_location_resource.window = Some(entity);
-                        
-                        for (entity_territory, parent, territory) in & territories_query {
-                            if parent.get() == entity 
-                                && territory.expanse.worldspace().contains(mouse_location_resource.worldspace_pos) {
-                                mouse_location_resource.territory = Some(entity_territory);
-                            }
-                        }
+                            screenspace_pos - viewport_rect.min
+                        ));
+                },
+                None => {
+                    // Only warn once per window, instead of every frame the cursor is in it.
+                    if windows_missing_camera.insert(window_entity) {
+                        warn!("No Territory Tabs camera found for window {:?} when getting mouse info!", window_entity);
                     }
                 }
-                _ => {warn!("No RenderTarget found for camera when getting mouse info!");}
+            }
+        }
+
+        if let Some(worldspace_pos) = new_cursor.worldspace_pos {
+            // Nothing moved or restacked since last frame, so neither did the answer. Restacking marks the hit grid changed too.
+            let unchanged = hit_grid.as_ref().is_some_and(|hit_grid| !hit_grid.is_changed()) 
+                && window_cursor.worldspace_pos == Some(worldspace_pos);
+            if unchanged {
+                new_cursor.territory = window_cursor.territory;
+                new_cursor.content_territory = window_cursor.content_territory;
+            }
+            else {
+                // Without a hit grid, every Territory in the window has to be checked.
+                let candidates: &[Entity] = match (&hit_grid, window_children) {
+                    (Some(hit_grid), _) => hit_grid.candidates(worldspace_pos),
+                    (None, Some(window_children)) => window_children,
+                    (None, None) => &[]
+                };
+                // Overlapping Territories go to whichever is drawn on top, then to the smaller one.
+                let mut top_territory: Option<(Entity, HoverRank)> = None;
+                let mut top_content_territory: Option<(Entity, HoverRank)> = None;
+                for (territory_entity, territory) in territories_query.iter_many(candidates) {
+                    let hover_rank = HoverRank::of(territory, territory.base_node().and_then(|base_node| base_node_query.get(base_node).ok()));
+
+                    // Full rect is for grabbing resize handles, content area is for everything else.
+                    if territory.contains_worldspace(worldspace_pos) 
+                        && top_territory.as_ref().is_none_or(|(_, top_rank)| hover_rank.is_above(top_rank)) {
+                        top_territory = Some((territory_entity, hover_rank));
+                    }
+                    if territory.content_contains_worldspace(worldspace_pos, &territory_settings) 
+                        && top_content_territory.as_ref().is_none_or(|(_, top_rank)| hover_rank.is_above(top_rank)) {
+                        top_content_territory = Some((territory_entity, hover_rank));
+                    }
+                }
+                new_cursor.territory = top_territory.map(|(territory_entity, _)| territory_entity);
+                new_cursor.content_territory = top_content_territory.map(|(territory_entity, _)| territory_entity);
+            }
+        }
+
+        if let (Some(screenspace_pos), Some(worldspace_pos)) = (new_cursor.screenspace_pos, new_cursor.worldspace_pos) {
+            mouse_location_resource.screenspace_pos = screenspace_pos;
+            mouse_location_resource.worldspace_pos = worldspace_pos;
+            mouse_location_resource.window = Some(window_entity);
+            mouse_location_resource.territory = new_cursor.territory;
+            mouse_location_resource.content_territory = new_cursor.content_territory;
+        }
+
+        // Don't trigger change detection every frame the mouse stays still.
+        if *window_cursor != new_cursor {
+            *window_cursor = new_cursor;
+        }
+    }
+}
+
+/// Rebuilds the [`TerritoryHitGrid`] of every `Window` whose [`Territory`]s moved, appeared, or went away.  
+/// \
+/// A [`TerritoryBaseNode`] changing its [`ZIndex`] doesn't move anything, but it can change which [`Territory`] is on top, 
+/// so every [`TerritoryHitGrid`] is marked changed for [`get_mouse_location`] to look again.
+pub fn update_territory_hit_grids (
+    mut removed_territories: RemovedComponents<Territory>,
+    changed_territory_query: Query<&Parent, Changed<Territory>>,
+    restacked_base_node_query: Query<(), (Changed<ZIndex>, With<TerritoryBaseNode>)>,
+    mut window_query: Query<(Entity, Option<Ref<Children>>, &mut TerritoryHitGrid)>,
+    territory_query: Query<(Entity, &Territory)>
+) {
+    // Removed Territories don't say which window they were in anymore.
+    let rebuild_all = removed_territories.read().count() > 0;
+    let changed_windows: HashSet<Entity> = changed_territory_query.iter().map(|parent| parent.get()).collect();
+    let restacked = !restacked_base_node_query.is_empty();
+
+    for (window_entity, window_children, mut hit_grid) in &mut window_query {
+        let children_changed = window_children.as_ref().is_some_and(|window_children| window_children.is_changed());
+        if !(rebuild_all || children_changed || changed_windows.contains(&window_entity)) {
+            if restacked {
+                hit_grid.set_changed();
+            }
+            continue;
+        }
+
+        hit_grid.clear();
+        if let Some(window_children) = window_children {
+            for (territory_entity, territory) in territory_query.iter_many(window_children.iter()) {
+                hit_grid.insert(territory_entity, territory.expanse.worldspace());
             }
         }
     }
@@ -84,14 +200,16 @@ pub fn get_mouse_location(
 // Spawns a new window on a dev command for testing.
 pub fn spawn_new_os_window(
     mut commands: Commands,
+    default_display_library: Option<Res<DefaultDisplayLibrary>>,
     mut spawn_window_button_events: EventReader<SpawnWindowKeyJustPressed>
 ) {
+    let display_library = default_display_library.map(|default_display_library| default_display_library.0).unwrap_or_default();
     for event in spawn_window_button_events.read() {
         commands.spawn((
             Name::new("[WINDOW] Test Spawn Window"),
             Window::default(),
             TerritoryTabs,
-            DisplayLibrary::BevySickle
+            display_library
         ));
     }
 }
@@ -100,7 +218,7 @@ pub fn spawn_new_os_window(
 pub fn territory_tabs_main_state_exit (
     territory_tabs_current_state: Res<State<TerritoryTabsState>>,
     mut territory_tabs_next_state: ResMut<NextState<TerritoryTabsState>>,
-    mut territory_move_tab_exit_events: EventReader<TestChordJustReleased>
+    mut territory_move_tab_exit_events: EventReader<MoveTabsJustReleased>
 ) {
     for event in territory_move_tab_exit_events.read() {
         match territory_tabs_current_state.get() {
@@ -114,11 +232,12 @@ pub fn territory_tabs_main_state_exit (
 pub fn territory_tabs_main_state_enter (
     territory_tabs_current_state: Res<State<TerritoryTabsState>>,
     mut territory_tabs_next_state: ResMut<NextState<TerritoryTabsState>>,
-    mut territory_move_tab_enter_events: EventReader<TestChordJustPressed>
+    mut territory_move_tab_enter_events: EventReader<MoveTabsJustPressed>
 ) {
     for event in territory_move_tab_enter_events.read() {
         match territory_tabs_current_state.get() {
-            TerritoryTabsState::Natural => territory_tabs_next_state.set(TerritoryTabsState::MovingTabs),
+            TerritoryTabsState::Natural |
+            TerritoryTabsState::Empty => territory_tabs_next_state.set(TerritoryTabsState::MovingTabs),
             _ => {warn!("[MAIN STATE] Invalid transition: {:?} -> MovingTabs", territory_tabs_current_state.get());}
         }
     }
@@ -132,8 +251,15 @@ pub fn territory_tabs_main_state_enter (
 pub fn setup_tab_move_placeholders(
     mut commands: Commands,
     mouse_location_resource: Res<WorldMousePosition>,
-    territory_query: Query<&Territory>
+    territory_query: Query<&Territory>,
+    tab_query: Query<(Entity, &Tab)>,
+    click_spawn_query: Query<(), With<ClickSpawnPlaceholder>>
 ) {
+    // Clicking empty space brings its own placeholder, so there's no Tab being moved.
+    if !click_spawn_query.is_empty() {
+        return;
+    }
+
     if let Some(window_entity) = mouse_location_resource.window {
 
         // This is a special situation during debugging when no territories exist.
@@ -159,6 +285,11 @@ pub fn setup_tab_move_placeholders(
         ))  .id();
         commands.entity(window_entity).add_child(tab_move);
         debug!("Spawned placeholder of type: TabMove");
+        let origin_and_tab = mouse_location_resource.territory
+            .zip(dragged_tab(&mouse_location_resource, &territory_query, &tab_query));
+        if let Some((origin, (tab, _))) = origin_and_tab {
+            commands.entity(tab_move).insert(TabMovePlaceholder { tab, origin });
+        }
         let tab_origin = commands.spawn((
             Name::new("[PLACEHOLDER] Initial TabOrigin"),
             CleanupOnMovingTabExit,
@@ -173,6 +304,82 @@ pub fn setup_tab_move_placeholders(
     else {warn!("Mouse window not found at start of Tab Move! No placeholders spawned!");}
 }
 
+/// The [`Tab`] picked up by a Tab move. That's the hovered [`Tab`], or the active one in the hovered [`Territory`] if no [`Tab`] is hovered.
+fn dragged_tab<'a>(
+    mouse_location_resource: &WorldMousePosition,
+    territory_query: &Query<&Territory>,
+    tab_query: &'a Query<(Entity, &Tab)>
+) -> Option<(Entity, &'a Tab)> {
+    mouse_location_resource.tab
+        .and_then(|tab_entity| tab_query.get(tab_entity).ok())
+        .or_else(|| mouse_location_resource.territory
+            .and_then(|territory_entity| territory_query.get(territory_entity).ok())
+            .and_then(|territory| tab_query.iter_many(&territory.tabs).find(|(_, tab)| tab.active)))
+}
+
+/// Spawns a [`TabGhost`] under the cursor at the start of a Tab move, showing the [`Tab`] being dragged.
+/// That's the hovered [`Tab`], or the active one in the hovered [`Territory`] if no [`Tab`] is hovered.
+pub fn spawn_tab_move_ghost(
+    mut commands: Commands,
+    mouse_location_resource: Res<WorldMousePosition>,
+    window_root_index: Res<WindowRootIndex>,
+    tab_text_config: Res<TabTextConfig>,
+    territory_query: Query<&Territory>,
+    tab_query: Query<(Entity, &Tab)>
+) {
+    let Some((tab_entity, tab)) = dragged_tab(&mouse_location_resource, &territory_query, &tab_query) else {
+        debug!("Tab move started without a Tab under the cursor, no ghost spawned.");
+        return;
+    };
+    let Some(root_node_entity) = mouse_location_resource.window
+        .and_then(|window_entity| window_root_index.root_of(window_entity)) else {
+        warn!("Unable to find [ROOT NODE] entity for the Tab move ghost!");
+        return;
+    };
+
+    let ghost_pos = mouse_location_resource.screenspace_pos + TabGhost::CURSOR_OFFSET;
+    let ghost_entity = commands.spawn((
+        Name::new("[GHOST] Tab Move"),
+        CleanupOnMovingTabExit,
+        TabGhost { tab: tab_entity },
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(ghost_pos.x),
+                top: Val::Px(ghost_pos.y),
+                padding: UiRect::all(Val::Px(4.0)),
+                ..default()
+            },
+            background_color: Color::srgba_u8(30, 40, 50, 200).into(),
+            z_index: ZIndex::Global(i32::MAX),
+            ..default()
+        }
+    ))
+        .with_children(|ghost| {
+            ghost.spawn(TextBundle::from_sections(tab_text_config.sections(
+                &format!("{} {}", tab.icon, tab.name), 
+                TextStyle { font_size: 14.0, color: Color::srgb_u8(93, 235, 215), ..default() }
+            )));
+        })
+        .id();
+    commands.entity(root_node_entity).add_child(ghost_entity);
+    debug!("Spawned Tab move ghost for {:?}", tab_entity);
+}
+
+/// Keeps the [`TabGhost`] next to the cursor while a Tab move is underway.
+pub fn follow_tab_move_ghost(
+    mouse_location_resource: Res<WorldMousePosition>,
+    mut ghost_query: Query<&mut Style, With<TabGhost>>
+) {
+    let ghost_pos = mouse_location_resource.screenspace_pos + TabGhost::CURSOR_OFFSET;
+    for mut ghost_style in &mut ghost_query {
+        if ghost_style.left != Val::Px(ghost_pos.x) || ghost_style.top != Val::Px(ghost_pos.y) {
+            ghost_style.left = Val::Px(ghost_pos.x);
+            ghost_style.top = Val::Px(ghost_pos.y);
+        }
+    }
+}
+
 // See if the mouse has triggered any events for placeholders.
 
 // Check for the cursor leaving the window.
@@ -186,7 +393,7 @@ pub fn check_placeholder_types_leaving_window (
     for event in mouse_left_window_events.read() {
         match territory_tabs_current_state.get() {
             TerritoryTabsState::MovingTabs => {
-                for (entity, placeholder) in &mut placeholder_query {
+                for (entity, mut placeholder) in &mut placeholder_query {
                     match placeholder.placeholder_type {
                         PlaceholderType::SpawnTerritory => {
                             commands.entity(event.window).remove_children(&[entity]);
@@ -203,6 +410,10 @@ pub fn check_placeholder_types_leaving_window (
                             warn!("[CURSOR LEFT] SpawnWindow type placeholder found while mouse was still in a Window??"); 
                             commands.entity(entity).despawn();
                         }
+                        PlaceholderType::CombineTerritories => {
+                            placeholder.valid_spawn = false;
+                            debug!("[CURSOR LEFT] Nothing to combine with outside the Window!");
+                        }
                         _ => {} // Leave others alone.
                     };
                 }
@@ -231,6 +442,7 @@ pub fn check_placeholder_types_entering_window (
     for event in mouse_entered_window_events.read() {
         match territory_tabs_current_state.get() {
             TerritoryTabsState::MovingTabs => {
+                let mut combining = false;
                 for (entity, placeholder) in &mut placeholder_query {
                     match placeholder.placeholder_type {
                         PlaceholderType::SpawnTerritory => {
@@ -245,10 +457,16 @@ pub fn check_placeholder_types_entering_window (
                             commands.entity(entity).despawn();
                             debug!("[CURSOR ENTERED] Removed SpawnWindow type placeholder!");
                         }
+                        PlaceholderType::CombineTerritories => combining = true,
                         _ => {} // Leave others alone.
                     };
                 }
 
+                // A whole Territory is being dragged, so there's no Tab to spawn a Territory for.
+                if combining {
+                    continue;
+                }
+
                 // Spawn a new child placeholder. SpawnTerritory type since calculate_placeholder_data will catch it.
                 let new_placeholder = commands.spawn((
                     Name::new("[PLACEHOLDER] CursorEntered Event SpawnTerritory"),
@@ -270,19 +488,19 @@ pub fn check_placeholder_types_mouse_moving (
     mut commands: Commands,
     mouse_location_resource: Res<WorldMousePosition>,
     mut mouse_moved_in_window_events: EventReader<CursorMoved>,
-    mut placeholder_query: Query<(Entity, &mut Placeholder)>
+    mut placeholder_query: Query<(Entity, &mut Placeholder), Without<ClickSpawnPlaceholder>>
 ) {
     for event in mouse_moved_in_window_events.read() {
         for (placeholder_entity, mut placeholder) in &mut placeholder_query {
             match placeholder.placeholder_type {
                 PlaceholderType::SpawnTerritory => {
-                    if let Some(territory_entity) = mouse_location_resource.territory {
+                    if let Some(territory_entity) = mouse_location_resource.content_territory {
                         placeholder.placeholder_type = PlaceholderType::TabMove;
                         debug!("[CURSOR MOVED] Changed placeholder type from SpawnTerritory to TabMove!");
                     }
                 },
                 PlaceholderType::TabMove => {
-                    if mouse_location_resource.territory == None {
+                    if mouse_location_resource.content_territory == None {
                         placeholder.placeholder_type = PlaceholderType::SpawnTerritory;
                         debug!("[CURSOR MOVED] Changed placeholder type from TabMove to SpawnTerritory!");
                     }
@@ -292,6 +510,7 @@ pub fn check_placeholder_types_mouse_moving (
                     commands.entity(placeholder_entity).despawn();
                 },
                 PlaceholderType::TabOrigin => {},
+                PlaceholderType::CombineTerritories => {}, // Handled by update_combine_territories_placeholder.
                 _ => {warn!("[CURSOR MOVED] Unusual placeholder type found!");}
             };
         }
@@ -312,76 +531,17 @@ pub fn calculate_placeholder_data(
 ) {
     for event in mouse_moved_in_window_events.read() {
         if let Ok(window) = window_query.get(event.window) {
-            // Get the event window's size for later.
-            let window_rect = Rect::from_center_size(
-                Vec2::new(0.0, 0.0),
-                Vec2::new(window.width(), window.height())
-            );
             for mut placeholder in &mut placeholder_query {
                 match placeholder.placeholder_type {
                     PlaceholderType::SpawnTerritory => {
-                        // Get upper left coord. Adjust slightly for tab_offsets.
-                        let worldspace_upper_left = Vec2::new(
-                            mouse_location_resource.worldspace_pos.x - territory_settings.inner_margins.x,
-                            mouse_location_resource.worldspace_pos.y + territory_settings.inner_margins.y
+                        fit_spawn_territory_placeholder(
+                            placeholder.as_mut(), 
+                            mouse_location_resource.worldspace_pos, 
+                            event.window, 
+                            window, 
+                            &territory_settings, 
+                            &territory_query
                         );
-
-                        // Get the initial minimum and default territory rects.
-                        let mut proposed_worldspace_rects = vec![
-                            Rect::from_corners(
-                                worldspace_upper_left, 
-                                Vec2::new(
-                                    worldspace_upper_left.x + territory_settings.min_size.x,
-                                    worldspace_upper_left.y - territory_settings.min_size.y
-                                )
-                            ),
-                            Rect::from_corners(
-                                worldspace_upper_left, 
-                                Vec2::new(
-                                    worldspace_upper_left.x + territory_settings.default_size.x,
-                                    worldspace_upper_left.y - territory_settings.default_size.y
-                                )
-                            )];
-
-                        // Clip off anything outside the window.
-                        proposed_worldspace_rects[1] = window_rect.intersect(proposed_worldspace_rects[1]);
-
-                        // Intersecting territories clip off pieces of our initial default rect too.
-                        for (parent, territory) in &territory_query {
-                            let territory_conflict = proposed_worldspace_rects[1].intersect(territory.expanse.worldspace());
-                            let territory_window = parent.get();
-                            if territory_window == event.window && !territory_conflict.is_empty() {
-                            
-                                let conflict_angle = (worldspace_upper_left.y - territory.expanse.worldspace().center().y)
-                                    .atan2(worldspace_upper_left.x - territory.expanse.worldspace().center().x);
-
-                                if conflict_angle <= FRAC_PI_4 && conflict_angle >= -FRAC_PI_4 {
-                                    proposed_worldspace_rects[1].min.x += territory_conflict.width();
-                                } 
-                                else if conflict_angle >= FRAC_PI_4 && conflict_angle <= 3.0 * FRAC_PI_4 {
-                                    proposed_worldspace_rects[1].min.y += territory_conflict.height();
-                                }
-                                else if (conflict_angle >= 3.0 * FRAC_PI_4 && conflict_angle <= PI)
-                                    || (conflict_angle >= -PI && conflict_angle <= -3.0 * FRAC_PI_4) {
-                                    proposed_worldspace_rects[1].max.x -= territory_conflict.width();
-                                }
-                                else if conflict_angle >= -3.0 * FRAC_PI_4 && conflict_angle <= -FRAC_PI_4 {
-                                    proposed_worldspace_rects[1].max.y -= territory_conflict.height();
-                                }
-                                else{
-                                    warn!{"Unusual conflict angle found during placeholder calculations!"}
-                                }
-                            }
-                        }
-                        // If the minimum still fits inside the clipped default, we're good to spawn.
-                        // If not, ignore this frame's data to keep the last valid data.
-                        if proposed_worldspace_rects[1].contains(proposed_worldspace_rects[0].min) 
-                        && proposed_worldspace_rects[1].contains(proposed_worldspace_rects[0].max) {
-                            placeholder.worldspace_visual_rects = proposed_worldspace_rects;
-                            placeholder.world_to_screen(window.width(), window.height());
-                            placeholder.valid_spawn = true;
-                        }
-                        
                     }
                     PlaceholderType::TabMove => {} // Do this later.
                     _ =>{}
@@ -393,17 +553,377 @@ pub fn calculate_placeholder_data(
 }
 
 
+/// Spawns a [`PlaceholderType::CombineTerritories`] [`Placeholder`] when a tiled [`Territory`] starts being dragged, 
+/// and activates it once the drag ends, combining the dragged [`Territory`] into the one it was dropped on.  
+/// \
+/// Follows the [`MotionOrigin`] of the drag. A canceled drag throws its [`Placeholder`] away without combining anything.
+pub fn track_combine_territories_placeholder(
+    mut commands: Commands,
+    mut combine_territories_request: EventWriter<CombineTerritoriesRequest>,
+    mut removed_motion_origins: RemovedComponents<MotionOrigin>,
+    dragged_query: Query<(Entity, &Parent, Ref<MotionOrigin>), TiledTerritory>,
+    placeholder_query: Query<(Entity, &Placeholder, &CombinePlaceholder)>
+) {
+    for (territory_entity, territory_parent, motion_origin) in &dragged_query {
+        if motion_origin.is_added() && !motion_origin.resizing() {
+            let combine_territories = commands.spawn((
+                Name::new("[PLACEHOLDER] Drag CombineTerritories"),
+                Placeholder {placeholder_type: PlaceholderType::CombineTerritories, ..Default::default()},
+                CombinePlaceholder { source: territory_entity, target: None },
+                SpatialBundle::default(),
+            ))  .id();
+            commands.entity(territory_parent.get()).add_child(combine_territories);
+            debug!("Spawned placeholder of type: CombineTerritories");
+        }
+        else if motion_origin.is_changed() && motion_origin.canceled() {
+            for (placeholder_entity, _, combine_placeholder) in &placeholder_query {
+                if combine_placeholder.source == territory_entity {
+                    commands.entity(placeholder_entity).despawn_recursive();
+                }
+            }
+        }
+    }
+
+    for territory_entity in removed_motion_origins.read() {
+        for (placeholder_entity, placeholder, combine_placeholder) in &placeholder_query {
+            if combine_placeholder.source != territory_entity {
+                continue;
+            }
+            match combine_placeholder.target {
+                Some(target) if placeholder.valid_spawn => {
+                    combine_territories_request.send(CombineTerritoriesRequest { surviving: target, absorbed: territory_entity });
+                },
+                _ => debug!("Territory drag ended without anything to combine with.")
+            }
+            commands.entity(placeholder_entity).despawn_recursive();
+        }
+    }
+}
+
+/// Points each [`PlaceholderType::CombineTerritories`] [`Placeholder`] at the [`Territory`] under the cursor, 
+/// and checks that the two can be combined without the result overlapping any other [`Territory`].  
+/// \
+/// The visual rects are the target's expanse and the combined one.
+pub fn update_combine_territories_placeholder(
+    mouse_location_resource: Res<WorldMousePosition>,
+    window_query: Query<&Window>,
+    territory_query: Query<(Entity, &Parent, &Territory), TiledTerritory>,
+    mut placeholder_query: Query<(&mut Placeholder, &mut CombinePlaceholder)>
+) {
+    for (mut placeholder, mut combine_placeholder) in &mut placeholder_query {
+        let source_entity = combine_placeholder.source;
+        let combination = mouse_location_resource.territory
+            .filter(|target_entity| *target_entity != source_entity)
+            .and_then(|target_entity| {
+                let [(_, source_parent, source), (_, target_parent, target)] = territory_query.get_many([source_entity, target_entity]).ok()?;
+                if source_parent.get() != target_parent.get() {
+                    return None;
+                }
+                let window_entity = source_parent.get();
+                let window = window_query.get(window_entity).ok()?;
+                let window_rect = Rect::from_center_size(Vec2::ZERO, Vec2::new(window.width(), window.height()));
+                let others = territory_query.iter()
+                    .filter(|(other_entity, other_parent, _)| {
+                        other_parent.get() == window_entity && *other_entity != source_entity && *other_entity != target_entity
+                    })
+                    .map(|(_, _, other_territory)| other_territory);
+                let combined_rect = combined_territory_rect(source, target, window_rect, others)?;
+                Some((target_entity, target.expanse.worldspace(), combined_rect, window))
+            });
+
+        match combination {
+            Some((target_entity, target_rect, combined_rect, window)) => {
+                combine_placeholder.target = Some(target_entity);
+                placeholder.worldspace_visual_rects = vec![target_rect, combined_rect];
+                placeholder.world_to_screen(window.width(), window.height());
+                placeholder.valid_spawn = true;
+            },
+            None => {
+                combine_placeholder.target = None;
+                placeholder.valid_spawn = false;
+            }
+        }
+    }
+}
+
+/// Fits a [`PlaceholderType::SpawnTerritory`] [`Placeholder`] around the cursor, clipped by the `Window` and other [`Territory`]s.
+/// If even the minimum size doesn't fit, the last valid data is kept.
+pub fn fit_spawn_territory_placeholder(
+    placeholder: &mut Placeholder,
+    cursor_worldspace_pos: Vec2,
+    window_entity: Entity,
+    window: &Window,
+    territory_settings: &GlobalTerritorySettings,
+    territory_query: &Query<(&Parent, &Territory)>
+) {
+    // Get the window's size for later.
+    let window_rect = Rect::from_center_size(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(window.width(), window.height())
+    );
+
+    // Get upper left coord. Adjust slightly for tab_offsets.
+    let worldspace_upper_left = Vec2::new(
+        cursor_worldspace_pos.x - territory_settings.inner_margins.x,
+        cursor_worldspace_pos.y + territory_settings.inner_margins.y
+    );
+
+    // Get the initial minimum and default territory rects.
+    let mut proposed_worldspace_rects = vec![
+        Rect::from_corners(
+            worldspace_upper_left, 
+            Vec2::new(
+                worldspace_upper_left.x + territory_settings.min_size.x,
+                worldspace_upper_left.y - territory_settings.min_size.y
+            )
+        ),
+        Rect::from_corners(
+            worldspace_upper_left, 
+            Vec2::new(
+                worldspace_upper_left.x + territory_settings.default_size.x,
+                worldspace_upper_left.y - territory_settings.default_size.y
+            )
+        )];
+
+    // Clip off anything outside the window.
+    proposed_worldspace_rects[1] = window_rect.intersect(proposed_worldspace_rects[1]);
+
+    // Intersecting territories clip off pieces of our initial default rect too.
+    for (parent, territory) in territory_query.iter() {
+        let territory_conflict = proposed_worldspace_rects[1].intersect(territory.expanse.worldspace());
+        let territory_window = parent.get();
+        if territory_window == window_entity && !territory_conflict.is_empty() {
+
+            let conflict_angle = (worldspace_upper_left.y - territory.expanse.worldspace().center().y)
+                .atan2(worldspace_upper_left.x - territory.expanse.worldspace().center().x);
+
+            if conflict_angle <= FRAC_PI_4 && conflict_angle >= -FRAC_PI_4 {
+                proposed_worldspace_rects[1].min.x += territory_conflict.width();
+            } 
+            else if conflict_angle >= FRAC_PI_4 && conflict_angle <= 3.0 * FRAC_PI_4 {
+                proposed_worldspace_rects[1].min.y += territory_conflict.height();
+            }
+            else if (conflict_angle >= 3.0 * FRAC_PI_4 && conflict_angle <= PI)
+                || (conflict_angle >= -PI && conflict_angle <= -3.0 * FRAC_PI_4) {
+                proposed_worldspace_rects[1].max.x -= territory_conflict.width();
+            }
+            else if conflict_angle >= -3.0 * FRAC_PI_4 && conflict_angle <= -FRAC_PI_4 {
+                proposed_worldspace_rects[1].max.y -= territory_conflict.height();
+            }
+            else{
+                warn!{"Unusual conflict angle found during placeholder calculations!"}
+            }
+        }
+    }
+    // If the minimum still fits inside the clipped default, we're good to spawn.
+    // If not, ignore this frame's data to keep the last valid data.
+    if proposed_worldspace_rects[1].contains(proposed_worldspace_rects[0].min) 
+    && proposed_worldspace_rects[1].contains(proposed_worldspace_rects[0].max) {
+        placeholder.worldspace_visual_rects = proposed_worldspace_rects;
+        placeholder.world_to_screen(window.width(), window.height());
+        placeholder.valid_spawn = true;
+    }
+}
+
+/// Moves the [`Tab`] of a [`PendingTabMove`] into its [`Territory`] once that [`Territory`] has spawned.
+pub fn move_tab_into_spawned_territory(
+    mut commands: Commands,
+    pending_tab_move: Res<PendingTabMove>,
+    mut move_tab_request: EventWriter<MoveTabRequest>,
+    added_territory_query: Query<(Entity, &Parent, &Territory), Added<Territory>>
+) {
+    let spawned_territory = added_territory_query.iter().find(|(_, territory_parent, territory)| {
+        let screenspace = territory.expanse().screenspace();
+        territory_parent.get() == pending_tab_move.window_entity
+            && screenspace.min.abs_diff_eq(pending_tab_move.screenspace.min, 0.01)
+            && screenspace.max.abs_diff_eq(pending_tab_move.screenspace.max, 0.01)
+    });
+    let Some((territory_entity, _, _)) = spawned_territory else {
+        return;
+    };
+
+    move_tab_request.send(MoveTabRequest { tab: pending_tab_move.tab, territory: territory_entity });
+    commands.remove_resource::<PendingTabMove>();
+}
+
+/// With [`GlobalTerritorySettings::click_to_spawn`] on, clicking empty space in a `Window` starts placing a new [`Territory`].
+/// Spawns a [`ClickSpawnPlaceholder`] at the cursor and moves to [`TerritoryTabsState::MovingTabs`], where placeholders live.
+#[allow(clippy::too_many_arguments)]
+pub fn start_click_spawn_placeholder(
+    mut commands: Commands,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_location_resource: Res<WorldMousePosition>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    territory_tabs_current_state: Res<State<TerritoryTabsState>>,
+    mut territory_tabs_next_state: ResMut<NextState<TerritoryTabsState>>,
+    window_query: Query<&Window, With<TerritoryTabs>>,
+    territory_query: Query<(&Parent, &Territory)>
+) {
+    if !territory_settings.click_to_spawn || !mouse_buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    match territory_tabs_current_state.get() {
+        TerritoryTabsState::Natural |
+        TerritoryTabsState::Empty => {},
+        _ => return
+    }
+    let Some(window_entity) = mouse_location_resource.window else { return; };
+    if mouse_location_resource.territory.is_some() {
+        return;
+    }
+    let Ok(window) = window_query.get(window_entity) else { return; };
+
+    let mut placeholder = Placeholder { placeholder_type: PlaceholderType::SpawnTerritory, ..Default::default() };
+    fit_spawn_territory_placeholder(
+        &mut placeholder, 
+        mouse_location_resource.worldspace_pos, 
+        window_entity, 
+        window, 
+        &territory_settings, 
+        &territory_query
+    );
+    let click_spawn = commands.spawn((
+        Name::new("[PLACEHOLDER] Click SpawnTerritory"),
+        CleanupOnMovingTabExit,
+        ClickSpawnPlaceholder,
+        placeholder,
+        SpatialBundle::default(),
+    ))  .id();
+    commands.entity(window_entity).add_child(click_spawn);
+    territory_tabs_next_state.set(TerritoryTabsState::MovingTabs);
+    debug!("[CLICK] Spawned placeholder of type: SpawnTerritory");
+}
+
+/// Sends a [`TerritorySpawnRequest`] right away when [`DevControls::SpawnTerritoryAtCursor`] is just pressed over empty space, 
+/// for building layouts quickly without the placeholders.  
+/// \
+/// The new [`Territory`] is fit around the cursor the same way a [`PlaceholderType::SpawnTerritory`] [`Placeholder`] is, 
+/// by [`fit_spawn_territory_placeholder`]. Nothing is spawned if even the minimum size doesn't fit there. 
+/// A `Window` without a [`DisplayLibrary`] spawns it with the [`DefaultDisplayLibrary`].
+pub fn spawn_territory_at_cursor(
+    dev_controls: Res<ActionState<DevControls>>,
+    mouse_location_resource: Res<WorldMousePosition>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    default_display_library: Option<Res<DefaultDisplayLibrary>>,
+    mut territory_spawn_request: EventWriter<TerritorySpawnRequest>,
+    window_query: Query<(&Window, Option<&DisplayLibrary>), With<TerritoryTabs>>,
+    territory_query: Query<(&Parent, &Territory)>
+) {
+    if !dev_controls.just_pressed(&DevControls::SpawnTerritoryAtCursor) {
+        return;
+    }
+    let Some(window_entity) = mouse_location_resource.window else { return; };
+    if mouse_location_resource.territory.is_some() {
+        debug!("Cursor is over a Territory, so no Territory was spawned there.");
+        return;
+    }
+    let Ok((window, display_library)) = window_query.get(window_entity) else { return; };
+    let default_display_library = default_display_library.map(|default_display_library| default_display_library.0).unwrap_or_default();
+
+    let mut placeholder = Placeholder { placeholder_type: PlaceholderType::SpawnTerritory, ..Default::default() };
+    fit_spawn_territory_placeholder(
+        &mut placeholder, 
+        mouse_location_resource.worldspace_pos, 
+        window_entity, 
+        window, 
+        &territory_settings, 
+        &territory_query
+    );
+    if !placeholder.valid_spawn {
+        debug!("No room for a Territory at the cursor, so none was spawned.");
+        return;
+    }
+
+    territory_spawn_request.send(TerritorySpawnRequest {
+        window_entity,
+        expanse: RectKit::from_worldspace(placeholder.worldspace_visual_rects[1], window.width(), window.height()),
+        relative_screenspace: None,
+        display_library: display_library.copied().unwrap_or(default_display_library),
+        resizable_edges: ResizableEdges::default()
+    });
+}
+
+/// How far each side of a [`Territory`] moves per line scrolled by [`scroll_resize_hovered_territory`].
+const SCROLL_RESIZE_LINE_STEP: f32 = 10.0;
+
+/// Grows or shrinks the hovered [`Territory`] from its center while [`DevControls::ScrollResize`] is held, 
+/// with every side moving [`SCROLL_RESIZE_LINE_STEP`] per line scrolled, or one per pixel.  
+/// \
+/// The new size is held to [`GlobalTerritorySettings::min_size`], or the [`MinSizeOverride`], and [`GlobalTerritorySettings::max_size`] 
+/// before a [`ResizeDirection::All`] [`MoveRequest`] is sent, so neighbors and the `Window` edge are handled like any other resize.
+pub fn scroll_resize_hovered_territory(
+    mut commands: Commands,
+    dev_controls: Res<ActionState<DevControls>>,
+    mouse_location_resource: Res<WorldMousePosition>,
+    territory_settings: Res<GlobalTerritorySettings>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    window_query: Query<&Window, With<TerritoryTabs>>,
+    territory_query: Query<(&Territory, Option<&MinSizeOverride>), Without<MoveRequest>>
+) {
+    let scroll_step: f32 = mouse_wheel_events.read()
+        .map(|wheel_event| match wheel_event.unit {
+            MouseScrollUnit::Line => wheel_event.y * SCROLL_RESIZE_LINE_STEP,
+            MouseScrollUnit::Pixel => wheel_event.y
+        })
+        .sum();
+    if scroll_step == 0.0 || !dev_controls.pressed(&DevControls::ScrollResize) {
+        return;
+    }
+    let (Some(window_entity), Some(territory_entity)) = (mouse_location_resource.window, mouse_location_resource.territory) else {
+        return;
+    };
+    let (Ok(window), Ok((territory, min_size_override))) = (window_query.get(window_entity), territory_query.get(territory_entity)) else {
+        return;
+    };
+
+    let current_rect = territory.expanse.worldspace();
+    let min_size = territory_settings.min_size_for(min_size_override, Vec2::new(window.width(), window.height()));
+    let new_size = (current_rect.size() + Vec2::splat(scroll_step * 2.0))
+        .min(territory_settings.max_size)
+        .max(min_size);
+    let half_delta = (new_size - current_rect.size()) * 0.5;
+    if half_delta == Vec2::ZERO {
+        return;
+    }
+
+    let mut resize_direction = ResizeDirection::ALL;
+    resize_direction.set_magnitudes_from_delta(half_delta);
+    commands.entity(territory_entity).insert(MoveRequest::new(
+        RectKit::from_worldspace(Rect::from_center_size(current_rect.center(), new_size), window.width(), window.height()),
+        MoveRequestType::Resize(resize_direction)
+    ));
+}
+
+/// Commits a [`ClickSpawnPlaceholder`] when its click is released, by leaving [`TerritoryTabsState::MovingTabs`].
+pub fn release_click_spawn_placeholder(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    territory_tabs_current_state: Res<State<TerritoryTabsState>>,
+    mut territory_tabs_next_state: ResMut<NextState<TerritoryTabsState>>,
+    click_spawn_query: Query<(), With<ClickSpawnPlaceholder>>
+) {
+    if mouse_buttons.just_released(MouseButton::Left) 
+        && !click_spawn_query.is_empty() 
+        && *territory_tabs_current_state.get() == TerritoryTabsState::MovingTabs {
+        territory_tabs_next_state.set(TerritoryTabsState::Natural);
+    }
+}
+
 /// Iterate through all placeholders, and do what actions they represent.
 /// TODO: Refactor the hell out of this mess.
+#[allow(clippy::too_many_arguments)]
 pub fn activate_placeholders (
     mut commands: Commands,
     mouse_location_resource: Res<WorldMousePosition>,
     mut territory_spawn_request: EventWriter<TerritorySpawnRequest>,
+    mut combine_territories_request: EventWriter<CombineTerritoriesRequest>,
+    mut move_tab_request: EventWriter<MoveTabRequest>,
     window_display_query: Query<&DisplayLibrary, With<Window>>,
     window_query: Query<&Window>,
-    placeholders_query: Query<(Entity, Option<&Parent>, &Placeholder)>
+    placeholders_query: Query<(Entity, Option<&Parent>, &Placeholder, Option<&CombinePlaceholder>)>,
+    tab_move_query: Query<&TabMovePlaceholder>
 ) {
-    for (entity, placeholder_parent, placeholder) in & placeholders_query {
+    for (entity, placeholder_parent, placeholder, combine_placeholder) in & placeholders_query {
+        let tab_move_placeholder = tab_move_query.get(entity).ok();
         match placeholder.placeholder_type {
             PlaceholderType::SpawnTerritory => {
                 if let Some(territory_parent) = placeholder_parent {
@@ -438,23 +958,48 @@ pub fn activate_placeholders (
                                 TerritorySpawnRequest {
                                     window_entity: mouse_window,
                                     expanse: new_rectkit,
-                                    display_library
+                                    relative_screenspace: None,
+                                    display_library,
+                                    resizable_edges: ResizableEdges::default()
                                 }
                             );
+
+                            // A Tab dropped here moves into the new Territory once it spawns.
+                            if let Some(tab_move_placeholder) = tab_move_placeholder {
+                                commands.insert_resource(PendingTabMove {
+                                    window_entity: mouse_window,
+                                    screenspace: new_rectkit.screenspace(),
+                                    tab: tab_move_placeholder.tab
+                                });
+                            }
                         }
                         else {warn!("Attempted to activate SpawnTerritory, but no mouse window found!");}
                     }
+                    else if tab_move_placeholder.is_some() {
+                        debug!("Tab dropped where no Territory fits, so it snapped back to where it came from.");
+                    }
                 }
                 else{warn!("SpawnTerritory type placeholder found without window parent!");}
             },
             PlaceholderType::TabMove => {
-                debug!("TabMove type placeholder activated! Pretend that a tab move occured.");
+                match (tab_move_placeholder, mouse_location_resource.territory) {
+                    (Some(tab_move_placeholder), Some(target)) if target != tab_move_placeholder.origin => {
+                        move_tab_request.send(MoveTabRequest { tab: tab_move_placeholder.tab, territory: target });
+                    },
+                    (Some(_), _) => debug!("Tab dropped back on its own Territory, nothing moved."),
+                    (None, _) => debug!("TabMove type placeholder activated without a Tab to move.")
+                }
             },
             PlaceholderType::TabOrigin => {
                 debug!("TabOrigin type placeholder activated! Pretend that nothing happened.");
             },
             PlaceholderType::CombineTerritories => {
-                warn!("Unimplemented CombineTerritories type placeholder activated!");
+                match combine_placeholder.and_then(|combine| combine.target.map(|target| (combine.source, target))) {
+                    Some((source, target)) if placeholder.valid_spawn => {
+                        combine_territories_request.send(CombineTerritoriesRequest { surviving: target, absorbed: source });
+                    },
+                    _ => debug!("CombineTerritories type placeholder activated without anything to combine with.")
+                }
             },
             PlaceholderType::SpawnWindow => {
                 debug!("SpawnWindow type placeholder activated! Pretend that a window spawned.");
@@ -464,4 +1009,579 @@ pub fn activate_placeholders (
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::camera::{camera_system, ManualTextureViews};
+    use bevy::render::texture::Image;
+    use bevy::render::camera::RenderTarget;
+    use bevy::window::{WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged};
+
+    /// App that configures new windows and tracks the cursor in them.
+    fn cursor_app() -> App {
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<WindowRootIndex>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>()
+            .add_systems(Update, (
+                configure_os_window
+                    .run_if(on_event::<WindowCreated>()),
+                index_added_ui_roots,
+                camera_system::<OrthographicProjection>,
+                update_territory_hit_grids,
+                get_mouse_location
+            ).chain());
+        app
+    }
+
+    #[test]
+    fn tab_move_ghost_follows_the_cursor() {
+        let mut app = App::new();
+        app
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(TerritoryTabsState::Natural)
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<TabTextConfig>()
+            .add_systems(OnEnter(TerritoryTabsState::MovingTabs), spawn_tab_move_ghost)
+            .add_systems(OnExit(TerritoryTabsState::MovingTabs), despawn_all_entities_with::<CleanupOnMovingTabExit>)
+            .add_systems(Update, follow_tab_move_ghost.run_if(in_state(TerritoryTabsState::MovingTabs)));
+
+        let window_entity = app.world_mut().spawn(Window::default()).id();
+        let root_node_entity = app.world_mut().spawn(NodeBundle::default()).id();
+        let mut window_root_index = WindowRootIndex::default();
+        window_root_index.insert(window_entity, root_node_entity);
+        app.insert_resource(window_root_index);
+        let inactive_tab = app.world_mut().spawn(Tab::build_from_type(TabType::ECS)).id();
+        let dragged_tab = app.world_mut().spawn(Tab { active: true, ..Tab::build_from_type(TabType::Glossary) }).id();
+        let mut territory = Territory::empty();
+        territory.tabs = vec![inactive_tab, dragged_tab];
+        let territory_entity = app.world_mut().spawn(territory).id();
+
+        let mut mouse_location = app.world_mut().resource_mut::<WorldMousePosition>();
+        mouse_location.window = Some(window_entity);
+        mouse_location.territory = Some(territory_entity);
+        mouse_location.screenspace_pos = Vec2::new(100.0, 50.0);
+        app.world_mut().resource_mut::<NextState<TerritoryTabsState>>().set(TerritoryTabsState::MovingTabs);
+        app.update();
+
+        let mut ghost_query = app.world_mut().query::<(Entity, &TabGhost, &Parent)>();
+        let ghosts: Vec<(Entity, &TabGhost, &Parent)> = ghost_query.iter(app.world()).collect();
+        assert_eq!(ghosts.len(), 1, "Starting a Tab move did not spawn exactly one ghost.");
+        assert_eq!(ghosts[0].1.tab, dragged_tab, "Ghost is not showing the active Tab.");
+        assert_eq!(ghosts[0].2.get(), root_node_entity, "Ghost was not put in the Window's root node.");
+        let ghost_entity = ghosts[0].0;
+
+        app.world_mut().resource_mut::<WorldMousePosition>().screenspace_pos = Vec2::new(400.0, 300.0);
+        app.update();
+        let ghost_style = app.world().get::<Style>(ghost_entity).unwrap();
+        assert_eq!(ghost_style.left, Val::Px(400.0 + TabGhost::CURSOR_OFFSET.x), "Ghost did not follow the cursor.");
+        assert_eq!(ghost_style.top, Val::Px(300.0 + TabGhost::CURSOR_OFFSET.y), "Ghost did not follow the cursor.");
+
+        app.world_mut().resource_mut::<NextState<TerritoryTabsState>>().set(TerritoryTabsState::Natural);
+        app.update();
+        assert!(app.world().get_entity(ghost_entity).is_none(), "Ghost was left behind after the Tab move.");
+    }
+
+    #[test]
+    fn clicking_empty_space_places_and_commits_a_new_territory() {
+        let mut app = App::new();
+        app
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(TerritoryTabsState::Natural)
+            .insert_resource(GlobalTerritorySettings { click_to_spawn: true, ..default() })
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<CombineTerritoriesRequest>()
+            .add_event::<MoveTabRequest>()
+            .add_systems(OnEnter(TerritoryTabsState::MovingTabs), setup_tab_move_placeholders)
+            .add_systems(OnExit(TerritoryTabsState::MovingTabs), (
+                activate_placeholders,
+                despawn_all_entities_with::<CleanupOnMovingTabExit>
+            ).chain())
+            .add_systems(Update, (start_click_spawn_placeholder, release_click_spawn_placeholder));
+
+        let window_entity = app.world_mut().spawn((
+            Window { resolution: (1000.0, 1000.0).into(), ..default() },
+            TerritoryTabs,
+            DisplayLibrary::BevyUi
+        )).id();
+        let mut mouse_location = app.world_mut().resource_mut::<WorldMousePosition>();
+        mouse_location.window = Some(window_entity);
+        mouse_location.worldspace_pos = Vec2::new(-100.0, 100.0);
+
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        app.update();
+
+        let mut placeholder_query = app.world_mut().query_filtered::<(&Parent, &Placeholder), With<ClickSpawnPlaceholder>>();
+        let placeholders: Vec<(&Parent, &Placeholder)> = placeholder_query.iter(app.world()).collect();
+        assert_eq!(placeholders.len(), 1, "Clicking empty space did not spawn exactly one placeholder.");
+        assert_eq!(placeholders[0].0.get(), window_entity, "Placeholder was not placed in the clicked Window.");
+        assert!(matches!(placeholders[0].1.placeholder_type, PlaceholderType::SpawnTerritory));
+        assert!(placeholders[0].1.valid_spawn, "Placeholder in open space should be a valid spawn.");
+        assert_eq!(app.world().resource::<State<TerritoryTabsState>>().get(), &TerritoryTabsState::MovingTabs);
+        let mut other_placeholder_query = app.world_mut().query::<&Placeholder>();
+        assert_eq!(other_placeholder_query.iter(app.world()).count(), 1, "Tab move placeholders were spawned for a click.");
+
+        let mut mouse_buttons = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        mouse_buttons.clear();
+        mouse_buttons.release(MouseButton::Left);
+        app.update();
+        app.update();
+
+        let spawn_events = app.world().resource::<Events<TerritorySpawnRequest>>();
+        let mut spawn_reader = spawn_events.get_reader();
+        let requests: Vec<&TerritorySpawnRequest> = spawn_reader.read(spawn_events).collect();
+        assert_eq!(requests.len(), 1, "Releasing the click did not request exactly one Territory.");
+        assert_eq!(requests[0].window_entity, window_entity);
+        assert_eq!(requests[0].expanse.screenspace().min, Vec2::new(400.0 - 3.0, 400.0 - 3.0), "Territory was not placed at the cursor.");
+        assert_eq!(other_placeholder_query.iter(app.world()).count(), 0, "Placeholder was left behind after committing.");
+    }
+
+    #[test]
+    fn spawn_territory_at_cursor_key_requests_a_default_sized_territory() {
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<ActionState<DevControls>>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_systems(Update, spawn_territory_at_cursor);
+
+        let window_entity = app.world_mut().spawn((
+            Window { resolution: (1000.0, 1000.0).into(), ..default() },
+            TerritoryTabs,
+            DisplayLibrary::BevyUi
+        )).id();
+        let mut mouse_location = app.world_mut().resource_mut::<WorldMousePosition>();
+        mouse_location.window = Some(window_entity);
+        mouse_location.worldspace_pos = Vec2::new(-100.0, 100.0);
+
+        let press_spawn_key = |app: &mut App| -> Vec<RectKit> {
+            app.world_mut().resource_mut::<ActionState<DevControls>>().press(&DevControls::SpawnTerritoryAtCursor);
+            app.update();
+            app.world_mut().resource_mut::<ActionState<DevControls>>().release(&DevControls::SpawnTerritoryAtCursor);
+            app.world_mut().resource_mut::<Events<TerritorySpawnRequest>>().drain().map(|request| request.expanse).collect()
+        };
+
+        let requested = press_spawn_key(&mut app);
+        assert_eq!(requested.len(), 1, "Spawn key did not request exactly one Territory.");
+        let default_size = app.world().resource::<GlobalTerritorySettings>().default_size;
+        assert_eq!(requested[0].worldspace().size(), default_size, "Territory in open space was not default sized.");
+        assert_eq!(requested[0].screenspace().min, Vec2::new(400.0 - 3.0, 400.0 - 3.0), "Territory was not placed at the cursor.");
+
+        let hovered_entity = app.world_mut().spawn(Territory::empty()).id();
+        app.world_mut().resource_mut::<WorldMousePosition>().territory = Some(hovered_entity);
+        assert!(press_spawn_key(&mut app).is_empty(), "Territory was requested on top of a hovered one.");
+    }
+
+    #[test]
+    fn scrolling_with_the_modifier_resizes_the_hovered_territory_from_its_center() {
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<ActionState<DevControls>>()
+            .add_event::<MouseWheel>()
+            .add_systems(Update, scroll_resize_hovered_territory);
+
+        let window_entity = app.world_mut().spawn((Window { resolution: (1000.0, 1000.0).into(), ..default() }, TerritoryTabs)).id();
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_worldspace(Rect::new(-100.0, -100.0, 100.0, 100.0), 1000.0, 1000.0);
+        let territory_entity = app.world_mut().spawn(territory).id();
+        let mut mouse_location = app.world_mut().resource_mut::<WorldMousePosition>();
+        mouse_location.window = Some(window_entity);
+        mouse_location.territory = Some(territory_entity);
+
+        let scroll = |app: &mut App, lines: f32| -> Option<Rect> {
+            app.world_mut().send_event(MouseWheel { unit: MouseScrollUnit::Line, x: 0.0, y: lines, window: window_entity });
+            app.update();
+            app.world_mut().entity_mut(territory_entity).take::<MoveRequest>().map(|move_request| move_request.proposed_expanse().worldspace())
+        };
+
+        assert!(scroll(&mut app, 1.0).is_none(), "Scrolling without the modifier resized the Territory.");
+
+        app.world_mut().resource_mut::<ActionState<DevControls>>().press(&DevControls::ScrollResize);
+        assert_eq!(
+            scroll(&mut app, 2.0), 
+            Some(Rect::new(-120.0, -120.0, 120.0, 120.0)), 
+            "Scrolling up did not grow every side."
+        );
+        let min_size = app.world().resource::<GlobalTerritorySettings>().min_size;
+        assert_eq!(
+            scroll(&mut app, -100.0), 
+            Some(Rect::from_center_size(Vec2::ZERO, min_size)), 
+            "Scrolling down did not stop shrinking at the minimum size."
+        );
+    }
+
+    #[test]
+    fn dropped_tab_moves_to_hovered_or_spawned_territory() {
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<CombineTerritoriesRequest>()
+            .add_event::<MoveTabRequest>()
+            .add_systems(Update, activate_placeholders)
+            .add_systems(Update, move_tab_into_spawned_territory.run_if(resource_exists::<PendingTabMove>));
+
+        let window_entity = app.world_mut().spawn((Window { resolution: (1000.0, 1000.0).into(), ..default() }, DisplayLibrary::BevyUi)).id();
+        let origin = app.world_mut().spawn(Territory::empty()).id();
+        let target = app.world_mut().spawn(Territory::empty()).id();
+        let tab = app.world_mut().spawn(Tab::build_from_type(TabType::ECS)).id();
+        let drop_tab = |app: &mut App, placeholder: Placeholder, hovered: Option<Entity>| -> Vec<(Entity, Entity)> {
+            app.world_mut().resource_mut::<WorldMousePosition>().territory = hovered;
+            let placeholder_entity = app.world_mut().spawn((placeholder, TabMovePlaceholder { tab, origin })).id();
+            app.world_mut().entity_mut(window_entity).add_child(placeholder_entity);
+            app.update();
+            app.world_mut().despawn(placeholder_entity);
+            app.world_mut().resource_mut::<Events<MoveTabRequest>>().drain()
+                .map(|move_request| (move_request.tab, move_request.territory))
+                .collect()
+        };
+        let tab_move = || Placeholder { placeholder_type: PlaceholderType::TabMove, ..default() };
+
+        assert_eq!(drop_tab(&mut app, tab_move(), Some(origin)), vec![], "Tab dropped on its own Territory was moved.");
+        assert_eq!(drop_tab(&mut app, tab_move(), Some(target)), vec![(tab, target)], "Tab dropped on another Territory was not moved.");
+
+        // Dropped where nothing fits, the Tab stays put.
+        let invalid_spawn = Placeholder { placeholder_type: PlaceholderType::SpawnTerritory, valid_spawn: false, ..default() };
+        assert_eq!(drop_tab(&mut app, invalid_spawn, None), vec![]);
+        assert!(!app.world().contains_resource::<PendingTabMove>(), "Snapped back Tab is waiting for a Territory.");
+
+        // Dropped in open space, the Tab waits for the new Territory and then moves in.
+        app.world_mut().resource_mut::<WorldMousePosition>().window = Some(window_entity);
+        let spawn_rect = Rect::new(600.0, 600.0, 900.0, 800.0);
+        let valid_spawn = Placeholder { 
+            placeholder_type: PlaceholderType::SpawnTerritory, 
+            valid_spawn: true, 
+            screenspace_visual_rects: vec![spawn_rect, spawn_rect],
+            ..default() 
+        };
+        assert_eq!(drop_tab(&mut app, valid_spawn, None), vec![]);
+        let spawn_request = app.world_mut().resource_mut::<Events<TerritorySpawnRequest>>().drain().last().unwrap();
+        let spawned = app.world_mut().spawn(Territory { expanse: spawn_request.expanse, ..Territory::empty() }).id();
+        app.world_mut().entity_mut(window_entity).add_child(spawned);
+        app.update();
+        let move_requests: Vec<_> = app.world_mut().resource_mut::<Events<MoveTabRequest>>().drain().collect();
+        assert_eq!(move_requests.len(), 1, "Tab did not move into the Territory spawned for it.");
+        assert_eq!((move_requests[0].tab, move_requests[0].territory), (tab, spawned));
+        assert!(!app.world().contains_resource::<PendingTabMove>(), "PendingTabMove outlived its spawn.");
+    }
+
+    #[test]
+    fn combine_placeholder_targets_hovered_territory() {
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .add_event::<TerritorySpawnRequest>()
+            .add_event::<CombineTerritoriesRequest>()
+            .add_event::<MoveTabRequest>()
+            .add_systems(Update, (update_combine_territories_placeholder, activate_placeholders).chain());
+
+        let window_entity = app.world_mut().spawn(Window { resolution: (1000.0, 1000.0).into(), ..default() }).id();
+        let spawn_territory_at = |app: &mut App, screenspace: Rect| {
+            let mut territory = Territory::empty();
+            territory.expanse = RectKit::from_screenspace(screenspace, 1000.0, 1000.0);
+            let territory_entity = app.world_mut().spawn(territory).id();
+            app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+            territory_entity
+        };
+        let source = spawn_territory_at(&mut app, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let target = spawn_territory_at(&mut app, Rect::new(300.0, 100.0, 500.0, 300.0));
+        let placeholder_entity = app.world_mut().spawn((
+            Placeholder { placeholder_type: PlaceholderType::CombineTerritories, ..default() },
+            CombinePlaceholder { source, target: None }
+        )).id();
+
+        // Still over the dragged Territory, so there's nothing to combine with.
+        app.world_mut().resource_mut::<WorldMousePosition>().territory = Some(source);
+        app.update();
+        assert_eq!(app.world().get::<CombinePlaceholder>(placeholder_entity).unwrap().target, None, "Territory was set to combine with itself.");
+        assert!(app.world().resource::<Events<CombineTerritoriesRequest>>().is_empty());
+
+        app.world_mut().resource_mut::<WorldMousePosition>().territory = Some(target);
+        app.update();
+        assert_eq!(app.world().get::<CombinePlaceholder>(placeholder_entity).unwrap().target, Some(target));
+        assert_eq!(
+            app.world().get::<Placeholder>(placeholder_entity).unwrap().screenspace_visual_rects[1], 
+            Rect::new(100.0, 100.0, 500.0, 300.0), 
+            "Placeholder does not show the combined rect."
+        );
+        let combine_requests: Vec<_> = app.world_mut().resource_mut::<Events<CombineTerritoriesRequest>>().drain().collect();
+        assert_eq!(combine_requests.len(), 1, "Activated combine placeholder did not request a combine.");
+        assert_eq!((combine_requests[0].surviving, combine_requests[0].absorbed), (target, source));
+    }
+
+    #[test]
+    fn dragging_a_territory_onto_another_combines_them() {
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .add_event::<CombineTerritoriesRequest>()
+            .add_systems(Update, (track_combine_territories_placeholder, update_combine_territories_placeholder).chain());
+
+        let window_entity = app.world_mut().spawn(Window { resolution: (1000.0, 1000.0).into(), ..default() }).id();
+        let spawn_territory_at = |app: &mut App, screenspace: Rect| {
+            let mut territory = Territory::empty();
+            territory.expanse = RectKit::from_screenspace(screenspace, 1000.0, 1000.0);
+            let territory_entity = app.world_mut().spawn(territory).id();
+            app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+            territory_entity
+        };
+        let source = spawn_territory_at(&mut app, Rect::new(100.0, 100.0, 300.0, 300.0));
+        let target = spawn_territory_at(&mut app, Rect::new(300.0, 100.0, 500.0, 300.0));
+        let combine_placeholders = |app: &mut App| {
+            app.world_mut().query::<&CombinePlaceholder>().iter(app.world()).copied().collect::<Vec<_>>()
+        };
+
+        // A resize isn't a drag, so there's nothing to combine.
+        app.world_mut().entity_mut(source).insert(MotionOrigin { resizing: true, ..default() });
+        app.update();
+        assert!(combine_placeholders(&mut app).is_empty(), "Resizing a Territory spawned a combine placeholder.");
+        app.world_mut().entity_mut(source).remove::<MotionOrigin>();
+        app.update();
+
+        app.world_mut().entity_mut(source).insert(MotionOrigin::default());
+        app.update();
+        assert_eq!(combine_placeholders(&mut app), vec![CombinePlaceholder { source, target: None }], "Dragging did not spawn a combine placeholder.");
+
+        app.world_mut().resource_mut::<WorldMousePosition>().territory = Some(target);
+        app.update();
+        assert_eq!(combine_placeholders(&mut app), vec![CombinePlaceholder { source, target: Some(target) }], "Hovered Territory was not targeted.");
+        assert!(app.world().resource::<Events<CombineTerritoriesRequest>>().is_empty(), "Combined before the drag ended.");
+
+        app.world_mut().entity_mut(source).remove::<MotionOrigin>();
+        app.update();
+        let combine_requests: Vec<_> = app.world_mut().resource_mut::<Events<CombineTerritoriesRequest>>().drain().collect();
+        assert_eq!(combine_requests.len(), 1, "Ending the drag did not request a combine.");
+        assert_eq!((combine_requests[0].surviving, combine_requests[0].absorbed), (target, source));
+        assert!(combine_placeholders(&mut app).is_empty(), "Combine placeholder outlived the drag.");
+    }
+
+    #[test]
+    fn each_window_reports_its_own_cursor_position() {
+        let mut app = cursor_app();
+
+        let mut spawn_window = |width: f32, height: f32, cursor: Vec2| {
+            let mut window = Window::default();
+            window.resolution.set(width, height);
+            window.set_cursor_position(Some(cursor));
+            let window_entity = app.world_mut().spawn(window).id();
+            app.world_mut().send_event(WindowCreated { window: window_entity });
+            window_entity
+        };
+        let wide_window = spawn_window(800.0, 600.0, Vec2::new(100.0, 100.0));
+        let square_window = spawn_window(400.0, 400.0, Vec2::new(300.0, 50.0));
+        app.update();
+
+        let wide_cursor = *app.world().get::<WindowCursor>(wide_window).unwrap();
+        assert_eq!(wide_cursor.screenspace_pos, Some(Vec2::new(100.0, 100.0)), "Wide window has the wrong screenspace cursor.");
+        assert!(
+            wide_cursor.worldspace_pos.is_some_and(|pos| pos.abs_diff_eq(Vec2::new(-300.0, 200.0), 0.001)), 
+            "Wide window has the wrong worldspace cursor: {:?}", wide_cursor.worldspace_pos
+        );
+
+        let square_cursor = *app.world().get::<WindowCursor>(square_window).unwrap();
+        assert_eq!(square_cursor.screenspace_pos, Some(Vec2::new(300.0, 50.0)), "Square window has the wrong screenspace cursor.");
+        assert!(
+            square_cursor.worldspace_pos.is_some_and(|pos| pos.abs_diff_eq(Vec2::new(100.0, 150.0), 0.001)), 
+            "Square window has the wrong worldspace cursor: {:?}", square_cursor.worldspace_pos
+        );
+    }
+
+    /// Counts `WARN` level log events.
+    struct WarnCounter(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+    impl<S: bevy::utils::tracing::Subscriber> bevy::log::tracing_subscriber::Layer<S> for WarnCounter {
+        fn on_event(&self, event: &bevy::utils::tracing::Event<'_>, _ctx: bevy::log::tracing_subscriber::layer::Context<'_, S>) {
+            if *event.metadata().level() == bevy::log::Level::WARN {
+                self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_outside_camera_viewport_has_no_world_position_and_no_warnings() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+        use bevy::render::camera::Viewport;
+
+        let mut app = App::new();
+        app
+            .init_resource::<WorldMousePosition>()
+            .init_resource::<GlobalTerritorySettings>()
+            .init_resource::<WindowRootIndex>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>()
+            .add_systems(Update, (
+                index_added_ui_roots,
+                camera_system::<OrthographicProjection>,
+                get_mouse_location
+            ).chain())
+            // Keep every system on this thread, so the warning counter sees them.
+            .edit_schedule(Update, |schedule| { 
+                schedule.set_executor_kind(bevy::ecs::schedule::ExecutorKind::SingleThreaded); 
+            });
+
+        // Camera only renders the left half of the window, and the cursor is just right of that.
+        let mut window = Window::default();
+        window.resolution.set(800.0, 600.0);
+        window.set_cursor_position(Some(Vec2::new(401.0, 300.0)));
+        let window_entity = app.world_mut().spawn((window, WindowCursor::default())).id();
+        let camera_entity = app.world_mut().spawn((
+            Camera2dBundle {
+                camera: Camera {
+                    target: RenderTarget::Window(WindowRef::Entity(window_entity)),
+                    viewport: Some(Viewport { 
+                        physical_position: UVec2::ZERO, 
+                        physical_size: UVec2::new(400, 600), 
+                        ..default() 
+                    }),
+                    ..default()
+                },
+                ..default()
+            },
+            TerritoryTabsCamera
+        )).id();
+        app.world_mut().spawn((
+            NodeBundle::default(), 
+            TargetCamera(camera_entity), 
+            TerritoryTabsUIRoot { associated_window_entity: window_entity }
+        ));
+        app.world_mut().send_event(WindowCreated { window: window_entity });
+
+        let warnings = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let subscriber = bevy::log::tracing_subscriber::registry().with(WarnCounter(warnings.clone()));
+        bevy::utils::tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..10 {
+                app.update();
+            }
+        });
+
+        let window_cursor = *app.world().get::<WindowCursor>(window_entity).unwrap();
+        assert_eq!(window_cursor.screenspace_pos, Some(Vec2::new(401.0, 300.0)), "Cursor should still be in the window.");
+        assert_eq!(window_cursor.worldspace_pos, None, "Cursor outside the camera viewport should have no world position.");
+        assert_eq!(warnings.load(std::sync::atomic::Ordering::Relaxed), 0, "Cursor outside the viewport caused warnings.");
+    }
+
+    #[test]
+    fn cursor_on_resize_handles_is_over_territory_but_not_its_content() {
+        let mut app = cursor_app();
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        // Just inside the western edge, on the resize handle ring.
+        window.set_cursor_position(Some(Vec2::new(402.0, 500.0)));
+        let window_entity = app.world_mut().spawn(window).id();
+        let mut territory = Territory::empty();
+        territory.expanse = RectKit::from_screenspace(Rect::new(400.0, 400.0, 600.0, 600.0), 1000.0, 1000.0);
+        let territory_entity = app.world_mut().spawn(territory).id();
+        app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+        app.world_mut().send_event(WindowCreated { window: window_entity });
+        app.update();
+
+        let window_cursor = *app.world().get::<WindowCursor>(window_entity).unwrap();
+        assert_eq!(window_cursor.territory, Some(territory_entity), "Handle ring should be over the Territory.");
+        assert_eq!(window_cursor.content_territory, None, "Handle ring should not be over the Territory's content.");
+
+        let mouse_location = app.world().resource::<WorldMousePosition>();
+        assert_eq!(mouse_location.territory, Some(territory_entity));
+        assert_eq!(mouse_location.content_territory, None);
+    }
+
+    #[test]
+    fn stacked_territories_hover_the_top_one() {
+        let mut app = cursor_app();
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        window.set_cursor_position(Some(Vec2::new(500.0, 500.0)));
+        let window_entity = app.world_mut().spawn(window).id();
+        let mut spawn_stacked = |screenspace: Rect| {
+            let base_node_entity = app.world_mut().spawn((ZIndex::Local(0), TerritoryBaseNode)).id();
+            let mut territory = Territory::empty();
+            territory.expanse = RectKit::from_screenspace(screenspace, 1000.0, 1000.0);
+            territory.base_node = Some(base_node_entity);
+            let territory_entity = app.world_mut().spawn(territory).id();
+            app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+            (territory_entity, base_node_entity)
+        };
+        // The small overlay comes first, so it would lose if the last match won.
+        let (small_entity, _) = spawn_stacked(Rect::new(400.0, 400.0, 600.0, 600.0));
+        let (large_entity, large_base_node) = spawn_stacked(Rect::new(200.0, 200.0, 800.0, 800.0));
+        app.world_mut().send_event(WindowCreated { window: window_entity });
+        app.update();
+
+        let window_cursor = *app.world().get::<WindowCursor>(window_entity).unwrap();
+        assert_eq!(window_cursor.territory, Some(small_entity), "Hover should prefer the smaller of two stacked Territories.");
+        assert_eq!(window_cursor.content_territory, Some(small_entity));
+
+        // Drawn on top beats being smaller, even with the cursor standing still.
+        *app.world_mut().get_mut::<ZIndex>(large_base_node).unwrap() = ZIndex::Local(1);
+        app.update();
+        let window_cursor = *app.world().get::<WindowCursor>(window_entity).unwrap();
+        assert_eq!(window_cursor.territory, Some(large_entity), "Hover should prefer the Territory drawn on top.");
+        assert_eq!(window_cursor.content_territory, Some(large_entity));
+    }
+
+    #[test]
+    fn hovering_among_a_hundred_territories_only_point_tests_nearby_ones() {
+        let mut app = cursor_app();
+
+        let mut window = Window::default();
+        window.resolution.set(1000.0, 1000.0);
+        window.set_cursor_position(Some(Vec2::new(555.0, 555.0)));
+        let window_entity = app.world_mut().spawn(window).id();
+        let mut spawn_in_window = |screenspace: Rect| {
+            let mut territory = Territory::empty();
+            territory.expanse = RectKit::from_screenspace(screenspace, 1000.0, 1000.0);
+            let territory_entity = app.world_mut().spawn(territory).id();
+            app.world_mut().entity_mut(window_entity).add_child(territory_entity);
+            territory_entity
+        };
+        // A 10 x 10 grid, with the cursor over the one in row 5, column 5.
+        let mut hovered_entity = Entity::PLACEHOLDER;
+        for row in 0..10 {
+            for column in 0..10 {
+                let min = Vec2::new(column as f32, row as f32) * 100.0 + 1.0;
+                let territory_entity = spawn_in_window(Rect::from_corners(min, min + 98.0));
+                if (row, column) == (5, 5) {
+                    hovered_entity = territory_entity;
+                }
+            }
+        }
+        // Smaller, so it wins the hover whenever it's point tested.
+        let decoy_entity = spawn_in_window(Rect::new(540.0, 540.0, 570.0, 570.0));
+        app.world_mut().send_event(WindowCreated { window: window_entity });
+        app.update();
+
+        let worldspace_pos = app.world().get::<WindowCursor>(window_entity).unwrap().worldspace_pos.unwrap();
+        let candidates = app.world().get::<TerritoryHitGrid>(window_entity).unwrap().candidates(worldspace_pos).len();
+        assert!(candidates <= 5, "{candidates} of 101 Territories would be point tested.");
+        assert_eq!(app.world().get::<WindowCursor>(window_entity).unwrap().territory, Some(decoy_entity));
+
+        // Once the decoy is left out of the hit grid, the hover can't see it anymore, even though it's still under the cursor.
+        for cell in app.world_mut().get_mut::<TerritoryHitGrid>(window_entity).unwrap().cells.values_mut() {
+            cell.retain(|territory_entity| *territory_entity != decoy_entity);
+        }
+        app.update();
+        assert_eq!(
+            app.world().get::<WindowCursor>(window_entity).unwrap().territory, 
+            Some(hovered_entity), 
+            "Hover point tested a Territory the hit grid didn't offer."
+        );
+    }
+}